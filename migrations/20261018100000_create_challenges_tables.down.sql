@@ -0,0 +1,11 @@
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_submission;
+
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_challenge;
+
+DROP INDEX IF EXISTS idx_challenge_submissions_score;
+
+DROP TABLE IF EXISTS challenge_submissions;
+
+DROP TABLE IF EXISTS daily_challenge_words;
+
+DROP TABLE IF EXISTS daily_challenges;
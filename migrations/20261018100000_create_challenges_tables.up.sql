@@ -0,0 +1,57 @@
+CREATE TABLE IF NOT EXISTS daily_challenges (
+    id INTEGER PRIMARY KEY NOT NULL,
+    language TEXT NOT NULL,
+    challenge_date TEXT NOT NULL,
+    created_at TEXT,
+    UNIQUE (language, challenge_date)
+);
+
+CREATE TABLE IF NOT EXISTS daily_challenge_words (
+    challenge_id INTEGER NOT NULL REFERENCES daily_challenges (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    definition TEXT NOT NULL,
+    pronunciation TEXT NOT NULL,
+    PRIMARY KEY (challenge_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS challenge_submissions (
+    id INTEGER PRIMARY KEY NOT NULL,
+    challenge_id INTEGER NOT NULL REFERENCES daily_challenges (id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    token_id TEXT NOT NULL UNIQUE,
+    score INTEGER NOT NULL,
+    total INTEGER NOT NULL,
+    created_at TEXT,
+    UNIQUE (challenge_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_challenge_submissions_score ON challenge_submissions (challenge_id, score);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_challenge
+AFTER
+INSERT
+    ON daily_challenges
+BEGIN
+UPDATE
+    daily_challenges
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_submission
+AFTER
+INSERT
+    ON challenge_submissions
+BEGIN
+UPDATE
+    challenge_submissions
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
@@ -0,0 +1,84 @@
+CREATE TABLE IF NOT EXISTS words_old (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    word TEXT NOT NULL UNIQUE,
+    definition TEXT NOT NULL UNIQUE,
+    pronunciation TEXT NOT NULL UNIQUE,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+-- only the first pronunciation of each word survives the downgrade
+INSERT INTO
+    words_old (id, word_type, word, definition, pronunciation, created_at, updated_at)
+SELECT
+    w.id,
+    w.word_type,
+    w.word,
+    w.definition,
+    (
+        SELECT
+            p.pronunciation
+        FROM
+            word_pronunciations p
+        WHERE
+            p.word_id = w.id
+        ORDER BY
+            p.position
+        LIMIT
+            1
+    ), w.created_at, w.updated_at
+FROM
+    words w;
+
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_word_pronunciation;
+
+DROP INDEX IF EXISTS idx_word_pronunciations_pronunciation;
+
+DROP INDEX IF EXISTS idx_word_pronunciations;
+
+DROP TABLE IF EXISTS word_pronunciations;
+
+DROP TABLE words;
+
+ALTER TABLE words_old RENAME TO words;
+
+CREATE INDEX IF NOT EXISTS idx_words ON words (word);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word
+AFTER
+INSERT
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
@@ -0,0 +1,112 @@
+-- keep the existing pronunciations aside while words is rebuilt
+CREATE TABLE word_pronunciations_staging AS
+SELECT
+    id AS word_id,
+    pronunciation
+FROM
+    words;
+
+CREATE TABLE IF NOT EXISTS words_new (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    word TEXT NOT NULL UNIQUE,
+    definition TEXT NOT NULL UNIQUE,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+INSERT INTO
+    words_new (id, word_type, word, definition, created_at, updated_at)
+SELECT
+    id,
+    word_type,
+    word,
+    definition,
+    created_at,
+    updated_at
+FROM
+    words;
+
+DROP TABLE words;
+
+ALTER TABLE words_new RENAME TO words;
+
+CREATE INDEX IF NOT EXISTS idx_words ON words (word);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word
+AFTER
+INSERT
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
+
+CREATE TABLE IF NOT EXISTS word_pronunciations (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    variant TEXT NOT NULL DEFAULT 'general',
+    pronunciation TEXT NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT,
+    UNIQUE (word_id, pronunciation)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_pronunciations ON word_pronunciations (word_id, position);
+
+CREATE INDEX IF NOT EXISTS idx_word_pronunciations_pronunciation ON word_pronunciations (pronunciation);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_pronunciation
+AFTER
+INSERT
+    ON word_pronunciations
+BEGIN
+UPDATE
+    word_pronunciations
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+INSERT INTO
+    word_pronunciations (word_id, variant, pronunciation, position)
+SELECT
+    word_id,
+    'general',
+    pronunciation,
+    0
+FROM
+    word_pronunciations_staging;
+
+DROP TABLE word_pronunciations_staging;
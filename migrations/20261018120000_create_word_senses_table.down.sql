@@ -0,0 +1,111 @@
+-- dropping words cascades to word_pronunciations, keep them aside
+CREATE TABLE word_pronunciations_staging AS
+SELECT
+    *
+FROM
+    word_pronunciations;
+
+CREATE TABLE IF NOT EXISTS words_old (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    word TEXT NOT NULL UNIQUE,
+    definition TEXT NOT NULL UNIQUE,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+-- only the first sense of each word survives the downgrade
+INSERT INTO
+    words_old (id, word_type, word, definition, created_at, updated_at)
+SELECT
+    w.id,
+    w.word_type,
+    w.word,
+    (
+        SELECT
+            s.definition
+        FROM
+            word_senses s
+        WHERE
+            s.word_id = w.id
+        ORDER BY
+            s.position
+        LIMIT
+            1
+    ), w.created_at, w.updated_at
+FROM
+    words w;
+
+DROP TRIGGER IF EXISTS trg_update_updatedat_for_word_sense;
+
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_word_sense;
+
+DROP INDEX IF EXISTS idx_word_senses;
+
+DROP TABLE IF EXISTS word_senses;
+
+DROP TABLE words;
+
+ALTER TABLE words_old RENAME TO words;
+
+CREATE INDEX IF NOT EXISTS idx_words ON words (word);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word
+AFTER
+INSERT
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
+
+INSERT INTO
+    word_pronunciations
+SELECT
+    *
+FROM
+    word_pronunciations_staging;
+
+-- the insert trigger stamps restored rows, put back their original timestamps
+UPDATE
+    word_pronunciations
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_pronunciations_staging
+        WHERE
+            word_pronunciations_staging.id = word_pronunciations.id
+    );
+
+DROP TABLE word_pronunciations_staging;
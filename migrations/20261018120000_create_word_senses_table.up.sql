@@ -0,0 +1,167 @@
+-- keep the existing definitions and pronunciations aside while words is
+-- rebuilt without its UNIQUE definition column, dropping words cascades
+-- to word_pronunciations
+CREATE TABLE word_senses_staging AS
+SELECT
+    id AS word_id,
+    word_type,
+    definition
+FROM
+    words;
+
+CREATE TABLE word_pronunciations_staging AS
+SELECT
+    *
+FROM
+    word_pronunciations;
+
+CREATE TABLE IF NOT EXISTS words_new (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    word TEXT NOT NULL UNIQUE,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+INSERT INTO
+    words_new (id, word_type, word, created_at, updated_at)
+SELECT
+    id,
+    word_type,
+    word,
+    created_at,
+    updated_at
+FROM
+    words;
+
+DROP TABLE words;
+
+ALTER TABLE words_new RENAME TO words;
+
+CREATE INDEX IF NOT EXISTS idx_words ON words (word);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word
+AFTER
+INSERT
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
+
+INSERT INTO
+    word_pronunciations
+SELECT
+    *
+FROM
+    word_pronunciations_staging;
+
+-- the insert trigger stamps restored rows, put back their original timestamps
+UPDATE
+    word_pronunciations
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_pronunciations_staging
+        WHERE
+            word_pronunciations_staging.id = word_pronunciations.id
+    );
+
+DROP TABLE word_pronunciations_staging;
+
+CREATE TABLE IF NOT EXISTS word_senses (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    part_of_speech TEXT NOT NULL CHECK (
+        part_of_speech IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    definition TEXT NOT NULL,
+    example TEXT,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT,
+    updated_at TEXT,
+    UNIQUE (word_id, definition)
+);
+
+INSERT INTO
+    word_senses (word_id, part_of_speech, definition, position)
+SELECT
+    word_id,
+    word_type,
+    definition,
+    0
+FROM
+    word_senses_staging;
+
+DROP TABLE word_senses_staging;
+
+CREATE INDEX IF NOT EXISTS idx_word_senses ON word_senses (word_id, position);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_sense
+AFTER
+INSERT
+    ON word_senses
+BEGIN
+UPDATE
+    word_senses
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word_sense
+AFTER
+UPDATE
+    ON word_senses
+BEGIN
+UPDATE
+    word_senses
+SET
+    updated_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
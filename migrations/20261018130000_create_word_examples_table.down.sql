@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS word_examples;
+
+ALTER TABLE words DROP COLUMN usage_note;
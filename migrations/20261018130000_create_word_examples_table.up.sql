@@ -0,0 +1,43 @@
+ALTER TABLE words ADD COLUMN usage_note TEXT;
+
+CREATE TABLE IF NOT EXISTS word_examples (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    sentence TEXT NOT NULL,
+    target_start INTEGER NOT NULL CHECK (target_start >= 0),
+    target_end INTEGER NOT NULL CHECK (target_end > target_start),
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT,
+    updated_at TEXT,
+    UNIQUE (word_id, sentence)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_examples ON word_examples (word_id, position);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_example
+AFTER
+INSERT
+    ON word_examples
+BEGIN
+UPDATE
+    word_examples
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word_example
+AFTER
+UPDATE
+    ON word_examples
+BEGIN
+UPDATE
+    word_examples
+SET
+    updated_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
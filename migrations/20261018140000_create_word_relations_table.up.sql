@@ -0,0 +1,27 @@
+CREATE TABLE IF NOT EXISTS word_relations (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    related_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (
+        kind IN ('synonym', 'antonym', 'hypernym', 'derived')
+    ),
+    created_at TEXT,
+    CHECK (word_id != related_id),
+    UNIQUE (word_id, related_id, kind)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_relations_related ON word_relations (related_id, kind);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_relation
+AFTER
+INSERT
+    ON word_relations
+BEGIN
+UPDATE
+    word_relations
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
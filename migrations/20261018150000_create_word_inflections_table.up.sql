@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS word_inflections (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (
+        kind IN (
+            'plural',
+            'past_tense',
+            'past_participle',
+            'comparative',
+            'superlative'
+        )
+    ),
+    form TEXT NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT,
+    UNIQUE (word_id, kind, form)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_inflections_form ON word_inflections (form);
+
+CREATE INDEX IF NOT EXISTS idx_word_inflections ON word_inflections (word_id, position);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_inflection
+AFTER
+INSERT
+    ON word_inflections
+BEGIN
+UPDATE
+    word_inflections
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
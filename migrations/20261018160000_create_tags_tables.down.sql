@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS word_tags;
+
+DROP TABLE IF EXISTS tags;
@@ -0,0 +1,27 @@
+CREATE TABLE IF NOT EXISTS tags (
+    id INTEGER PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    created_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS word_tags (
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+    PRIMARY KEY (word_id, tag_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_tags_tag ON word_tags (tag_id);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_tag
+AFTER
+INSERT
+    ON tags
+BEGIN
+UPDATE
+    tags
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS word_flags (
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    flag TEXT NOT NULL CHECK (
+        flag IN ('offensive', 'mature', 'archaic', 'slang')
+    ),
+    PRIMARY KEY (word_id, flag)
+);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_words_language;
+
+ALTER TABLE words DROP COLUMN language;
+
+DROP TABLE IF EXISTS languages;
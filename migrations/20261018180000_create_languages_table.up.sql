@@ -0,0 +1,47 @@
+CREATE TABLE IF NOT EXISTS languages (
+    code TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    script TEXT NOT NULL,
+    validation_profile TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_language
+AFTER
+INSERT
+    ON languages
+BEGIN
+UPDATE
+    languages
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_language
+AFTER
+UPDATE
+    ON languages
+BEGIN
+UPDATE
+    languages
+SET
+    updated_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+INSERT INTO
+    languages (code, name, script, validation_profile)
+VALUES
+    ('en', 'English', 'Latn', 'latin');
+
+-- every existing word is English, the only language served so far
+ALTER TABLE words ADD COLUMN language TEXT NOT NULL DEFAULT 'en';
+
+CREATE INDEX IF NOT EXISTS idx_words_language ON words (language, word_type);
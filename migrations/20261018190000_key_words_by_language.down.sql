@@ -0,0 +1,182 @@
+-- a lemma can only be stored once again, whatever its language
+CREATE TABLE word_pronunciations_staging AS
+SELECT
+    *
+FROM
+    word_pronunciations;
+
+CREATE TABLE word_senses_staging AS
+SELECT
+    *
+FROM
+    word_senses;
+
+CREATE TABLE word_examples_staging AS
+SELECT
+    *
+FROM
+    word_examples;
+
+CREATE TABLE word_relations_staging AS
+SELECT
+    *
+FROM
+    word_relations;
+
+CREATE TABLE word_inflections_staging AS
+SELECT
+    *
+FROM
+    word_inflections;
+
+CREATE TABLE word_tags_staging AS
+SELECT
+    *
+FROM
+    word_tags;
+
+CREATE TABLE word_flags_staging AS
+SELECT
+    *
+FROM
+    word_flags;
+
+CREATE TABLE IF NOT EXISTS words_new (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    word TEXT NOT NULL UNIQUE,
+    created_at TEXT,
+    updated_at TEXT,
+    usage_note TEXT,
+    language TEXT NOT NULL DEFAULT 'en'
+);
+
+INSERT INTO
+    words_new (
+        id,
+        word_type,
+        word,
+        created_at,
+        updated_at,
+        usage_note,
+        language
+    )
+SELECT
+    id,
+    word_type,
+    word,
+    created_at,
+    updated_at,
+    usage_note,
+    language
+FROM
+    words;
+
+DROP TABLE words;
+
+ALTER TABLE words_new RENAME TO words;
+
+CREATE INDEX IF NOT EXISTS idx_words ON words (word);
+
+CREATE INDEX IF NOT EXISTS idx_words_language ON words (language, word_type);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word
+AFTER
+INSERT
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
+
+INSERT INTO
+    word_pronunciations
+SELECT
+    *
+FROM
+    word_pronunciations_staging;
+
+DROP TABLE word_pronunciations_staging;
+
+INSERT INTO
+    word_senses
+SELECT
+    *
+FROM
+    word_senses_staging;
+
+DROP TABLE word_senses_staging;
+
+INSERT INTO
+    word_examples
+SELECT
+    *
+FROM
+    word_examples_staging;
+
+DROP TABLE word_examples_staging;
+
+INSERT INTO
+    word_relations
+SELECT
+    *
+FROM
+    word_relations_staging;
+
+DROP TABLE word_relations_staging;
+
+INSERT INTO
+    word_inflections
+SELECT
+    *
+FROM
+    word_inflections_staging;
+
+DROP TABLE word_inflections_staging;
+
+INSERT INTO
+    word_tags
+SELECT
+    *
+FROM
+    word_tags_staging;
+
+DROP TABLE word_tags_staging;
+
+INSERT INTO
+    word_flags
+SELECT
+    *
+FROM
+    word_flags_staging;
+
+DROP TABLE word_flags_staging;
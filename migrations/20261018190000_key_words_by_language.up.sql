@@ -0,0 +1,243 @@
+-- keep the child rows of every word aside while words is rebuilt keyed by
+-- language, dropping words cascades to all of its child tables
+CREATE TABLE word_pronunciations_staging AS
+SELECT
+    *
+FROM
+    word_pronunciations;
+
+CREATE TABLE word_senses_staging AS
+SELECT
+    *
+FROM
+    word_senses;
+
+CREATE TABLE word_examples_staging AS
+SELECT
+    *
+FROM
+    word_examples;
+
+CREATE TABLE word_relations_staging AS
+SELECT
+    *
+FROM
+    word_relations;
+
+CREATE TABLE word_inflections_staging AS
+SELECT
+    *
+FROM
+    word_inflections;
+
+CREATE TABLE word_tags_staging AS
+SELECT
+    *
+FROM
+    word_tags;
+
+CREATE TABLE word_flags_staging AS
+SELECT
+    *
+FROM
+    word_flags;
+
+CREATE TABLE IF NOT EXISTS words_new (
+    id INTEGER PRIMARY KEY NOT NULL,
+    language TEXT NOT NULL REFERENCES languages (code),
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            "noun",
+            "verb",
+            "adjective",
+            "adverb",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article"
+        )
+    ),
+    word TEXT NOT NULL,
+    usage_note TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    UNIQUE (language, word)
+);
+
+INSERT INTO
+    words_new (
+        id,
+        language,
+        word_type,
+        word,
+        usage_note,
+        created_at,
+        updated_at
+    )
+SELECT
+    id,
+    language,
+    word_type,
+    word,
+    usage_note,
+    created_at,
+    updated_at
+FROM
+    words;
+
+DROP TABLE words;
+
+ALTER TABLE words_new RENAME TO words;
+
+CREATE INDEX IF NOT EXISTS idx_words_language ON words (language, word_type);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word
+AFTER
+INSERT
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
+
+INSERT INTO
+    word_pronunciations
+SELECT
+    *
+FROM
+    word_pronunciations_staging;
+
+-- the insert triggers stamp restored rows, put back their original timestamps
+UPDATE
+    word_pronunciations
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_pronunciations_staging
+        WHERE
+            word_pronunciations_staging.id = word_pronunciations.id
+    );
+
+DROP TABLE word_pronunciations_staging;
+
+INSERT INTO
+    word_senses
+SELECT
+    *
+FROM
+    word_senses_staging;
+
+UPDATE
+    word_senses
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_senses_staging
+        WHERE
+            word_senses_staging.id = word_senses.id
+    );
+
+DROP TABLE word_senses_staging;
+
+INSERT INTO
+    word_examples
+SELECT
+    *
+FROM
+    word_examples_staging;
+
+UPDATE
+    word_examples
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_examples_staging
+        WHERE
+            word_examples_staging.id = word_examples.id
+    );
+
+DROP TABLE word_examples_staging;
+
+INSERT INTO
+    word_relations
+SELECT
+    *
+FROM
+    word_relations_staging;
+
+UPDATE
+    word_relations
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_relations_staging
+        WHERE
+            word_relations_staging.id = word_relations.id
+    );
+
+DROP TABLE word_relations_staging;
+
+INSERT INTO
+    word_inflections
+SELECT
+    *
+FROM
+    word_inflections_staging;
+
+UPDATE
+    word_inflections
+SET
+    created_at = (
+        SELECT
+            created_at
+        FROM
+            word_inflections_staging
+        WHERE
+            word_inflections_staging.id = word_inflections.id
+    );
+
+DROP TABLE word_inflections_staging;
+
+INSERT INTO
+    word_tags
+SELECT
+    *
+FROM
+    word_tags_staging;
+
+DROP TABLE word_tags_staging;
+
+INSERT INTO
+    word_flags
+SELECT
+    *
+FROM
+    word_flags_staging;
+
+DROP TABLE word_flags_staging;
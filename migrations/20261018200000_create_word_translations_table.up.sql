@@ -0,0 +1,27 @@
+CREATE TABLE IF NOT EXISTS word_translations (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    translation_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    -- optional positions of the senses of either word the link is for
+    sense INTEGER CHECK (sense >= 0),
+    translation_sense INTEGER CHECK (translation_sense >= 0),
+    created_at TEXT,
+    CHECK (word_id != translation_id),
+    UNIQUE (word_id, translation_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_translations_translation ON word_translations (translation_id);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_translation
+AFTER
+INSERT
+    ON word_translations
+BEGIN
+UPDATE
+    word_translations
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS word_categories (
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    category TEXT NOT NULL CHECK (
+        category IN (
+            'proper',
+            'countable',
+            'uncountable',
+            'transitive',
+            'intransitive'
+        )
+    ),
+    PRIMARY KEY (word_id, category)
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_categories_category ON word_categories (category);
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS type_names (
+    language TEXT NOT NULL REFERENCES languages (code) ON DELETE CASCADE,
+    word_type TEXT NOT NULL CHECK (
+        word_type IN (
+            'noun',
+            'verb',
+            'adjective',
+            'adverb',
+            'pronoun',
+            'preposition',
+            'conjunction',
+            'interjection',
+            'article'
+        )
+    ),
+    name TEXT NOT NULL,
+    PRIMARY KEY (language, word_type),
+    UNIQUE (language, name)
+);
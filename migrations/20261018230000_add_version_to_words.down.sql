@@ -0,0 +1,5 @@
+DROP TRIGGER IF EXISTS trg_delete_version_for_word_tag;
+
+DROP TRIGGER IF EXISTS trg_insert_version_for_word_tag;
+
+ALTER TABLE words DROP COLUMN version;
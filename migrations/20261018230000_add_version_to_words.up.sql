@@ -0,0 +1,31 @@
+-- every write to a word bumps its version, which is the ETag of the word
+ALTER TABLE words ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+-- tags are part of a word, even when removed along with their tag
+CREATE TRIGGER IF NOT EXISTS trg_insert_version_for_word_tag
+AFTER
+INSERT
+    ON word_tags
+BEGIN
+UPDATE
+    words
+SET
+    version = version + 1
+WHERE
+    id = new.word_id;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_delete_version_for_word_tag
+AFTER
+DELETE
+    ON word_tags
+BEGIN
+UPDATE
+    words
+SET
+    version = version + 1
+WHERE
+    id = old.word_id;
+
+END;
@@ -0,0 +1,5 @@
+DELETE FROM words WHERE deleted_at IS NOT NULL;
+
+DROP INDEX IF EXISTS idx_words_deleted_at;
+
+ALTER TABLE words DROP COLUMN deleted_at;
@@ -0,0 +1,5 @@
+-- deleted words stay in the trash, with the time they were deleted, until
+-- they are restored or purged
+ALTER TABLE words ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_words_deleted_at ON words (language, deleted_at);
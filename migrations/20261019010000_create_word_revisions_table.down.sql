@@ -0,0 +1,19 @@
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_word_revision;
+
+DROP INDEX IF EXISTS idx_word_revisions_word;
+
+DROP TABLE IF EXISTS word_revisions;
+
+DROP TRIGGER IF EXISTS trg_update_updatedat_for_word;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec');
+
+END;
@@ -0,0 +1,61 @@
+-- an update stamps the updated word only, it used to stamp every word
+DROP TRIGGER IF EXISTS trg_update_updatedat_for_word;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word
+AFTER
+UPDATE
+    OF word,
+    word_type,
+    usage_note,
+    version,
+    deleted_at ON words
+BEGIN
+UPDATE
+    words
+SET
+    updated_at = DATETIME('NOW', 'subsec')
+WHERE
+    id = new.id;
+
+END;
+
+-- every change to a word, with the admin who made it and the word before
+-- and after, as JSON snapshots
+CREATE TABLE IF NOT EXISTS word_revisions (
+    id INTEGER PRIMARY KEY NOT NULL,
+    -- kept when the word is purged, hence no foreign key
+    word_id INTEGER NOT NULL,
+    language TEXT NOT NULL REFERENCES languages (code),
+    action TEXT NOT NULL CHECK (
+        action IN (
+            'create',
+            'update',
+            'delete',
+            'restore',
+            'revert',
+            'purge'
+        )
+    ),
+    -- kept when the user is removed, hence no foreign key
+    user_id INTEGER,
+    username TEXT,
+    before_snapshot TEXT,
+    after_snapshot TEXT,
+    created_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_revisions_word ON word_revisions (language, word_id, id);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_revision
+AFTER
+INSERT
+    ON word_revisions
+BEGIN
+UPDATE
+    word_revisions
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
@@ -0,0 +1,11 @@
+DROP TRIGGER IF EXISTS trg_update_updatedat_for_word_proposal;
+
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_word_proposal;
+
+DROP INDEX IF EXISTS idx_word_proposals_author;
+
+DROP INDEX IF EXISTS idx_word_proposals_status;
+
+DROP TABLE IF EXISTS word_proposals;
+
+ALTER TABLE users DROP COLUMN is_editor;
@@ -0,0 +1,59 @@
+-- editors propose words and changes without administrative privileges
+ALTER TABLE users ADD COLUMN is_editor BOOLEAN NOT NULL DEFAULT 0;
+
+-- proposed words and changes, pending review by an admin
+CREATE TABLE IF NOT EXISTS word_proposals (
+    id INTEGER PRIMARY KEY NOT NULL,
+    language TEXT NOT NULL REFERENCES languages (code) ON DELETE CASCADE,
+    -- the word to change, or the created word once a new word is approved,
+    -- the proposal is kept when the word is purged
+    word_id INTEGER REFERENCES words (id) ON DELETE SET NULL,
+    -- version of the word the change was proposed against
+    base_version INTEGER,
+    proposal TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (
+        status IN ('pending', 'approved', 'rejected', 'changes_requested')
+    ),
+    -- kept when the user is removed, hence no foreign key
+    author_id INTEGER NOT NULL,
+    author TEXT NOT NULL,
+    reviewer_id INTEGER,
+    reviewer TEXT,
+    review_comment TEXT,
+    reviewed_at TEXT,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_proposals_status ON word_proposals (language, status, id);
+
+CREATE INDEX IF NOT EXISTS idx_word_proposals_author ON word_proposals (author_id, id);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_proposal
+AFTER
+INSERT
+    ON word_proposals
+BEGIN
+UPDATE
+    word_proposals
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_update_updatedat_for_word_proposal
+AFTER
+UPDATE
+    OF proposal,
+    status ON word_proposals
+BEGIN
+UPDATE
+    word_proposals
+SET
+    updated_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
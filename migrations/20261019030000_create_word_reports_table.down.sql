@@ -0,0 +1,5 @@
+DROP TRIGGER IF EXISTS trg_insert_createdat_for_word_report;
+
+DROP INDEX IF EXISTS idx_word_reports_status;
+
+DROP TABLE IF EXISTS word_reports;
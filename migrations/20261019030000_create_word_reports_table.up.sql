@@ -0,0 +1,39 @@
+-- problems with words reported by the public, pending triage by an admin
+CREATE TABLE IF NOT EXISTS word_reports (
+    id INTEGER PRIMARY KEY NOT NULL,
+    word_id INTEGER NOT NULL REFERENCES words (id) ON DELETE CASCADE,
+    reason TEXT NOT NULL CHECK (
+        reason IN (
+            'wrong_definition',
+            'bad_pronunciation',
+            'inappropriate',
+            'other'
+        )
+    ),
+    note TEXT,
+    status TEXT NOT NULL DEFAULT 'open' CHECK (
+        status IN ('open', 'resolved', 'dismissed')
+    ),
+    -- kept when the user is removed, hence no foreign key
+    resolver_id INTEGER,
+    resolver TEXT,
+    resolution_note TEXT,
+    resolved_at TEXT,
+    created_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_word_reports_status ON word_reports (status, word_id, id);
+
+CREATE TRIGGER IF NOT EXISTS trg_insert_createdat_for_word_report
+AFTER
+INSERT
+    ON word_reports
+BEGIN
+UPDATE
+    word_reports
+SET
+    created_at = DATETIME('NOW', 'subsec')
+WHERE
+    ROWID = new.ROWID;
+
+END;
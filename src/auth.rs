@@ -10,7 +10,7 @@ use argon2::{
     Argon2,
 };
 use axum::{
-    extract::{FromRequestParts, Request, State},
+    extract::{FromRequestParts, OptionalFromRequestParts, Request, State},
     http::request::Parts,
     middleware::Next,
     response::Response,
@@ -95,6 +95,7 @@ impl JwtManager {
             // Custom application claims
             username: user.username.clone(),
             is_admin: user.is_admin,
+            is_editor: user.is_editor,
             session_id: Uuid::new_v4().to_string(),
             token_type: "access".to_string(),
         };
@@ -182,6 +183,27 @@ impl FromRequestParts<AppState> for AuthUser {
     }
 }
 
+/// Optional authenticated user for public routes
+///
+/// Requests without an Authorization header are anonymous, while a header
+/// with an invalid token is still rejected.
+impl OptionalFromRequestParts<AppState> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if !parts.headers.contains_key("Authorization") {
+            return Ok(None);
+        }
+
+        <AuthUser as FromRequestParts<AppState>>::from_request_parts(parts, state)
+            .await
+            .map(Some)
+    }
+}
+
 /// Middleware for admin-only routes
 pub struct RequireAdmin(pub AuthUser);
 
@@ -192,7 +214,8 @@ impl FromRequestParts<AppState> for RequireAdmin {
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
-        let user = AuthUser::from_request_parts(parts, state).await?;
+        let user =
+            <AuthUser as FromRequestParts<AppState>>::from_request_parts(parts, state).await?;
 
         if !user.is_admin {
             return Err(AppError::from(AuthError::InvalidCredentials));
@@ -268,6 +291,34 @@ pub async fn admin_auth_middleware(
     Ok(next.run(request).await)
 }
 
+/// Editor authentication middleware for router-level protection
+///
+/// Validates JWT tokens like `admin_auth_middleware`, but lets editors
+/// through as well as admins, so volunteers can propose words for review
+/// without administrative privileges. Injects AuthUser into request
+/// extensions for handlers that need user context.
+pub async fn editor_auth_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let (mut parts, body) = request.into_parts();
+    let auth_user =
+        <AuthUser as FromRequestParts<AppState>>::from_request_parts(&mut parts, &state).await?;
+
+    // Check editorial privileges, which admins have too
+    if !auth_user.is_editor && !auth_user.is_admin {
+        return Err(AppError::from(AuthError::InvalidCredentials));
+    }
+
+    // Inject authenticated user into request extensions
+    let mut request = Request::from_parts(parts, body);
+    request.extensions_mut().insert(auth_user);
+
+    // Continue to the handler
+    Ok(next.run(request).await)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,6 +329,7 @@ mod tests {
             username: "testuser".to_string(),
             password_hash: "hash".to_string(),
             is_admin: true,
+            is_editor: false,
             created_at: None,
             updated_at: None,
         }
@@ -353,6 +405,7 @@ mod tests {
             jti: uuid::Uuid::new_v4().to_string(),
             username: user.username.clone(),
             is_admin: user.is_admin,
+            is_editor: user.is_editor,
             session_id: uuid::Uuid::new_v4().to_string(),
             token_type: "access".to_string(),
         };
@@ -386,6 +439,7 @@ mod tests {
             jti: uuid::Uuid::new_v4().to_string(),
             username: user.username.clone(),
             is_admin: user.is_admin,
+            is_editor: user.is_editor,
             session_id: uuid::Uuid::new_v4().to_string(),
             token_type: "refresh".to_string(), // Wrong type
         };
@@ -415,6 +469,7 @@ mod tests {
             jti: "unique-jwt-id".to_string(),
             username: "testuser".to_string(),
             is_admin: true,
+            is_editor: false,
             session_id: "unique-session-id".to_string(),
             token_type: "access".to_string(),
         };
@@ -423,6 +478,7 @@ mod tests {
         assert_eq!(auth_user.id, 123);
         assert_eq!(auth_user.username, "testuser");
         assert!(auth_user.is_admin);
+        assert_eq!(auth_user.jti, "unique-jwt-id");
     }
 
     #[test]
@@ -449,6 +505,7 @@ mod tests {
             username: "regular_user".to_string(),
             password_hash: "hash".to_string(),
             is_admin: false, // Not admin
+            is_editor: false,
             created_at: None,
             updated_at: None,
         };
@@ -492,6 +549,7 @@ mod tests {
             jti: uuid::Uuid::new_v4().to_string(),
             username: user.username.clone(),
             is_admin: user.is_admin,
+            is_editor: user.is_editor,
             session_id: uuid::Uuid::new_v4().to_string(),
             token_type: "refresh".to_string(), // Wrong type
         };
@@ -81,6 +81,10 @@ pub struct Arguments {
     #[arg(short('o'), long, default_value = "localhost")]
     pub allowed_origins: Vec<String>,
 
+    /// Directory of the pronunciation tables, one `<language>.toml` per language
+    #[arg(long, default_value = "data/pronunciation")]
+    pub pronunciation_dir: PathBuf,
+
     /// Enable Brotli compression
     #[arg(long, default_value_t = false)]
     pub enable_brotli: bool,
@@ -122,6 +126,21 @@ pub struct Arguments {
     #[arg(short('k'), long, default_value_t = 512)]
     pub request_body_limit_kilobytes: usize,
 
+    /// Days deleted words stay in the trash before being purged
+    #[validate(range(min = 1, max = 3650))]
+    #[arg(long, default_value_t = 30)]
+    pub trash_retention_days: u32,
+
+    /// Maximum problem reports per hour per IP address
+    #[validate(range(min = 1, max = 1000))]
+    #[arg(long, default_value_t = 10)]
+    pub report_limit_per_hour: u32,
+
+    /// Maximum body size of word imports in kilobytes
+    #[validate(range(min = 1, max = 102400))]
+    #[arg(long, default_value_t = 16384)]
+    pub import_body_limit_kilobytes: usize,
+
     /// Enable SwaggerUI documentation interface
     #[arg(long, default_value_t = false)]
     pub with_swagger_ui: bool,
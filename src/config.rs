@@ -140,6 +140,9 @@ impl ApiConfig {
                     .split(',')
                     .map(|s| s.trim().to_string())
                     .collect(),
+                // optional, as env files predating loaded pronunciation tables don't have it
+                dotenvy::var("PRONUNCIATION_DIR")
+                    .map_or_else(|_| default_pronunciation_dir(), PathBuf::from),
             ),
             compression: ApiCompression::new(
                 bool::from_str(&dotenvy::var("ENABLE_BROTLI")?)?,
@@ -154,6 +157,17 @@ impl ApiConfig {
                 u32::from_str(&dotenvy::var("BURST_SIZE")?)?,
                 u64::from_str(&dotenvy::var("REQUEST_TIMEOUT")?)?,
                 usize::from_str(&dotenvy::var("REQUEST_BODY_LIMIT")?)?,
+                // optional, as env files predating the trash don't have it
+                dotenvy::var("TRASH_RETENTION_DAYS")
+                    .map_or(Ok(default_trash_retention_days()), |v| u32::from_str(&v))?,
+                // optional, as env files predating word reports don't have it
+                dotenvy::var("REPORT_LIMIT_PER_HOUR")
+                    .map_or(Ok(default_report_limit_per_hour()), |v| u32::from_str(&v))?,
+                // optional, as env files predating the import limit don't have it
+                dotenvy::var("IMPORT_BODY_LIMIT")
+                    .map_or(Ok(default_import_body_limit_kilobytes()), |v| {
+                        usize::from_str(&v)
+                    })?,
             ),
             openapi: OpenApiDocs::new(
                 bool::from_str(&dotenvy::var("ENABLE_SWAGGER_UI")?)?,
@@ -200,6 +214,7 @@ impl ApiConfig {
                 cli.arg.port,
                 cli.arg.database_url.clone(),
                 cli.arg.allowed_origins.clone(),
+                cli.arg.pronunciation_dir.clone(),
             ),
             compression: ApiCompression::new(cli.arg.enable_brotli, cli.arg.enable_gzip),
             jwt_settings: JwtSettings::new(
@@ -211,6 +226,9 @@ impl ApiConfig {
                 cli.arg.burst_size,
                 cli.arg.request_timeout,
                 cli.arg.request_body_limit_kilobytes,
+                cli.arg.trash_retention_days,
+                cli.arg.report_limit_per_hour,
+                cli.arg.import_body_limit_kilobytes,
             ),
             openapi: OpenApiDocs::new(
                 cli.arg.with_swagger_ui,
@@ -272,6 +290,14 @@ pub struct ApiSettings {
     pub database_url: String,
     /// Allowed origins domain to peruse the API
     pub allowed_origins: Vec<String>,
+    /// Directory of the pronunciation tables, one `<language>.toml` per language
+    #[serde(default = "default_pronunciation_dir")]
+    pub pronunciation_dir: PathBuf,
+}
+
+/// Directory of the pronunciation tables when not configured
+fn default_pronunciation_dir() -> PathBuf {
+    PathBuf::from("data/pronunciation")
 }
 
 impl ApiSettings {
@@ -282,17 +308,20 @@ impl ApiSettings {
     /// * `port` - Port number to listen on
     /// * `database_url` - Database connection URL
     /// * `allowed_origins` - List of allowed CORS origins
+    /// * `pronunciation_dir` - Directory of the pronunciation tables
     pub fn new(
         address: IpAddr,
         port: u16,
         database_url: String,
         allowed_origins: Vec<String>,
+        pronunciation_dir: PathBuf,
     ) -> Self {
         Self {
             address,
             port,
             database_url,
             allowed_origins,
+            pronunciation_dir,
         }
     }
 }
@@ -313,11 +342,12 @@ impl fmt::Display for ApiSettings {
 
         write!(
             f,
-            "# Server Configuration\nBIND_ADDR=\"{}\"\nBIND_PORT={}\nDATABASE_URL=\"{}\"\nALLOWED_ORIGINS={}",
+            "# Server Configuration\nBIND_ADDR=\"{}\"\nBIND_PORT={}\nDATABASE_URL=\"{}\"\nALLOWED_ORIGINS={}\nPRONUNCIATION_DIR=\"{}\"",
             self.address,
             self.port,
             self.database_url,
             origins_str,
+            self.pronunciation_dir.display(),
         )
     }
 }
@@ -335,6 +365,7 @@ impl Default for ApiSettings {
             port: u16::from_str("3000").unwrap(),
             database_url: "sqlite:random-words.db".to_string(),
             allowed_origins: vec!["localhost".to_string()],
+            pronunciation_dir: default_pronunciation_dir(),
         }
     }
 }
@@ -461,6 +492,34 @@ pub struct ApiLimits {
     /// Maximum request body size in kilobytes (1-10240, default: 1024KB)
     #[validate(range(min = 1, max = 10240))]
     pub request_body_limit_kilobytes: usize,
+    /// Days deleted words stay in the trash before being purged (1-3650, default: 30)
+    #[validate(range(min = 1, max = 3650))]
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u32,
+    /// Maximum problem reports per hour per IP address (1-1000, default: 10)
+    #[validate(range(min = 1, max = 1000))]
+    #[serde(default = "default_report_limit_per_hour")]
+    pub report_limit_per_hour: u32,
+    /// Maximum body size of word imports in kilobytes, instead of the request
+    /// body limit (1-102400, default: 16384KB)
+    #[validate(range(min = 1, max = 102400))]
+    #[serde(default = "default_import_body_limit_kilobytes")]
+    pub import_body_limit_kilobytes: usize,
+}
+
+/// Days deleted words stay in the trash when not configured
+fn default_trash_retention_days() -> u32 {
+    30
+}
+
+/// Problem reports per hour per IP address when not configured
+fn default_report_limit_per_hour() -> u32 {
+    10
+}
+
+/// Body size of word imports in kilobytes when not configured
+fn default_import_body_limit_kilobytes() -> usize {
+    16384
 }
 
 impl ApiLimits {
@@ -471,17 +530,26 @@ impl ApiLimits {
     /// * `burst_size` - Maximum burst size per IP (1-1000)
     /// * `request_timeout` - Request timeout in seconds (1-300)
     /// * `request_body_limit_kilobytes` - Maximum request body size in kilobytes
+    /// * `trash_retention_days` - Days deleted words stay in the trash (1-3650)
+    /// * `report_limit_per_hour` - Maximum problem reports per hour per IP (1-1000)
+    /// * `import_body_limit_kilobytes` - Maximum body size of word imports in kilobytes
     pub fn new(
         rate_limit_per_second: u64,
         burst_size: u32,
         request_timeout: u64,
         request_body_limit_kilobytes: usize,
+        trash_retention_days: u32,
+        report_limit_per_hour: u32,
+        import_body_limit_kilobytes: usize,
     ) -> Self {
         Self {
             rate_limit_per_second,
             burst_size,
             request_timeout,
             request_body_limit_kilobytes,
+            trash_retention_days,
+            report_limit_per_hour,
+            import_body_limit_kilobytes,
         }
     }
 }
@@ -497,6 +565,9 @@ impl Default for ApiLimits {
             burst_size: 5,
             request_timeout: 5,
             request_body_limit_kilobytes: 1024,
+            trash_retention_days: default_trash_retention_days(),
+            report_limit_per_hour: default_report_limit_per_hour(),
+            import_body_limit_kilobytes: default_import_body_limit_kilobytes(),
         }
     }
 }
@@ -509,8 +580,8 @@ impl fmt::Display for ApiLimits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "# API Limiting\nRATE_LIMIT_PER_SECOND={}\nBURST_SIZE={}\nREQUEST_TIMEOUT={}\nREQUEST_BODY_LIMIT={}",
-            self.rate_limit_per_second, self.burst_size, self.request_timeout, self.request_body_limit_kilobytes
+            "# API Limiting\nRATE_LIMIT_PER_SECOND={}\nBURST_SIZE={}\nREQUEST_TIMEOUT={}\nREQUEST_BODY_LIMIT={}\nTRASH_RETENTION_DAYS={}\nREPORT_LIMIT_PER_HOUR={}\nIMPORT_BODY_LIMIT={}",
+            self.rate_limit_per_second, self.burst_size, self.request_timeout, self.request_body_limit_kilobytes, self.trash_retention_days, self.report_limit_per_hour, self.import_body_limit_kilobytes
         )
     }
 }
@@ -581,13 +652,14 @@ mod tests {
     fn test_api_config_new() {
         let address = IpAddr::from_str("127.0.0.1").unwrap();
         let jwt_settings = JwtSettings::new(5, "secret".to_string());
-        let api_limits = ApiLimits::new(5, 10, 30, 1024);
+        let api_limits = ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384);
         let openapi = OpenApiDocs::new(true, false, true, false);
         let server_settings = ApiSettings::new(
             address,
             8080,
             "sqlite:test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -608,13 +680,14 @@ mod tests {
     fn test_api_config_new_ipv4_custom() {
         let address = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100));
         let jwt_settings = JwtSettings::new(5, "secret".to_string());
-        let api_limits = ApiLimits::new(5, 10, 30, 1024);
+        let api_limits = ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384);
         let openapi = OpenApiDocs::new(false, true, true, false);
         let server_settings = ApiSettings::new(
             address,
             9000,
             "sqlite:ipv4_test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -639,6 +712,7 @@ mod tests {
             8080,
             "sqlite:test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -663,6 +737,7 @@ mod tests {
             3000,
             "sqlite:test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -687,6 +762,7 @@ mod tests {
             8080,
             "sqlite:test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -745,6 +821,10 @@ mod tests {
         );
         assert_eq!(config.server_settings.port, 8080);
         assert_eq!(config.server_settings.database_url, "sqlite:ipv4.db");
+        assert_eq!(
+            config.server_settings.pronunciation_dir,
+            default_pronunciation_dir()
+        );
         assert_eq!(config.jwt_settings.secret, "test_secret_ipv4");
         assert_eq!(config.jwt_settings.token_expiration_minutes, 5);
         assert_eq!(config.api_limits.rate_limit_per_second, 5);
@@ -759,6 +839,7 @@ mod tests {
             8080,
             "sqlite:ipv4_display.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -772,6 +853,7 @@ mod tests {
         assert!(output.contains("BIND_ADDR=\"172.16.0.1\""));
         assert!(output.contains("BIND_PORT=8080"));
         assert!(output.contains("DATABASE_URL=\"sqlite:ipv4_display.db\""));
+        assert!(output.contains("PRONUNCIATION_DIR=\"data/pronunciation\""));
     }
 
     #[test]
@@ -789,6 +871,7 @@ mod tests {
                 8080,
                 "sqlite:test.db".to_string(),
                 vec!["localhost".to_string()],
+                default_pronunciation_dir(),
             );
             let config = ApiConfig::new(
                 server_settings,
@@ -815,6 +898,7 @@ mod tests {
             9000,
             "sqlite:ipv6_test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -839,6 +923,7 @@ mod tests {
             8080,
             "sqlite:test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -863,6 +948,7 @@ mod tests {
             3000,
             "sqlite:test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -932,6 +1018,7 @@ mod tests {
             8080,
             "sqlite:ipv6_display.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -995,6 +1082,7 @@ mod tests {
             9000,
             "sqlite:display_test.db".to_string(),
             vec!["localhost".to_string()],
+            default_pronunciation_dir(),
         );
         let config = ApiConfig::new(
             server_settings,
@@ -1008,6 +1096,9 @@ mod tests {
         assert!(output.contains("BIND_ADDR=\"192.168.1.1\""));
         assert!(output.contains("BIND_PORT=9000"));
         assert!(output.contains("DATABASE_URL=\"sqlite:display_test.db\""));
+        assert!(output.contains("TRASH_RETENTION_DAYS=30"));
+        assert!(output.contains("REPORT_LIMIT_PER_HOUR=10"));
+        assert!(output.contains("IMPORT_BODY_LIMIT=16384"));
         assert!(output.contains("ENABLE_SWAGGER_UI=true"));
         assert!(output.contains("ENABLE_REDOC=false"));
         assert!(output.contains("ENABLE_SCALAR=true"));
@@ -1139,6 +1230,10 @@ mod tests {
         assert_eq!(config.jwt_settings.secret, "test_secret_config");
         assert_eq!(config.jwt_settings.token_expiration_minutes, 5);
         assert_eq!(config.api_limits.rate_limit_per_second, 5);
+        // files predating the trash keep the default retention
+        assert_eq!(config.api_limits.trash_retention_days, 30);
+        assert_eq!(config.api_limits.report_limit_per_hour, 10);
+        assert_eq!(config.api_limits.import_body_limit_kilobytes, 16384);
         assert!(config.openapi.enable_swagger_ui);
         assert!(config.openapi.enable_redoc);
     }
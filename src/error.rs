@@ -52,6 +52,117 @@ impl IntoResponse for AppError {
                 ),
             };
             (status, message).into_response()
+        } else if let Some(ladder_error) = self.0.downcast_ref::<LadderError>() {
+            // LadderError maps to client errors, the request itself was fine
+            let status = match ladder_error {
+                LadderError::LengthMismatch(_, _) | LadderError::InvalidLength(_) => {
+                    StatusCode::BAD_REQUEST
+                }
+                LadderError::UnknownWord(_)
+                | LadderError::NoLadder(_, _)
+                | LadderError::NoPuzzle(_) => StatusCode::NOT_FOUND,
+            };
+            (status, ladder_error.to_string()).into_response()
+        } else if let Some(challenge_error) = self.0.downcast_ref::<ChallengeError>() {
+            // ChallengeError distinguishes bad input, unknown days and conflicts
+            let status = match challenge_error {
+                ChallengeError::InvalidDate(_)
+                | ChallengeError::Closed(_)
+                | ChallengeError::AnswerCount { .. } => StatusCode::BAD_REQUEST,
+                ChallengeError::FutureDate(_) | ChallengeError::NoWords => StatusCode::NOT_FOUND,
+                ChallengeError::AlreadySubmitted | ChallengeError::ReplayedToken => {
+                    StatusCode::CONFLICT
+                }
+            };
+            (status, challenge_error.to_string()).into_response()
+        } else if let Some(lookup_error) = self.0.downcast_ref::<LookupError>() {
+            // LookupError should return 404 Not Found
+            (StatusCode::NOT_FOUND, lookup_error.to_string()).into_response()
+        } else if let Some(relation_error) = self.0.downcast_ref::<RelationError>() {
+            // RelationError distinguishes bad input, unknown relations and duplicates
+            let status = match relation_error {
+                RelationError::SelfRelation => StatusCode::BAD_REQUEST,
+                RelationError::UnknownRelation(_) => StatusCode::NOT_FOUND,
+                RelationError::AlreadyRelated(_) => StatusCode::CONFLICT,
+            };
+            (status, relation_error.to_string()).into_response()
+        } else if let Some(translation_error) = self.0.downcast_ref::<TranslationError>() {
+            // TranslationError distinguishes bad input, unknown links and duplicates
+            let status = match translation_error {
+                TranslationError::SameLanguage | TranslationError::UnknownSense(_) => {
+                    StatusCode::BAD_REQUEST
+                }
+                TranslationError::UnknownTranslation(_) => StatusCode::NOT_FOUND,
+                TranslationError::AlreadyTranslated => StatusCode::CONFLICT,
+            };
+            (status, translation_error.to_string()).into_response()
+        } else if let Some(tag_error) = self.0.downcast_ref::<TagError>() {
+            // TagError distinguishes unknown tags and duplicates
+            let status = match tag_error {
+                TagError::UnknownTag(_) => StatusCode::NOT_FOUND,
+                TagError::AlreadyExists(_) => StatusCode::CONFLICT,
+            };
+            (status, tag_error.to_string()).into_response()
+        } else if let Some(language_error) = self.0.downcast_ref::<LanguageError>() {
+            // LanguageError distinguishes unknown languages, duplicates and code changes
+            let status = match language_error {
+                LanguageError::UnknownLanguage(_) => StatusCode::NOT_FOUND,
+                LanguageError::AlreadyExists(_) => StatusCode::CONFLICT,
+                LanguageError::CodeChange(_) => StatusCode::BAD_REQUEST,
+            };
+            (status, language_error.to_string()).into_response()
+        } else if let Some(safety_error) = self.0.downcast_ref::<SafetyError>() {
+            // SafetyError should return 403 Forbidden
+            (StatusCode::FORBIDDEN, safety_error.to_string()).into_response()
+        } else if let Some(profile_error) = self.0.downcast_ref::<ProfileError>() {
+            // ProfileError should return 400 Bad Request naming the character
+            (StatusCode::BAD_REQUEST, profile_error.to_string()).into_response()
+        } else if let Some(import_error) = self.0.downcast_ref::<ImportError>() {
+            // ImportError should return 400 Bad Request, rows are reported one by one
+            (StatusCode::BAD_REQUEST, import_error.to_string()).into_response()
+        } else if let Some(patch_error) = self.0.downcast_ref::<PatchError>() {
+            // PatchError distinguishes bad documents and patches making a malformed word
+            let status = match patch_error {
+                PatchError::NotAnObject => StatusCode::BAD_REQUEST,
+                PatchError::UnknownField(_) | PatchError::MalformedWord(_) => {
+                    StatusCode::UNPROCESSABLE_ENTITY
+                }
+            };
+            (status, patch_error.to_string()).into_response()
+        } else if let Some(precondition_error) = self.0.downcast_ref::<PreconditionError>() {
+            // PreconditionError should return 412 Precondition Failed
+            (
+                StatusCode::PRECONDITION_FAILED,
+                precondition_error.to_string(),
+            )
+                .into_response()
+        } else if let Some(trash_error) = self.0.downcast_ref::<TrashError>() {
+            // TrashError should return 409 Conflict, the lemma is still taken
+            (StatusCode::CONFLICT, trash_error.to_string()).into_response()
+        } else if let Some(revision_error) = self.0.downcast_ref::<RevisionError>() {
+            // RevisionError should return 404 Not Found
+            (StatusCode::NOT_FOUND, revision_error.to_string()).into_response()
+        } else if let Some(proposal_error) = self.0.downcast_ref::<ProposalError>() {
+            // ProposalError distinguishes unknown proposals, reviews out of turn and missing comments
+            let status = match proposal_error {
+                ProposalError::UnknownProposal(_) => StatusCode::NOT_FOUND,
+                ProposalError::NotPending(_) | ProposalError::Closed(_) => StatusCode::CONFLICT,
+                ProposalError::MissingComment => StatusCode::BAD_REQUEST,
+            };
+            (status, proposal_error.to_string()).into_response()
+        } else if let Some(report_error) = self.0.downcast_ref::<ReportError>() {
+            // ReportError distinguishes unknown reports and reports already closed
+            let status = match report_error {
+                ReportError::UnknownReport(_) => StatusCode::NOT_FOUND,
+                ReportError::NotOpen(_) => StatusCode::CONFLICT,
+            };
+            (status, report_error.to_string()).into_response()
+        } else if let Some(user_error) = self.0.downcast_ref::<UserError>() {
+            // UserError should return 404 Not Found
+            (StatusCode::NOT_FOUND, user_error.to_string()).into_response()
+        } else if let Some(query_error) = self.0.downcast_ref::<QueryError>() {
+            // QueryError should return 400 Bad Request
+            (StatusCode::BAD_REQUEST, query_error.to_string()).into_response()
         } else {
             // All other errors return 500 Internal Server Error
             (
@@ -136,6 +247,272 @@ pub enum AuthError {
     InternalError(#[from] anyhow::Error),
 }
 
+/// Word ladder errors for solving and generating puzzles
+///
+/// Handles lemmas that can't be connected and puzzle requests that
+/// the current dictionary can't satisfy.
+#[derive(thiserror::Error, Debug)]
+pub enum LadderError {
+    /// Start and end lemmas don't have the same number of letters
+    #[error("'{0}' and '{1}' must have the same length")]
+    LengthMismatch(String, String),
+    /// Requested ladder length is outside of the supported range
+    #[error("invalid ladder length: {0}")]
+    InvalidLength(usize),
+    /// Lemma is not in the dictionary or can't take part in a ladder
+    #[error("word not found in dictionary: {0}")]
+    UnknownWord(String),
+    /// Both lemmas exist but no one-letter-change path connects them
+    #[error("no ladder connects '{0}' and '{1}'")]
+    NoLadder(String, String),
+    /// No ladder of the requested length exists in the dictionary
+    #[error("no ladder of length {0} found")]
+    NoPuzzle(usize),
+}
+
+/// Daily challenge errors for retrieval, submissions and leaderboards
+///
+/// Handles malformed or unavailable challenge dates, invalid submissions,
+/// and duplicate or replayed submissions.
+#[derive(thiserror::Error, Debug)]
+pub enum ChallengeError {
+    /// Date in URL path is not `today` or a `YYYY-MM-DD` date
+    #[error("invalid challenge date: {0}")]
+    InvalidDate(String),
+    /// Challenge date is in the future
+    #[error("challenge for {0} is not available yet")]
+    FutureDate(chrono::NaiveDate),
+    /// Submissions are only accepted for today's challenge
+    #[error("challenge for {0} is closed for submissions")]
+    Closed(chrono::NaiveDate),
+    /// Number of answers doesn't match the number of challenge words
+    #[error("expected {expected} answers, got {got}")]
+    AnswerCount { expected: usize, got: usize },
+    /// User already submitted results for this challenge
+    #[error("results already submitted for this challenge")]
+    AlreadySubmitted,
+    /// Access token was already used for a previous submission
+    #[error("authentication token already used for a submission")]
+    ReplayedToken,
+    /// Dictionary has no words to build a challenge from
+    #[error("no words available for a challenge")]
+    NoWords,
+}
+
+/// Word lookup errors for `/{lang}/words/{lemma}/...` endpoints
+///
+/// Handles lemmas that aren't in the dictionary of the requested language.
+#[derive(thiserror::Error, Debug)]
+pub enum LookupError {
+    /// Lemma is not in the dictionary
+    #[error("word not found in dictionary: {0}")]
+    UnknownWord(String),
+}
+
+/// Word relation errors for the administrative relation endpoints
+///
+/// Handles relations that can't be stored and relations that don't exist.
+#[derive(thiserror::Error, Debug)]
+pub enum RelationError {
+    /// Word and related word are the same
+    #[error("a word can't be related to itself")]
+    SelfRelation,
+    /// Relation doesn't exist or doesn't involve the word
+    #[error("relation not found: {0}")]
+    UnknownRelation(u32),
+    /// Both words are already related with this kind
+    #[error("words are already related as {0}")]
+    AlreadyRelated(String),
+}
+
+/// Translation errors for the administrative translation endpoints
+///
+/// Handles links within a single language, unknown senses, links that
+/// don't exist and words that are already linked.
+#[derive(thiserror::Error, Debug)]
+pub enum TranslationError {
+    /// Word and translation belong to the same language
+    #[error("a translation must belong to another language")]
+    SameLanguage,
+    /// Word has no sense at this position
+    #[error("sense not found: {0}")]
+    UnknownSense(u32),
+    /// Link doesn't exist or doesn't involve the word
+    #[error("translation not found: {0}")]
+    UnknownTranslation(u32),
+    /// Both words are already linked as translations
+    #[error("words are already linked as translations")]
+    AlreadyTranslated,
+}
+
+/// Tag errors for the administrative tag endpoints
+///
+/// Handles tags that don't exist and tag names already in use.
+#[derive(thiserror::Error, Debug)]
+pub enum TagError {
+    /// No tag has this name or ID
+    #[error("tag not found: {0}")]
+    UnknownTag(String),
+    /// A tag with this name already exists
+    #[error("tag already exists: {0}")]
+    AlreadyExists(String),
+}
+
+/// Language registry errors for administrative endpoints
+///
+/// Handles languages that aren't registered, codes already in use and
+/// attempts to change the code of a language.
+#[derive(thiserror::Error, Debug)]
+pub enum LanguageError {
+    /// No language is registered with this code
+    #[error("language not found: {0}")]
+    UnknownLanguage(String),
+    /// A language with this code is already registered
+    #[error("language already exists: {0}")]
+    AlreadyExists(String),
+    /// The code of a registered language can't be changed
+    #[error("the code of language '{0}' can't be changed")]
+    CodeChange(String),
+}
+
+/// Safe mode errors for public endpoints
+///
+/// Handles callers asking for flagged words without the role to see them.
+#[derive(thiserror::Error, Debug)]
+pub enum SafetyError {
+    /// Safe mode can only be turned off by privileged callers
+    #[error("safe mode can only be turned off by administrators")]
+    SafeModeRequired,
+}
+
+/// Validation profile errors for words
+///
+/// Handles text of a word that isn't written in the scripts or the phoneme
+/// inventory of its language.
+#[derive(thiserror::Error, Debug)]
+pub enum ProfileError {
+    /// A field holds a character the profile of the language doesn't allow
+    #[error("{field}: character '{character}' (U+{:04X}) is not allowed by the {profile} validation profile", *character as u32)]
+    InvalidCharacter {
+        field: String,
+        character: char,
+        profile: String,
+    },
+}
+
+/// Bulk import errors
+///
+/// Handles request bodies that can't be split into rows at all. Problems
+/// with single rows are part of the import report instead.
+#[derive(thiserror::Error, Debug)]
+pub enum ImportError {
+    /// The body isn't a CSV file, JSON array or NDJSON stream of the format
+    #[error("malformed {0} body: {1}")]
+    MalformedBody(String, String),
+}
+
+/// Partial update errors
+///
+/// Handles JSON Merge Patch documents that can't be applied to a word.
+#[derive(thiserror::Error, Debug)]
+pub enum PatchError {
+    /// The patch document isn't a JSON object
+    #[error("a merge patch must be a JSON object")]
+    NotAnObject,
+    /// The patch names a field words don't have
+    #[error("unknown field: {0}")]
+    UnknownField(String),
+    /// The patched word doesn't have the shape of a word
+    #[error("malformed patched word: {0}")]
+    MalformedWord(String),
+}
+
+/// Conditional request errors
+///
+/// Handles writes whose `If-Match` precondition doesn't hold.
+#[derive(thiserror::Error, Debug)]
+pub enum PreconditionError {
+    /// The word was changed or deleted since the client read it
+    #[error("the word has changed since it was read")]
+    VersionMismatch,
+}
+
+/// Trash errors
+///
+/// Handles writes clashing with deleted words waiting in the trash.
+#[derive(thiserror::Error, Debug)]
+pub enum TrashError {
+    /// A deleted word still holds the lemma until it is restored or purged
+    #[error("'{0}' is in the trash, restore or purge it first")]
+    InTrash(String),
+}
+
+/// Word history errors
+///
+/// Handles reverts to revisions that aren't in the history of the word.
+#[derive(thiserror::Error, Debug)]
+pub enum RevisionError {
+    /// The revision doesn't exist or belongs to another word
+    #[error("revision not found in the history of the word: #{0}")]
+    UnknownRevision(u32),
+}
+
+/// Editorial review errors
+///
+/// Handles proposals that don't exist or aren't in a state allowing the
+/// requested review or revision.
+#[derive(thiserror::Error, Debug)]
+pub enum ProposalError {
+    /// No proposal of the language has this ID, or it belongs to another editor
+    #[error("proposal not found: #{0}")]
+    UnknownProposal(u32),
+    /// Only pending proposals can be approved, rejected or sent back
+    #[error("proposal #{0} isn't pending review")]
+    NotPending(u32),
+    /// Approved and rejected proposals can't be revised anymore
+    #[error("proposal #{0} was already approved or rejected")]
+    Closed(u32),
+    /// Rejections and change requests must tell the editor why
+    #[error("a comment is required to reject a proposal or request changes")]
+    MissingComment,
+}
+
+/// Problem report triage errors
+///
+/// Handles reports that don't exist or were already resolved or dismissed.
+#[derive(thiserror::Error, Debug)]
+pub enum ReportError {
+    /// No report about a word of the language has this ID
+    #[error("report not found: #{0}")]
+    UnknownReport(u32),
+    /// Only open reports can be resolved or dismissed
+    #[error("report #{0} was already resolved or dismissed")]
+    NotOpen(u32),
+}
+
+/// User management errors for administrative endpoints
+///
+/// Handles users that don't exist.
+#[derive(thiserror::Error, Debug)]
+pub enum UserError {
+    /// No user has this ID
+    #[error("user not found: #{0}")]
+    UnknownUser(i64),
+}
+
+/// Query string validation errors for API endpoints
+///
+/// Handles query parameters whose values are outside of the supported set.
+#[derive(thiserror::Error, Debug)]
+pub enum QueryError {
+    /// Query parameter has an unsupported value
+    #[error("invalid value for '{0}': {1}")]
+    InvalidParameter(String, String),
+    /// Query parameter required by another one is absent
+    #[error("missing value for '{0}'")]
+    MissingParameter(String),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +567,252 @@ mod tests {
         assert!(format!("{}", app_error.0).contains("invalid language code"));
     }
 
+    #[test]
+    fn test_ladder_error_http_responses() {
+        let error = AppError::from(LadderError::LengthMismatch(
+            "cold".to_string(),
+            "hot".to_string(),
+        ));
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(LadderError::InvalidLength(42));
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(LadderError::UnknownWord("zzzz".to_string()));
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+
+        let error = AppError::from(LadderError::NoPuzzle(12));
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_challenge_error_http_responses() {
+        let error = AppError::from(ChallengeError::InvalidDate("nope".to_string()));
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(ChallengeError::AnswerCount {
+            expected: 10,
+            got: 3,
+        });
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(ChallengeError::NoWords);
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+
+        let error = AppError::from(ChallengeError::AlreadySubmitted);
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+
+        let error = AppError::from(ChallengeError::ReplayedToken);
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_lookup_error_http_responses() {
+        let error = LookupError::UnknownWord("zzzz".to_string());
+        assert_eq!(error.to_string(), "word not found in dictionary: zzzz");
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_relation_error_http_responses() {
+        let error = AppError::from(RelationError::SelfRelation);
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(RelationError::UnknownRelation(7));
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+
+        let error = RelationError::AlreadyRelated("synonym".to_string());
+        assert_eq!(error.to_string(), "words are already related as synonym");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_translation_error_http_responses() {
+        let error = AppError::from(TranslationError::SameLanguage);
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(TranslationError::UnknownSense(3));
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let error = AppError::from(TranslationError::UnknownTranslation(7));
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+
+        let error = TranslationError::AlreadyTranslated;
+        assert_eq!(
+            error.to_string(),
+            "words are already linked as translations"
+        );
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_tag_error_http_responses() {
+        let error = TagError::UnknownTag("animals".to_string());
+        assert_eq!(error.to_string(), "tag not found: animals");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let error = AppError::from(TagError::AlreadyExists("food".to_string()));
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_language_error_http_responses() {
+        let error = LanguageError::UnknownLanguage("de".to_string());
+        assert_eq!(error.to_string(), "language not found: de");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let error = AppError::from(LanguageError::AlreadyExists("de".to_string()));
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+
+        let error = AppError::from(LanguageError::CodeChange("de".to_string()));
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_safety_error_http_responses() {
+        let error = SafetyError::SafeModeRequired;
+        assert_eq!(
+            error.to_string(),
+            "safe mode can only be turned off by administrators"
+        );
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_profile_error_http_responses() {
+        let error = ProfileError::InvalidCharacter {
+            field: "word".to_string(),
+            character: 'λ',
+            profile: "latin".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "word: character 'λ' (U+03BB) is not allowed by the latin validation profile"
+        );
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_import_error_http_responses() {
+        let error =
+            ImportError::MalformedBody("csv".to_string(), "unknown column 'lemma'".to_string());
+        assert_eq!(
+            error.to_string(),
+            "malformed csv body: unknown column 'lemma'"
+        );
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_patch_error_http_responses() {
+        let error = PatchError::UnknownField("definition".to_string());
+        assert_eq!(error.to_string(), "unknown field: definition");
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = AppError::from(PatchError::NotAnObject).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_precondition_error_http_responses() {
+        let error = PreconditionError::VersionMismatch;
+        assert_eq!(error.to_string(), "the word has changed since it was read");
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[test]
+    fn test_revision_error_http_responses() {
+        let error = RevisionError::UnknownRevision(7);
+        assert_eq!(
+            error.to_string(),
+            "revision not found in the history of the word: #7"
+        );
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_proposal_error_http_responses() {
+        let error = ProposalError::UnknownProposal(3);
+        assert_eq!(error.to_string(), "proposal not found: #3");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let error = AppError::from(ProposalError::NotPending(3));
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+
+        let error = AppError::from(ProposalError::Closed(3));
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+
+        let error = AppError::from(ProposalError::MissingComment);
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_report_error_http_responses() {
+        let error = ReportError::UnknownReport(4);
+        assert_eq!(error.to_string(), "report not found: #4");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let error = AppError::from(ReportError::NotOpen(4));
+        assert_eq!(error.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_user_error_http_responses() {
+        let error = UserError::UnknownUser(7);
+        assert_eq!(error.to_string(), "user not found: #7");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_trash_error_http_responses() {
+        let error = TrashError::InTrash("cat".to_string());
+        assert_eq!(
+            error.to_string(),
+            "'cat' is in the trash, restore or purge it first"
+        );
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_query_error_http_responses() {
+        let error =
+            QueryError::InvalidParameter("pronunciation_format".to_string(), "x".to_string());
+        assert_eq!(
+            error.to_string(),
+            "invalid value for 'pronunciation_format': x"
+        );
+
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let error = QueryError::MissingParameter("to".to_string());
+        assert_eq!(error.to_string(), "missing value for 'to'");
+        let response = AppError::from(error).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[test]
     fn test_error_chain_preservation() {
         // Create a chain of errors
@@ -1,27 +1,65 @@
 //! Administrative word management endpoints.
 //!
-//! Provides CRUD operations for word database management and user management.
+//! Provides CRUD operations for word database management, word relations,
+//! translations, tags, the trash of deleted words, the review of proposals
+//! from editors, the triage of problem reports, the language registry and
+//! user management.
 //! All endpoints require authentication and return JSON responses.
+//! Successful writes drop the word ladder index of the affected language,
+//! rebuilt on the next ladder request.
 
 use crate::error::AppError;
+use crate::models::export::{export_words, ExportMetadata, ExportParams};
+use crate::models::import::{import_words, ImportParams, ImportReport};
+use crate::models::language::{Language, UpsertLanguage};
+use crate::models::listing::ListParams;
+use crate::models::proposal::{ProposalParams, ProposalReview, ProposalStatus, WordProposal};
+use crate::models::relation::{UpsertRelation, WordRelation};
+use crate::models::report::{
+    ReportParams, ReportResolution, ReportStatus, ReportedWord, WordReport,
+};
+use crate::models::revision::WordRevision;
+use crate::models::tag::{Tag, TagParams, UpsertTag};
+use crate::models::translation::{UpsertTranslation, WordTranslation};
+use crate::models::trash::{PurgeReport, TrashedWord};
+use crate::models::user::{AuthUser, User};
+use crate::models::version::{http_date, IfMatch};
 use crate::models::word::{UpsertWord, Word};
 use crate::state::AppState;
-use axum::extract::{Path, State};
+use axum::body::{Body, Bytes};
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
 
-/// Lists all words in the database.
+/// Lists the words of a language, one page at a time.
 ///
-/// Returns every word stored in the database without filtering or pagination.
+/// Returns a page of words sorted by lemma, creation or last change time.
+/// The `Link` header holds the `first` page and, unless this is the last
+/// page, the `next` one, and `X-Total-Count` the number of words matching
+/// the filters in every page.
 ///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `limit` - Optional page size (1-500, default: 50)
+/// * `cursor` - Optional cursor of the page, from the `next` link
+/// * `sort` - Optional sort field: 'word' (default), 'created_at' or 'updated_at'
+/// * `order` - Optional sort direction: 'asc' (default) or 'desc'
+/// * `word_type` - Optional grammatical type of the listed words
+/// * `contains` - Optional text the lemmas must contain
+/// * `created_after` / `created_before` - Optional creation time range
+/// * `updated_after` / `updated_before` - Optional last change time range,
+///   leaving out words never changed
+///
+/// Time bounds are exclusive dates (`YYYY-MM-DD`) or dates and times
+/// (`YYYY-MM-DDTHH:MM:SS`), in UTC.
 ///
 /// # Returns
 ///
-/// * `200 OK` - JSON array containing all words with complete information
-/// * `404 Not Found` - Database is empty or no words are available
+/// * `200 OK` - JSON array of the words of the page, possibly empty
+/// * `400 Bad Request` - Unknown language, sort, order or type, malformed
+///   time bound, or a cursor made for another sort
 /// * `500 Internal Server Error` - Database connection or query error
 #[utoipa::path(
     get,
@@ -33,21 +71,57 @@ use axum::Json;
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "Listed every single word successfully", body = [Word]),
+        (status = 200, description = "Listed a page of words successfully", body = [Word],
+            headers(
+                ("Link" = String, description = "Links to the first and next pages"),
+                ("X-Total-Count" = i64, description = "Number of words matching the filters"),
+            )
+        ),
+        (status = 400, description = "Bad request - Invalid sort, filter or cursor"),
         (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
         (status = 403, description = "Forbidden - Admin privileges required"),
-        (status = 404, description = "Couldn't list words. Does your database contain any?"),
         (status = 500, description = "Internal server error")
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word operations. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ListParams,
     )
 )]
 pub async fn word_list(
     State(state): State<AppState>,
     Path(lang): Path<String>,
-) -> Result<Json<Vec<Word>>, AppError> {
-    Word::list(state.dbpool, &lang).await.map(Json::from)
+    OriginalUri(uri): OriginalUri,
+    Query(params): Query<ListParams>,
+) -> Result<(HeaderMap, Json<Vec<Word>>), AppError> {
+    let page = Word::page(state.dbpool, &lang, &params).await?;
+
+    // links keep the other query parameters as they were sent
+    let others: Vec<&str> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !pair.starts_with("cursor="))
+        .collect();
+    let link = |cursor: Option<String>| {
+        let pairs: Vec<String> = others
+            .iter()
+            .map(|pair| pair.to_string())
+            .chain(cursor.map(|token| format!("cursor={token}")))
+            .collect();
+        match pairs.is_empty() {
+            true => uri.path().to_string(),
+            false => format!("{}?{}", uri.path(), pairs.join("&")),
+        }
+    };
+    let mut links = vec![format!("<{}>; rel=\"first\"", link(None))];
+    if let Some(next) = &page.next {
+        links.push(format!("<{}>; rel=\"next\"", link(Some(next.encode()))));
+    }
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::LINK, links.join(", ").parse()?);
+    headers.insert("x-total-count", page.total.into());
+    Ok((headers, Json(page.words)))
 }
 
 /// Creates a new word entry in the database.
@@ -57,17 +131,29 @@ pub async fn word_list(
 ///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
 ///
 /// # Request Body
 ///
-/// JSON object with required fields: `word`, `definition`, `pronunciation`, `wordType`.
+/// JSON object with required fields: `word`, `senses`, `pronunciations`, `wordType`.
+/// Each sense has a `definition`, a `partOfSpeech` and an optional `example`.
+/// Optional `examples` hold a `sentence` with the `targetStart` and `targetEnd`
+/// character offsets of the word, and `usageNote` a note on register or usage.
+/// Optional `inflections` hold a `kind` ('plural', 'past_tense', 'past_participle',
+/// 'comparative' or 'superlative', as applicable to the word) and a `form`.
+/// Optional `flags` mark the word as 'offensive', 'mature', 'archaic' or 'slang',
+/// which keeps it out of public endpoints in safe mode.
+/// Each pronunciation has an IPA `pronunciation` and an optional `variant` label
+/// (defaults to "general"); the first one is the primary pronunciation.
 /// All fields must pass validation (valid lemma, dictionary text, IPA notation, allowed grammatical types).
+/// Text is normalized to Unicode NFC and must be written in the scripts and
+/// phoneme inventory of the validation profile of the language.
 ///
 /// # Returns
 ///
 /// * `200 OK` - Word successfully created with generated ID
+/// * `400 Bad Request` - A character isn't allowed by the validation profile of the language
+/// * `409 Conflict` - The lemma is held by a word in the trash
 /// * `415 Unsupported Media Type` - Invalid content type
 /// * `422 Unprocessable Entity` - Validation failed
 /// * `500 Internal Server Error` - Database error
@@ -80,27 +166,31 @@ pub async fn word_list(
     security(
         ("bearer_auth" = [])
     ),
-    request_body(content = UpsertWord, description = "Word data to add to the database with validation. Must include word, definition, pronunciation, and word_type fields", content_type = "application/json"),
+    request_body(content = UpsertWord, description = "Word data to add to the database with validation. Must include word, senses, pronunciations, and word_type fields", content_type = "application/json"),
     responses(
         (status = 200, description = "Word successfully created and added to the database", body = [Word]),
+        (status = 400, description = "Bad request - A character isn't allowed by the validation profile of the language"),
         (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
         (status = 403, description = "Forbidden - Admin privileges required"),
-        (status = 415, description = "Please provide a valid word with all required fields (word, definition, pronunciation, word_type) in your JSON body"),
-        (status = 422, description = "Validation failed - ensure word, definition, pronunciation are properly formatted and word_type is one of: noun, verb, adjective, adverb"),
+        (status = 409, description = "The lemma is held by a word in the trash"),
+        (status = 415, description = "Please provide a valid word with all required fields (word, senses, pronunciations, word_type) in your JSON body"),
+        (status = 422, description = "Validation failed - ensure word, senses, pronunciations are properly formatted and word_type is one of: noun, verb, adjective, adverb"),
         (status = 500, description = "Internal server error"),
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word creation. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
+        ("lang" = String, Path, description = "Language code for word creation, as registered by administrators (e.g. 'en' for English).", example = "en"),
     )
 )]
 pub async fn word_create(
     Path(lang): Path<String>,
     State(state): State<AppState>,
+    user: Option<AuthUser>,
     Json(word): Json<UpsertWord>,
 ) -> Result<Json<Vec<Word>>, AppError> {
-    Word::create(state.dbpool, &lang, word)
-        .await
-        .map(Json::from)
+    let created = Word::create(state.dbpool.clone(), &lang, word, user.as_ref()).await?;
+    state.ladders.invalidate(&lang);
+
+    Ok(Json::from(created))
 }
 
 /// Retrieves a specific word by its database ID.
@@ -108,15 +198,20 @@ pub async fn word_create(
 /// Fetches a single word using its unique identifier. Provides administrators
 /// access to complete word records including metadata.
 ///
+/// The `ETag` header holds the version of the word, to send back in
+/// `If-Match` when changing it, and `Last-Modified` the time of its last
+/// change. A cached copy can be revalidated with `If-None-Match` or
+/// `If-Modified-Since`.
+///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
 /// * `id` - Unique database identifier of the word to retrieve
 ///
 /// # Returns
 ///
 /// * `200 OK` - Word found and returned with all fields
+/// * `304 Not Modified` - The cached copy of the word is still current
 /// * `404 Not Found` - No word exists with specified ID
 /// * `500 Internal Server Error` - Database connection or query error
 #[utoipa::path(
@@ -129,22 +224,45 @@ pub async fn word_create(
         ("bearer_auth" = [])
     ),
     responses (
-        (status = 200, description = "Word with specified ID returned successfully", body = [Word]),
+        (status = 200, description = "Word with specified ID returned successfully", body = [Word],
+            headers(
+                ("ETag" = String, description = "Version of the word"),
+                ("Last-Modified" = String, description = "Time of the last change of the word"),
+            )
+        ),
+        (status = 304, description = "The cached copy of the word is still current"),
         (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
         (status = 403, description = "Forbidden - Admin privileges required"),
         (status = 404, description = "Couldn't find the word with {id}"),
         (status = 500, description = "Internal server error"),
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word retrieval. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
         ("id" = u32, Path, description = "Unique database identifier of the word to retrieve", example = 1),
+        ("If-None-Match" = Option<String>, Header, description = "Entity tags of cached copies of the word"),
+        ("If-Modified-Since" = Option<String>, Header, description = "Time a cached copy of the word was last changed"),
     )
 )]
 pub async fn word_read(
     State(state): State<AppState>,
     Path((lang, id)): Path<(String, u32)>,
-) -> Result<Json<Vec<Word>>, AppError> {
-    Word::read(state.dbpool, &lang, id).await.map(Json::from)
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let words = Word::read(state.dbpool, &lang, id).await?;
+    let Some(word) = words.first() else {
+        return Ok(Json(words).into_response());
+    };
+
+    let header_value = |name| headers.get(name).and_then(|v| v.to_str().ok());
+    let not_modified = word.not_modified(
+        header_value(header::IF_NONE_MATCH),
+        header_value(header::IF_MODIFIED_SINCE),
+    );
+    let version = version_headers(word)?;
+    match not_modified {
+        true => Ok((StatusCode::NOT_MODIFIED, version).into_response()),
+        false => Ok((version, Json(words)).into_response()),
+    }
 }
 
 /// Updates an existing word entry in the database.
@@ -154,19 +272,34 @@ pub async fn word_read(
 ///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
 /// * `id` - Unique database identifier of the word to update
 ///
 /// # Request Body
 ///
-/// JSON object with required fields: `word`, `definition`, `pronunciation`, `wordType`.
-/// Must pass same validation as word creation.
+/// JSON object with required fields: `word`, `senses`, `pronunciations`, `wordType`.
+/// Each sense has a `definition`, a `partOfSpeech` and an optional `example`.
+/// Optional `examples` hold a `sentence` with the `targetStart` and `targetEnd`
+/// character offsets of the word, and `usageNote` a note on register or usage.
+/// Optional `inflections` hold a `kind` ('plural', 'past_tense', 'past_participle',
+/// 'comparative' or 'superlative', as applicable to the word) and a `form`.
+/// Optional `flags` mark the word as 'offensive', 'mature', 'archaic' or 'slang',
+/// which keeps it out of public endpoints in safe mode.
+/// Each pronunciation has an IPA `pronunciation` and an optional `variant` label
+/// (defaults to "general"); the first one is the primary pronunciation.
+/// Must pass same validation as word creation, including the validation
+/// profile of the language.
+///
+/// An `If-Match` header with the `ETag` of the word as it was read keeps
+/// the update from overwriting a change made in the meantime.
 ///
 /// # Returns
 ///
-/// * `200 OK` - Word successfully updated
+/// * `200 OK` - Word successfully updated, with its new `ETag`
+/// * `400 Bad Request` - A character isn't allowed by the validation profile of the language
 /// * `404 Not Found` - No word exists with specified ID
+/// * `412 Precondition Failed` - The word changed since the `If-Match` version
+/// * `409 Conflict` - The lemma is held by a word in the trash
 /// * `422 Unprocessable Entity` - Validation failed
 /// * `500 Internal Server Error` - Database error
 #[utoipa::path(
@@ -178,45 +311,149 @@ pub async fn word_read(
     security(
         ("bearer_auth" = [])
     ),
-    request_body(content = UpsertWord, description = "Word data to update in the database. Must include word, definition, pronunciation, and word_type fields", content_type = "application/json"),
+    request_body(content = UpsertWord, description = "Word data to update in the database. Must include word, senses, pronunciations, and word_type fields", content_type = "application/json"),
     responses (
         (status = 200, description = "Word with {id} updated successfully", body = [Word]),
+        (status = 400, description = "Bad request - A character isn't allowed by the validation profile of the language"),
         (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
         (status = 403, description = "Forbidden - Admin privileges required"),
         (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 409, description = "The lemma is held by a word in the trash"),
+        (status = 412, description = "The word changed since the If-Match version"),
         (status = 422, description = "Validation failed - invalid word data provided"),
         (status = 500, description = "Internal server error"),
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word update. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
+        ("lang" = String, Path, description = "Language code for word update, as registered by administrators (e.g. 'en' for English).", example = "en"),
         ("id" = u32, Path, description = "Unique database identifier of the word to update", example = 1),
+        ("If-Match" = Option<String>, Header, description = "ETag of the word as it was read"),
     )
 )]
 pub async fn word_update(
     State(state): State<AppState>,
     Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    headers: HeaderMap,
     Json(updated_word): Json<UpsertWord>,
-) -> Result<Json<Vec<Word>>, AppError> {
-    Word::update(state.dbpool, &lang, id, updated_word)
-        .await
-        .map(Json::from)
+) -> Result<(HeaderMap, Json<Vec<Word>>), AppError> {
+    let precondition = if_match(&headers);
+    let updated = Word::update(
+        state.dbpool.clone(),
+        &lang,
+        id,
+        updated_word,
+        user.as_ref(),
+        precondition.as_ref(),
+    )
+    .await?;
+    state.ladders.invalidate(&lang);
+
+    let version = match updated.first() {
+        Some(word) => version_headers(word)?,
+        None => HeaderMap::new(),
+    };
+    Ok((version, Json::from(updated)))
+}
+
+/// Partially updates an existing word entry in the database.
+///
+/// Applies a JSON Merge Patch (RFC 7396) to a word: only the fields of the
+/// patch change, fields set to `null` are cleared, and lists such as
+/// `senses` are replaced as a whole. Fixing a typo in a definition doesn't
+/// require resending the rest of the word.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word to update
+///
+/// # Request Body
+///
+/// JSON object with some of the fields of a word update, sent as
+/// `application/merge-patch+json` or `application/json`. The fields of the
+/// patch pass the same validation as in a word update, the other fields are
+/// kept as stored without being validated again.
+///
+/// An `If-Match` header with the `ETag` of the word as it was read keeps
+/// the patch from applying to a word changed in the meantime.
+///
+/// # Returns
+///
+/// * `200 OK` - Word successfully updated, with its new state and `ETag`
+/// * `400 Bad Request` - The patch isn't a JSON object, or a character isn't
+///   allowed by the validation profile of the language
+/// * `404 Not Found` - No word exists with specified ID
+/// * `412 Precondition Failed` - The word changed since the `If-Match` version
+/// * `409 Conflict` - The lemma is held by a word in the trash
+/// * `422 Unprocessable Entity` - Unknown field, or the patched word is malformed
+/// * `500 Internal Server Error` - Database error, or the patched word is invalid
+#[utoipa::path(
+    patch,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}",
+    operation_id = "admin_words_patch_by_id",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = Object, description = "Fields of the word to change, as a JSON Merge Patch", content_type = "application/merge-patch+json"),
+    responses (
+        (status = 200, description = "Word with {id} updated successfully", body = Word),
+        (status = 400, description = "Bad request - The patch isn't an object, or a character isn't allowed by the validation profile of the language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 409, description = "The lemma is held by a word in the trash"),
+        (status = 412, description = "The word changed since the If-Match version"),
+        (status = 422, description = "Unknown field, or malformed patched word"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word update, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word to update", example = 1),
+        ("If-Match" = Option<String>, Header, description = "ETag of the word as it was read"),
+    )
+)]
+pub async fn word_patch(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    headers: HeaderMap,
+    Json(patch): Json<serde_json::Value>,
+) -> Result<(HeaderMap, Json<Word>), AppError> {
+    let precondition = if_match(&headers);
+    let patched = Word::patch(
+        state.dbpool.clone(),
+        &lang,
+        id,
+        &patch,
+        user.as_ref(),
+        precondition.as_ref(),
+    )
+    .await?;
+    state.ladders.invalidate(&lang);
+
+    Ok((version_headers(&patched)?, Json(patched)))
 }
 
-/// Permanently removes a word from the database.
+/// Moves a word to the trash.
 ///
-/// Deletes a word record by its database ID. This operation is irreversible
-/// and permanently removes all associated data.
+/// The word is left out of every query from then on, but is kept with all
+/// of its data until it is restored or purged, at the latest once the
+/// trash retention is over. An `If-Match` header with the `ETag` of the
+/// word as it was read keeps a word changed in the meantime from being
+/// deleted.
 ///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
 /// * `id` - Unique database identifier of the word to delete
 ///
 /// # Returns
 ///
-/// * `200 OK` - Word successfully deleted
+/// * `200 OK` - Word successfully moved to the trash
 /// * `404 Not Found` - No word exists with specified ID
+/// * `412 Precondition Failed` - The word changed since the `If-Match` version
 /// * `500 Internal Server Error` - Database error during deletion
 #[utoipa::path(
     delete,
@@ -228,106 +465,2777 @@ pub async fn word_update(
         ("bearer_auth" = [])
     ),
     responses (
-        (status = 200, description = "Word successfully deleted from the database"),
+        (status = 200, description = "Word successfully moved to the trash"),
         (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
         (status = 403, description = "Forbidden - Admin privileges required"),
         (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 412, description = "The word changed since the If-Match version"),
         (status = 500, description = "Internal server error"),
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word deletion. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
+        ("lang" = String, Path, description = "Language code for word deletion, as registered by administrators (e.g. 'en' for English).", example = "en"),
         ("id" = u32, Path, description = "Unique database identifier of the word to delete", example = 1),
+        ("If-Match" = Option<String>, Header, description = "ETag of the word as it was read"),
     )
 )]
 pub async fn word_delete(
     State(state): State<AppState>,
     Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    headers: HeaderMap,
 ) -> Result<(), AppError> {
-    Word::delete(state.dbpool, &lang, id).await
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::error::{AppError, PathError};
-    use crate::models::word::{GrammaticalType, LanguageCode, UpsertWord};
-    use std::str::FromStr;
-
-    #[test]
-    fn test_parameter_extraction_logic() {
-        // Test language parameter validation logic used by handlers
-        let valid_lang = "en";
-        let language_result = LanguageCode::from_str(valid_lang);
-        assert!(language_result.is_ok());
-        assert_eq!(language_result.unwrap().table_name(), "words");
+    let precondition = if_match(&headers);
+    Word::delete(
+        state.dbpool.clone(),
+        &lang,
+        id,
+        user.as_ref(),
+        precondition.as_ref(),
+    )
+    .await?;
+    state.ladders.invalidate(&lang);
 
-        let invalid_lang = "xyz";
-        let language_result = LanguageCode::from_str(invalid_lang);
-        assert!(language_result.is_err());
+    Ok(())
+}
 
-        let valid_type = "noun";
-        let type_result = GrammaticalType::from_str(valid_type);
-        assert!(type_result.is_ok());
+/// Reads the `If-Match` precondition of a write to a word, if any
+fn if_match(headers: &HeaderMap) -> Option<IfMatch> {
+    headers
+        .get(header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .map(IfMatch::parse)
+}
 
-        let invalid_type = "determiner";
-        let type_result = GrammaticalType::from_str(invalid_type);
-        assert!(type_result.is_err());
+/// Returns the `ETag` and `Last-Modified` headers of a word
+fn version_headers(word: &Word) -> Result<HeaderMap, AppError> {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ETAG, word.etag().parse()?);
+    if let Some(time) = word.last_modified() {
+        headers.insert(header::LAST_MODIFIED, http_date(time).parse()?);
     }
+    Ok(headers)
+}
 
-    #[test]
-    fn test_error_conversion_logic() {
-        // Test error conversion used by handlers
-        let path_error = PathError::InvalidPath("invalid".to_string());
-        let app_error = AppError::from(path_error);
-        let error_debug = format!("{app_error:?}");
-        assert!(error_debug.contains("invalid") || error_debug.contains("InvalidPath"));
+/// Imports words in bulk from CSV, JSON or NDJSON.
+///
+/// Every row is validated like the body of a word creation, then all rows
+/// are applied in a single transaction. When a row is invalid nothing is
+/// written and the report lists the problems of each row. Duplicate lemmas
+/// are skipped: repeated rows, and in insert mode words already stored.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `format` - Optional format of the body: 'csv', 'json' or 'ndjson',
+///   taken from the `Content-Type` header ('text/csv', 'application/json' or
+///   'application/x-ndjson') if absent
+/// * `mode` - Optional import mode: 'insert' (default) adds new words,
+///   'upsert' also updates stored ones, 'replace' also moves the words of the
+///   language missing from the import to the trash, where they can be restored
+/// * `dry_run` - Optional flag checking and reporting the rows without
+///   writing them
+///
+/// # Request Body
+///
+/// A JSON array or NDJSON stream of word objects with the same fields as for
+/// word creation, or a CSV file with a header line naming some of the
+/// columns `word`, `wordType`, `senses`, `examples`, `usageNote`,
+/// `inflections`, `categories`, `flags` and `pronunciations`. CSV list
+/// columns hold JSON arrays. Imports are bounded by
+/// `import_body_limit_kilobytes` rather than the request body limit.
+///
+/// # Returns
+///
+/// * `200 OK` - Rows applied, or checked in a dry run, with the report
+/// * `400 Bad Request` - Unknown format or mode, or a body that can't be split into rows
+/// * `422 Unprocessable Entity` - Some rows are invalid, nothing was written, with the report
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/words/import",
+    operation_id = "admin_words_import",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(
+        description = "Words to import, as CSV, a JSON array or NDJSON",
+        content(
+            (Vec<UpsertWord> = "application/json"),
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )
+    ),
+    responses(
+        (status = 200, description = "Rows applied, or checked in a dry run", body = ImportReport),
+        (status = 400, description = "Bad request - Unknown format or mode, or malformed body"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 422, description = "Some rows are invalid and nothing was written", body = ImportReport),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for the imported words, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ImportParams,
+    )
+)]
+pub async fn word_import(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<ImportParams>,
+    user: Option<AuthUser>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<(StatusCode, Json<ImportReport>), AppError> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+    let format = params.format(content_type)?;
+    let report = import_words(
+        &state.dbpool,
+        &lang,
+        format,
+        params.mode()?,
+        params.dry_run(),
+        user.as_ref(),
+        &body,
+    )
+    .await?;
 
-        let word_type_error = PathError::InvalidWordType("preposition".to_string());
-        let app_error = AppError::from(word_type_error);
-        let error_debug = format!("{app_error:?}");
-        assert!(error_debug.contains("preposition") || error_debug.contains("InvalidWordType"));
+    if report.committed() {
+        state.ladders.invalidate(&lang);
     }
+    let status = match report.invalid() {
+        0 => StatusCode::OK,
+        _ => StatusCode::UNPROCESSABLE_ENTITY,
+    };
+    Ok((status, Json(report)))
+}
 
-    #[test]
-    fn test_data_transformation_logic() {
-        // Test data transformation logic used by handlers
-        let test_word = UpsertWord {
-            word: "TEST".to_string(),
-            definition: "A Test Definition".to_string(),
-            pronunciation: "/TEST/".to_string(),
-            word_type: "NOUN".to_string(),
-        };
+/// Exports the words of a language as CSV, NDJSON or JSON.
+///
+/// Streams the words while reading them from the database, so whole
+/// dictionaries can be exported for backups or versioning. Words are
+/// sorted by lemma and hold the same fields, in the same order, as for an
+/// import, so an export can be imported back as it is. Relations,
+/// translations and tags aren't exported.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `format` - Optional format: 'csv', 'ndjson' or 'json' (default)
+/// * `word_type` - Optional grammatical type of the exported words
+/// * `category` - Optional sub-category of the exported words
+/// * `tags` / `exclude_tags` - Optional tags the words must have, or not have
+///
+/// # Returns
+///
+/// * `200 OK` - The export, starting with its metadata: a `#` comment line
+///   in CSV, a `{"metadata": ...}` line in NDJSON, and the `metadata` field
+///   of the JSON object holding the `words` array
+/// * `400 Bad Request` - Unknown language, format, type, category or tag
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/words/export",
+    operation_id = "admin_words_export",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Words of the language, after the export metadata",
+            content(
+                (ExportMetadata = "application/json"),
+                (String = "text/csv"),
+                (String = "application/x-ndjson"),
+            )
+        ),
+        (status = 400, description = "Bad request - Unknown language, format, type, category or tag"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the exported words, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ExportParams,
+        TagParams,
+    )
+)]
+pub async fn word_export(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<ExportParams>,
+    Query(tags): Query<TagParams>,
+) -> Result<Response, AppError> {
+    let format = params.format()?;
+    let chunks = export_words(state.dbpool, &lang, format, &params, tags.filter()?).await?;
+    let stream = futures::stream::unfold(chunks, |mut chunks| async move {
+        chunks.recv().await.map(|chunk| (chunk, chunks))
+    });
 
-        // Test lowercase transformation that handlers perform
-        let lowercase_word = test_word.word.to_lowercase();
-        let lowercase_definition = test_word.definition.to_lowercase();
-        let lowercase_pronunciation = test_word.pronunciation.to_lowercase();
-        let lowercase_word_type = test_word.word_type.to_lowercase();
+    Response::builder()
+        .header(header::CONTENT_TYPE, format.content_type())
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"words-{lang}.{}\"",
+                format.format_name()
+            ),
+        )
+        .body(Body::from_stream(stream))
+        .map_err(AppError::from)
+}
 
-        assert_eq!(lowercase_word, "test");
-        assert_eq!(lowercase_definition, "a test definition");
-        assert_eq!(lowercase_pronunciation, "/test/");
-        assert_eq!(lowercase_word_type, "noun");
-    }
+/// Retrieves the revision history of a word.
+///
+/// Returns every recorded change to a word, most recent first, with the
+/// admin who made it, when, and the word before and after the change. The
+/// history of a word in the trash, or purged from it, can be read too.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+///
+/// # Returns
+///
+/// * `200 OK` - Revisions of the word
+/// * `400 Bad Request` - Unknown language
+/// * `404 Not Found` - No word of the language exists or has a history with the ID
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/history",
+    operation_id = "admin_words_history",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Revisions of the word listed successfully", body = [WordRevision]),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+    )
+)]
+pub async fn word_history(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+) -> Result<Json<Vec<WordRevision>>, AppError> {
+    let revisions = WordRevision::history(&state.dbpool, &lang, id).await?;
+    Ok(Json(revisions))
+}
 
-    #[test]
-    fn test_validation_logic() {
-        // Test validation logic used by handlers
-        let invalid_word = UpsertWord {
-            word: "".to_string(),
-            definition: "valid definition".to_string(),
-            pronunciation: "/valid/".to_string(),
-            word_type: "noun".to_string(),
-        };
+/// Reverts a word to the state left by one of its revisions.
+///
+/// The word gets back its lemma, type, senses, examples, usage note,
+/// inflections, categories, flags and pronunciations as they were after the
+/// revision, or as they were deleted for a deletion. Tags are kept as they
+/// are. The revert is recorded as a new revision, so it can be reverted
+/// too. A word in the trash must be restored first. An `If-Match` header
+/// with the `ETag` of the word as it was read keeps a word changed in the
+/// meantime from being reverted.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+/// * `revision_id` - Identifier of the revision, from the history of the word
+///
+/// # Returns
+///
+/// * `200 OK` - Word successfully reverted, with its new state and `ETag`
+/// * `404 Not Found` - No word exists with the ID, or the revision isn't one of its own
+/// * `409 Conflict` - The lemma is held by another word in the trash
+/// * `412 Precondition Failed` - The word changed since the `If-Match` version
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/history/{revision_id}/revert",
+    operation_id = "admin_words_history_revert",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Word successfully reverted", body = Word),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id}, or the revision in its history"),
+        (status = 409, description = "The lemma is held by a word in the trash"),
+        (status = 412, description = "The word changed since the If-Match version"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+        ("revision_id" = u32, Path, description = "Identifier of the revision to revert to", example = 1),
+        ("If-Match" = Option<String>, Header, description = "ETag of the word as it was read"),
+    )
+)]
+pub async fn word_revert(
+    State(state): State<AppState>,
+    Path((lang, id, revision_id)): Path<(String, u32, u32)>,
+    user: Option<AuthUser>,
+    headers: HeaderMap,
+) -> Result<(HeaderMap, Json<Word>), AppError> {
+    let precondition = if_match(&headers);
+    let reverted = WordRevision::revert(
+        state.dbpool.clone(),
+        &lang,
+        id,
+        revision_id,
+        user.as_ref(),
+        precondition.as_ref(),
+    )
+    .await?;
+    state.ladders.invalidate(&lang);
 
-        assert!(invalid_word.word().is_err());
+    Ok((version_headers(&reverted)?, Json(reverted)))
+}
 
-        let invalid_type_word = UpsertWord {
-            word: "valid".to_string(),
-            definition: "valid definition".to_string(),
-            pronunciation: "/valid/".to_string(),
-            word_type: "determiner".to_string(),
-        };
+/// Lists the review queue of a language.
+///
+/// Returns the proposals of editors in a review state, pending ones by
+/// default, oldest first, so they are reviewed in the order they came in.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `status` - Review state to list: 'pending' (default), 'approved',
+///   'rejected' or 'changes_requested'
+///
+/// # Returns
+///
+/// * `200 OK` - Proposals in the state, the list is empty when there are none
+/// * `400 Bad Request` - Unknown language or state
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/proposals",
+    operation_id = "admin_proposals_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Proposals listed successfully", body = [WordProposal]),
+        (status = 400, description = "Bad request - Unknown language or state"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposals, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ProposalParams,
+    )
+)]
+pub async fn proposal_queue(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<ProposalParams>,
+) -> Result<Json<Vec<WordProposal>>, AppError> {
+    let proposals = WordProposal::list(&state.dbpool, &lang, Some(params.status()?), None).await?;
+    Ok(Json(proposals))
+}
 
-        assert!(invalid_type_word.word_type().is_err());
+/// Approves a pending proposal.
+///
+/// Adds the proposed word to the dictionary, or applies the proposed change
+/// to the word, recorded in its history as made by the reviewing admin. A
+/// change to a word changed since it was proposed isn't applied; the editor
+/// can be asked to revise it instead.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the proposal
+///
+/// # Returns
+///
+/// * `200 OK` - Proposal approved, naming the written word
+/// * `404 Not Found` - No proposal of the language exists with the ID, or
+///   the word to change doesn't exist anymore
+/// * `409 Conflict` - The proposal isn't pending, or the lemma is held by a
+///   word in the trash
+/// * `412 Precondition Failed` - The word changed since it was proposed
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/proposals/{id}/approve",
+    operation_id = "admin_proposals_approve",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Proposal approved and applied", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the proposal with {id}, or the word it changes"),
+        (status = 409, description = "The proposal isn't pending, or the lemma is held by a word in the trash"),
+        (status = 412, description = "The word changed since it was proposed"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposal, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the proposal", example = 1),
+    )
+)]
+pub async fn proposal_approve(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::approve(&state.dbpool, &lang, id, user.as_ref()).await?;
+    state.ladders.invalidate(&lang);
+
+    Ok(Json(proposal))
+}
+
+/// Rejects a pending proposal.
+///
+/// Closes the proposal for good without applying it. The comment tells the
+/// editor why.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the proposal
+///
+/// # Request Body
+///
+/// JSON object with the required `comment`.
+///
+/// # Returns
+///
+/// * `200 OK` - Proposal rejected
+/// * `400 Bad Request` - The comment is missing or blank
+/// * `404 Not Found` - No proposal of the language exists with the ID
+/// * `409 Conflict` - The proposal isn't pending
+/// * `500 Internal Server Error` - Database error, or the comment is too long
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/proposals/{id}/reject",
+    operation_id = "admin_proposals_reject",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = ProposalReview, description = "Why the proposal is rejected", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Proposal rejected", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language, or missing comment"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the proposal with {id}"),
+        (status = 409, description = "The proposal isn't pending"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposal, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the proposal", example = 1),
+    )
+)]
+pub async fn proposal_reject(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    Json(review): Json<ProposalReview>,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::review(
+        &state.dbpool,
+        &lang,
+        id,
+        ProposalStatus::Rejected,
+        &review,
+        user.as_ref(),
+    )
+    .await?;
+    Ok(Json(proposal))
+}
+
+/// Sends a pending proposal back to its editor for changes.
+///
+/// The comment tells the editor what to change. The editor can then revise
+/// the proposal, which goes back to the review queue.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the proposal
+///
+/// # Request Body
+///
+/// JSON object with the required `comment`.
+///
+/// # Returns
+///
+/// * `200 OK` - Changes requested
+/// * `400 Bad Request` - The comment is missing or blank
+/// * `404 Not Found` - No proposal of the language exists with the ID
+/// * `409 Conflict` - The proposal isn't pending
+/// * `500 Internal Server Error` - Database error, or the comment is too long
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/proposals/{id}/request-changes",
+    operation_id = "admin_proposals_request_changes",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = ProposalReview, description = "What the editor should change", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Proposal sent back for changes", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language, or missing comment"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the proposal with {id}"),
+        (status = 409, description = "The proposal isn't pending"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposal, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the proposal", example = 1),
+    )
+)]
+pub async fn proposal_request_changes(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    Json(review): Json<ProposalReview>,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::review(
+        &state.dbpool,
+        &lang,
+        id,
+        ProposalStatus::ChangesRequested,
+        &review,
+        user.as_ref(),
+    )
+    .await?;
+    Ok(Json(proposal))
+}
+
+/// Lists the problem reports of a language.
+///
+/// Returns the reports in a triage state, open ones by default, oldest
+/// first, so they are triaged in the order they came in.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `status` - Triage state to list: 'open' (default), 'resolved' or
+///   'dismissed'
+///
+/// # Returns
+///
+/// * `200 OK` - Reports in the state, the list is empty when there are none
+/// * `400 Bad Request` - Unknown language or state
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/reports",
+    operation_id = "admin_reports_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Reports listed successfully", body = [WordReport]),
+        (status = 400, description = "Bad request - Unknown language or state"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the reported words, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ReportParams,
+    )
+)]
+pub async fn report_queue(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<ReportParams>,
+) -> Result<Json<Vec<WordReport>>, AppError> {
+    let reports = WordReport::list(&state.dbpool, &lang, params.status()?).await?;
+    Ok(Json(reports))
+}
+
+/// Lists the reported words of a language.
+///
+/// Groups the reports in a triage state, open ones by default, by word,
+/// with their number and distinct reasons. The most reported words come
+/// first, then the most recently reported.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `status` - Triage state of the reports: 'open' (default), 'resolved'
+///   or 'dismissed'
+///
+/// # Returns
+///
+/// * `200 OK` - Reported words, the list is empty when there are none
+/// * `400 Bad Request` - Unknown language or state
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/reports/words",
+    operation_id = "admin_reports_words",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Reported words listed successfully", body = [ReportedWord]),
+        (status = 400, description = "Bad request - Unknown language or state"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the reported words, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ReportParams,
+    )
+)]
+pub async fn reported_words(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<ReportParams>,
+) -> Result<Json<Vec<ReportedWord>>, AppError> {
+    let words = WordReport::grouped(&state.dbpool, &lang, params.status()?).await?;
+    Ok(Json(words))
+}
+
+/// Resolves an open problem report.
+///
+/// Closes the report once the problem with the word is fixed. The word
+/// itself is fixed through the word endpoints.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the report
+///
+/// # Request Body
+///
+/// JSON object with an optional `note`.
+///
+/// # Returns
+///
+/// * `200 OK` - Report resolved
+/// * `400 Bad Request` - Unknown language
+/// * `404 Not Found` - No report about a word of the language exists with the ID
+/// * `409 Conflict` - The report was already resolved or dismissed
+/// * `500 Internal Server Error` - Database error, or the note is too long
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/reports/{id}/resolve",
+    operation_id = "admin_reports_resolve",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = ReportResolution, description = "What was fixed", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Report resolved", body = WordReport),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the report with {id}"),
+        (status = 409, description = "The report was already resolved or dismissed"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the reported word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the report", example = 1),
+    )
+)]
+pub async fn report_resolve(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    Json(resolution): Json<ReportResolution>,
+) -> Result<Json<WordReport>, AppError> {
+    let report = WordReport::close(
+        &state.dbpool,
+        &lang,
+        id,
+        ReportStatus::Resolved,
+        &resolution,
+        user.as_ref(),
+    )
+    .await?;
+    Ok(Json(report))
+}
+
+/// Dismisses an open problem report.
+///
+/// Closes the report when there was nothing to fix.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the report
+///
+/// # Request Body
+///
+/// JSON object with an optional `note`.
+///
+/// # Returns
+///
+/// * `200 OK` - Report dismissed
+/// * `400 Bad Request` - Unknown language
+/// * `404 Not Found` - No report about a word of the language exists with the ID
+/// * `409 Conflict` - The report was already resolved or dismissed
+/// * `500 Internal Server Error` - Database error, or the note is too long
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/reports/{id}/dismiss",
+    operation_id = "admin_reports_dismiss",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = ReportResolution, description = "Why there was nothing to fix", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Report dismissed", body = WordReport),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the report with {id}"),
+        (status = 409, description = "The report was already resolved or dismissed"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the reported word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the report", example = 1),
+    )
+)]
+pub async fn report_dismiss(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+    Json(resolution): Json<ReportResolution>,
+) -> Result<Json<WordReport>, AppError> {
+    let report = WordReport::close(
+        &state.dbpool,
+        &lang,
+        id,
+        ReportStatus::Dismissed,
+        &resolution,
+        user.as_ref(),
+    )
+    .await?;
+    Ok(Json(report))
+}
+
+/// Lists the trash of a language.
+///
+/// Returns the deleted words of a language, most recently deleted first,
+/// with the time after which each one is purged for good.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+///
+/// # Returns
+///
+/// * `200 OK` - Words in the trash, the list is empty when there are none
+/// * `400 Bad Request` - Unknown language
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/words/trash",
+    operation_id = "admin_words_trash_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Words in the trash listed successfully", body = [TrashedWord]),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the trash, as registered by administrators (e.g. 'en' for English).", example = "en"),
+    )
+)]
+pub async fn trash_list(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+) -> Result<Json<Vec<TrashedWord>>, AppError> {
+    let retention_days = trash_retention_days(&state)?;
+    TrashedWord::list(&state.dbpool, &lang, retention_days)
+        .await
+        .map(Json::from)
+}
+
+/// Restores a word from the trash.
+///
+/// The word comes back with its ID and all of its data, as it was when it
+/// was deleted, with a new `ETag`.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the deleted word
+///
+/// # Returns
+///
+/// * `200 OK` - Word successfully restored, with its `ETag`
+/// * `404 Not Found` - No word of the language is in the trash with the ID
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/words/trash/{id}/restore",
+    operation_id = "admin_words_trash_restore",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Word successfully restored", body = Word),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} in the trash"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the trash, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the deleted word", example = 1),
+    )
+)]
+pub async fn trash_restore(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+) -> Result<(HeaderMap, Json<Word>), AppError> {
+    let restored = TrashedWord::restore(state.dbpool.clone(), &lang, id, user.as_ref()).await?;
+    state.ladders.invalidate(&lang);
+
+    Ok((version_headers(&restored)?, Json(restored)))
+}
+
+/// Permanently removes a word from the trash.
+///
+/// Purges a deleted word without waiting for the end of the retention,
+/// e.g. to use its lemma for a new word. This operation is irreversible and
+/// permanently removes all associated data, only the history of the word is
+/// kept, the purge being its last revision.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the deleted word
+///
+/// # Returns
+///
+/// * `200 OK` - Word successfully purged
+/// * `404 Not Found` - No word of the language is in the trash with the ID
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/{lang}/words/trash/{id}",
+    operation_id = "admin_words_trash_purge_by_id",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Word successfully purged from the database"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} in the trash"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the trash, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the deleted word", example = 1),
+    )
+)]
+pub async fn trash_purge(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: Option<AuthUser>,
+) -> Result<(), AppError> {
+    TrashedWord::purge(&state.dbpool, &lang, id, user.as_ref()).await
+}
+
+/// Purges the words in the trash for longer than the retention.
+///
+/// The server already does so every hour, this purges the trash of a
+/// language right away. The retention is `trash_retention_days` of the
+/// configuration.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+///
+/// # Returns
+///
+/// * `200 OK` - Number of words purged
+/// * `400 Bad Request` - Unknown language
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/{lang}/words/trash",
+    operation_id = "admin_words_trash_purge_expired",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Words past the retention purged", body = PurgeReport),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the trash, as registered by administrators (e.g. 'en' for English).", example = "en"),
+    )
+)]
+pub async fn trash_purge_expired(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    user: Option<AuthUser>,
+) -> Result<Json<PurgeReport>, AppError> {
+    let retention_days = trash_retention_days(&state)?;
+    TrashedWord::purge_expired(&state.dbpool, Some(&lang), retention_days, user.as_ref())
+        .await
+        .map(Json::from)
+}
+
+/// Reads the trash retention from the configuration
+fn trash_retention_days(state: &AppState) -> Result<u32, AppError> {
+    state
+        .apiconfig
+        .lock()
+        .map(|config| config.api_limits.trash_retention_days)
+        .map_err(|e| anyhow::anyhow!("couldn't lock the configuration: {e}").into())
+}
+
+/// Lists the relations of a word.
+///
+/// Returns the synonyms, antonyms, hypernyms and derived forms of a word.
+/// Synonyms and antonyms are listed from either of the related words.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+///
+/// # Returns
+///
+/// * `200 OK` - Relations of the word, the list is empty when there are none
+/// * `404 Not Found` - No word exists with specified ID
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/relations",
+    operation_id = "admin_word_relations_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Relations of the word with {id} listed successfully", body = [WordRelation]),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+    )
+)]
+pub async fn relation_list(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+) -> Result<Json<Vec<WordRelation>>, AppError> {
+    WordRelation::list(&state.dbpool, &lang, id)
+        .await
+        .map(Json::from)
+}
+
+/// Relates a word to another word.
+///
+/// Stores a typed relation from the word to the related word. Synonyms and
+/// antonyms only need to be stored once, from either word.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+///
+/// # Request Body
+///
+/// JSON object with required fields: `kind` ('synonym', 'antonym', 'hypernym'
+/// or 'derived') and `relatedId`, the database identifier of the related word.
+///
+/// # Returns
+///
+/// * `200 OK` - Relation successfully created
+/// * `400 Bad Request` - Word can't be related to itself
+/// * `404 Not Found` - No word exists with specified ID or related ID
+/// * `409 Conflict` - Words are already related with this kind
+/// * `500 Internal Server Error` - Database error or validation failed
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/relations",
+    operation_id = "admin_word_relations_create",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertRelation, description = "Relation kind and related word ID", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Relation successfully created", body = [WordRelation]),
+        (status = 400, description = "A word can't be related to itself"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} or the related word"),
+        (status = 409, description = "Words are already related with this kind"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+    )
+)]
+pub async fn relation_create(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    Json(relation): Json<UpsertRelation>,
+) -> Result<Json<Vec<WordRelation>>, AppError> {
+    WordRelation::create(&state.dbpool, &lang, id, relation)
+        .await
+        .map(Json::from)
+}
+
+/// Removes a relation of a word.
+///
+/// Synonyms and antonyms can be removed from either of the related words.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+/// * `relation_id` - Unique database identifier of the relation
+///
+/// # Returns
+///
+/// * `200 OK` - Relation successfully deleted
+/// * `404 Not Found` - No word or relation of the word exists with specified IDs
+/// * `500 Internal Server Error` - Database error during deletion
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/relations/{relation_id}",
+    operation_id = "admin_word_relations_delete",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Relation successfully deleted"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} or its relation with {relation_id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+        ("relation_id" = u32, Path, description = "Unique database identifier of the relation", example = 1),
+    )
+)]
+pub async fn relation_delete(
+    State(state): State<AppState>,
+    Path((lang, id, relation_id)): Path<(String, u32, u32)>,
+) -> Result<(), AppError> {
+    WordRelation::delete(&state.dbpool, &lang, id, relation_id).await
+}
+
+/// Lists the translations of a word.
+///
+/// Returns the translations of a word into other languages, whichever of
+/// the two words they were linked from.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+///
+/// # Returns
+///
+/// * `200 OK` - Translations of the word, the list is empty when there are none
+/// * `404 Not Found` - No word exists with specified ID
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/translations",
+    operation_id = "admin_word_translations_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Translations of the word with {id} listed successfully", body = [WordTranslation]),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+    )
+)]
+pub async fn translation_list(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+) -> Result<Json<Vec<WordTranslation>>, AppError> {
+    WordTranslation::list(&state.dbpool, &lang, id)
+        .await
+        .map(Json::from)
+}
+
+/// Links a word to its translation.
+///
+/// Stores a link from the word to a word of another language. Links hold
+/// in both directions and only need to be stored once, from either word.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+///
+/// # Request Body
+///
+/// JSON object with the required field `translationId`, the database
+/// identifier of the translation, and the optional fields `sense` and
+/// `translationSense`, the positions of the senses of either word the link
+/// is for (0 for the primary sense).
+///
+/// # Returns
+///
+/// * `200 OK` - Translation successfully linked
+/// * `400 Bad Request` - Translation of the same language, or unknown sense
+/// * `404 Not Found` - No word exists with specified ID or translation ID
+/// * `409 Conflict` - Words are already linked as translations
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/translations",
+    operation_id = "admin_word_translations_create",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertTranslation, description = "Translation word ID and optional sense positions", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Translation successfully linked", body = [WordTranslation]),
+        (status = 400, description = "A translation must belong to another language and senses must exist"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} or the translation"),
+        (status = 409, description = "Words are already linked as translations"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+    )
+)]
+pub async fn translation_create(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    Json(translation): Json<UpsertTranslation>,
+) -> Result<Json<Vec<WordTranslation>>, AppError> {
+    WordTranslation::create(&state.dbpool, &lang, id, translation)
+        .await
+        .map(Json::from)
+}
+
+/// Removes a translation link of a word.
+///
+/// Links can be removed from either of the linked words.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+/// * `link_id` - Unique database identifier of the translation link
+///
+/// # Returns
+///
+/// * `200 OK` - Translation link successfully deleted
+/// * `404 Not Found` - No word or translation link of the word exists with specified IDs
+/// * `500 Internal Server Error` - Database error during deletion
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/translations/{link_id}",
+    operation_id = "admin_word_translations_delete",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Translation link successfully deleted"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} or its translation link with {link_id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+        ("link_id" = u32, Path, description = "Unique database identifier of the translation link", example = 1),
+    )
+)]
+pub async fn translation_delete(
+    State(state): State<AppState>,
+    Path((lang, id, link_id)): Path<(String, u32, u32)>,
+) -> Result<(), AppError> {
+    WordTranslation::delete(&state.dbpool, &lang, id, link_id).await
+}
+
+/// Lists all tags.
+///
+/// Returns every tag in alphabetical order with the number of words
+/// carrying it, in any language.
+///
+/// # Returns
+///
+/// * `200 OK` - Tags listed, the list is empty when there are none
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/tags",
+    operation_id = "admin_tags_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Tags listed successfully", body = [Tag]),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn tag_list(State(state): State<AppState>) -> Result<Json<Vec<Tag>>, AppError> {
+    Tag::list(&state.dbpool).await.map(Json::from)
+}
+
+/// Creates a new tag.
+///
+/// # Request Body
+///
+/// JSON object with the required field `name`: letters, digits and hyphens
+/// (e.g. 'animals', 'farm-animals'), stored lowercased.
+///
+/// # Returns
+///
+/// * `200 OK` - Tag successfully created
+/// * `409 Conflict` - A tag with this name already exists
+/// * `500 Internal Server Error` - Database error or validation failed
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/tags",
+    operation_id = "admin_tags_create",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertTag, description = "Tag name", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Tag successfully created", body = [Tag]),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 409, description = "A tag with this name already exists"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn tag_create(
+    State(state): State<AppState>,
+    Json(tag): Json<UpsertTag>,
+) -> Result<Json<Vec<Tag>>, AppError> {
+    Tag::create(&state.dbpool, tag).await.map(Json::from)
+}
+
+/// Deletes a tag.
+///
+/// The tag is removed from every word carrying it.
+///
+/// # Parameters
+///
+/// * `id` - Unique database identifier of the tag
+///
+/// # Returns
+///
+/// * `200 OK` - Tag successfully deleted
+/// * `404 Not Found` - No tag exists with specified ID
+/// * `500 Internal Server Error` - Database error during deletion
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/tags/{id}",
+    operation_id = "admin_tags_delete",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Tag successfully deleted"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the tag with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("id" = u32, Path, description = "Unique database identifier of the tag", example = 1),
+    )
+)]
+pub async fn tag_delete(
+    State(state): State<AppState>,
+    Path(id): Path<u32>,
+) -> Result<(), AppError> {
+    Tag::delete(&state.dbpool, id).await
+}
+
+/// Tags a word.
+///
+/// Tagging a word that already has the tag has no effect.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+/// * `name` - Name of an existing tag
+///
+/// # Returns
+///
+/// * `200 OK` - Word successfully tagged
+/// * `404 Not Found` - No word exists with specified ID or no tag with specified name
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    put,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/tags/{name}",
+    operation_id = "admin_word_tags_add",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Word successfully tagged"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} or the tag with {name}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+        ("name" = String, Path, description = "Name of the tag", example = "animals"),
+    )
+)]
+pub async fn word_tag_add(
+    State(state): State<AppState>,
+    Path((lang, id, name)): Path<(String, u32, String)>,
+) -> Result<(), AppError> {
+    Tag::attach(&state.dbpool, &lang, id, &name).await
+}
+
+/// Removes a tag from a word.
+///
+/// Untagging a word that doesn't have the tag has no effect.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word
+/// * `name` - Name of an existing tag
+///
+/// # Returns
+///
+/// * `200 OK` - Tag successfully removed from the word
+/// * `404 Not Found` - No word exists with specified ID or no tag with specified name
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/{lang}/words/{id}/tags/{name}",
+    operation_id = "admin_word_tags_remove",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Tag successfully removed from the word"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the word with {id} or the tag with {name}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word operations, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word", example = 1),
+        ("name" = String, Path, description = "Name of the tag", example = "animals"),
+    )
+)]
+pub async fn word_tag_remove(
+    State(state): State<AppState>,
+    Path((lang, id, name)): Path<(String, u32, String)>,
+) -> Result<(), AppError> {
+    Tag::detach(&state.dbpool, &lang, id, &name).await
+}
+
+/// Lists all registered languages.
+///
+/// Returns every language in code order, enabled or not.
+///
+/// # Returns
+///
+/// * `200 OK` - Languages listed successfully
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/admin",
+    path = "/languages",
+    operation_id = "admin_languages_list",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Languages listed successfully", body = [Language]),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn language_list(State(state): State<AppState>) -> Result<Json<Vec<Language>>, AppError> {
+    Language::list(&state.dbpool).await.map(Json::from)
+}
+
+/// Registers a new language.
+///
+/// The language is served under `/{code}` right away when enabled. Words
+/// can be added to a disabled language before it is enabled.
+///
+/// # Request Body
+///
+/// JSON object with the required fields `code` (ISO 639, e.g. 'de'), `name`,
+/// `script` (ISO 15924, e.g. 'Latn') and `validationProfile` ('latin',
+/// 'greek', 'cyrillic' or 'cjk'), and the optional fields `enabled` (`true`
+/// if absent) and `typeNames`, the names the language gives grammatical
+/// types in `/{lang}/{type}` (e.g. `{"noun": "substantiv"}`).
+///
+/// # Returns
+///
+/// * `200 OK` - Language successfully registered
+/// * `409 Conflict` - A language with this code already exists
+/// * `500 Internal Server Error` - Database error or validation failed
+#[utoipa::path(
+    post,
+    context_path = "/admin",
+    path = "/languages",
+    operation_id = "admin_languages_create",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertLanguage, description = "Language to register", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Language successfully registered", body = [Language]),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 409, description = "A language with this code already exists"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn language_create(
+    State(state): State<AppState>,
+    Json(language): Json<UpsertLanguage>,
+) -> Result<Json<Vec<Language>>, AppError> {
+    Language::create(&state.dbpool, language)
+        .await
+        .map(Json::from)
+}
+
+/// Updates a language, or enables and disables it.
+///
+/// Disabled languages keep their words but are no longer served by the
+/// public endpoints.
+///
+/// # Parameters
+///
+/// * `code` - Code of the registered language
+///
+/// # Request Body
+///
+/// Same fields as for registering. The `code` can't be changed, and the
+/// `typeNames` replace the previous ones.
+///
+/// # Returns
+///
+/// * `200 OK` - Language successfully updated
+/// * `400 Bad Request` - The body tries to change the language code
+/// * `404 Not Found` - No language is registered with this code
+/// * `500 Internal Server Error` - Database error or validation failed
+#[utoipa::path(
+    put,
+    context_path = "/admin",
+    path = "/languages/{code}",
+    operation_id = "admin_languages_update",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertLanguage, description = "Updated language", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Language successfully updated", body = [Language]),
+        (status = 400, description = "The language code can't be changed"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the language with {code}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("code" = String, Path, description = "Code of the registered language", example = "de"),
+    )
+)]
+pub async fn language_update(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Json(language): Json<UpsertLanguage>,
+) -> Result<Json<Vec<Language>>, AppError> {
+    Language::update(&state.dbpool, &code, language)
+        .await
+        .map(Json::from)
+}
+
+/// Grants the editor role to a user.
+///
+/// Editors propose new words and changes through the editorial endpoints,
+/// which wait for review by an admin. The role is carried by the tokens of
+/// the user, so it applies from their next login on.
+///
+/// # Parameters
+///
+/// * `id` - Unique database identifier of the user
+///
+/// # Returns
+///
+/// * `200 OK` - The user, now an editor
+/// * `404 Not Found` - No user exists with the ID
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    put,
+    context_path = "/admin",
+    path = "/users/{id}/editor",
+    operation_id = "admin_users_editor_grant",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Editor role granted to the user", body = User),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the user with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("id" = i64, Path, description = "Unique database identifier of the user", example = 2),
+    )
+)]
+pub async fn user_editor_grant(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<User>, AppError> {
+    User::set_editor(&state.dbpool, id, true)
+        .await
+        .map(Json::from)
+}
+
+/// Revokes the editor role of a user.
+///
+/// The proposals of the user are kept. Tokens issued before the revoke
+/// still carry the role until they expire.
+///
+/// # Parameters
+///
+/// * `id` - Unique database identifier of the user
+///
+/// # Returns
+///
+/// * `200 OK` - The user, no longer an editor
+/// * `404 Not Found` - No user exists with the ID
+/// * `500 Internal Server Error` - Database error
+#[utoipa::path(
+    delete,
+    context_path = "/admin",
+    path = "/users/{id}/editor",
+    operation_id = "admin_users_editor_revoke",
+    tag = "administration_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Editor role revoked from the user", body = User),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 403, description = "Forbidden - Admin privileges required"),
+        (status = 404, description = "Couldn't find the user with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("id" = i64, Path, description = "Unique database identifier of the user", example = 2),
+    )
+)]
+pub async fn user_editor_revoke(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<User>, AppError> {
+    User::set_editor(&state.dbpool, id, false)
+        .await
+        .map(Json::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{AppError, PathError};
+    use crate::models::language::UpsertLanguage;
+    use crate::models::profile::ValidationProfile;
+    use crate::models::word::{GrammaticalType, PronunciationVariant, UpsertWord, WordSense};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_parameter_extraction_logic() {
+        // Test language registration validation logic used by handlers
+        let mut language = UpsertLanguage {
+            code: "de".to_string(),
+            name: "German".to_string(),
+            script: "Latn".to_string(),
+            validation_profile: "latin".to_string(),
+            enabled: true,
+            type_names: Default::default(),
+        };
+        assert!(language.code().is_ok());
+        assert!(ValidationProfile::from_str("latin").is_ok());
+
+        language.script = "latn".to_string();
+        assert!(language.code().is_err());
+
+        language.script = "Latn".to_string();
+        language.validation_profile = "klingon".to_string();
+        assert!(language.code().is_err());
+
+        let valid_type = "noun";
+        let type_result = GrammaticalType::from_str(valid_type);
+        assert!(type_result.is_ok());
+
+        let invalid_type = "determiner";
+        let type_result = GrammaticalType::from_str(invalid_type);
+        assert!(type_result.is_err());
+    }
+
+    #[test]
+    fn test_error_conversion_logic() {
+        // Test error conversion used by handlers
+        let path_error = PathError::InvalidPath("invalid".to_string());
+        let app_error = AppError::from(path_error);
+        let error_debug = format!("{app_error:?}");
+        assert!(error_debug.contains("invalid") || error_debug.contains("InvalidPath"));
+
+        let word_type_error = PathError::InvalidWordType("preposition".to_string());
+        let app_error = AppError::from(word_type_error);
+        let error_debug = format!("{app_error:?}");
+        assert!(error_debug.contains("preposition") || error_debug.contains("InvalidWordType"));
+    }
+
+    #[test]
+    fn test_data_transformation_logic() {
+        // Test data transformation logic used by handlers
+        let test_word = UpsertWord {
+            word: "TEST".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "NOUN".to_string(),
+                definition: "A Test Definition".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "US".to_string(),
+                pronunciation: "/TEST/".to_string(),
+            }],
+            word_type: "NOUN".to_string(),
+        };
+
+        // Test lowercase transformation that handlers perform
+        let lowercase_word = test_word.word.to_lowercase();
+        let lowercase_definition = test_word.senses[0].definition.to_lowercase();
+        let lowercase_pronunciation = test_word.pronunciations[0].pronunciation.to_lowercase();
+        let lowercase_word_type = test_word.word_type.to_lowercase();
+
+        assert_eq!(lowercase_word, "test");
+        assert_eq!(lowercase_definition, "a test definition");
+        assert_eq!(lowercase_pronunciation, "/test/");
+        assert_eq!(lowercase_word_type, "noun");
+    }
+
+    #[test]
+    fn test_validation_logic() {
+        // Test validation logic used by handlers
+        let invalid_word = UpsertWord {
+            word: "".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "valid definition".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/valid/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+
+        assert!(invalid_word.word().is_err());
+
+        let invalid_type_word = UpsertWord {
+            word: "valid".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "valid definition".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/valid/".to_string(),
+            }],
+            word_type: "determiner".to_string(),
+        };
+
+        assert!(invalid_type_word.word_type().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_word_import() {
+        use super::word_import;
+        use crate::models::language::Language;
+        use crate::models::listing::ListParams;
+        use crate::models::revision::WordRevision;
+        use crate::models::trash::TrashedWord;
+        use crate::models::word::Word;
+        use axum::http::StatusCode;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+        sqlx::query("DELETE FROM words")
+            .execute(&dbpool)
+            .await
+            .unwrap();
+
+        let app = axum::Router::new()
+            .route(
+                "/admin/{lang}/words/import",
+                axum::routing::post(word_import),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+
+        let row = |word: &str, definition: &str| {
+            serde_json::json!({
+                "word": word,
+                "wordType": "noun",
+                "senses": [{"definition": definition, "partOfSpeech": "noun"}],
+                "pronunciations": [{"pronunciation": "/kæt/"}],
+            })
+        };
+        let ndjson = |rows: &[serde_json::Value]| {
+            rows.iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let count = || async {
+            Word::page(dbpool.clone(), "en", &ListParams::default())
+                .await
+                .unwrap()
+                .total
+        };
+
+        // a dry run reports the rows without writing them
+        let response = server
+            .post("/admin/en/words/import?dry_run=true")
+            .json(&[row("cat", "a small animal"), row("Cat", "a feline")])
+            .await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["committed"], false);
+        assert_eq!(json["accepted"], 1);
+        assert_eq!(json["duplicates"], 1);
+        assert_eq!(json["rows"][1]["status"], "duplicate");
+        assert_eq!(count().await, 0);
+
+        // one invalid row keeps every row from being written
+        let response = server
+            .post("/admin/en/words/import")
+            .text(ndjson(&[
+                row("cat", "a small animal"),
+                row("two words", "x"),
+            ]))
+            .content_type("application/x-ndjson")
+            .await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["invalid"], 1);
+        assert_eq!(json["rows"][1]["row"], 2);
+        assert_eq!(json["rows"][1]["errors"][0]["field"], "word");
+        assert_eq!(json["rows"][1]["errors"][0]["code"], "invalid_lemma");
+        assert_eq!(count().await, 0);
+
+        // CSV list columns hold JSON arrays
+        let csv = "word,wordType,senses,pronunciations\n\
+            cat,noun,\"[{\"\"definition\"\": \"\"a small animal\"\", \"\"partOfSpeech\"\": \"\"noun\"\"}]\",\"[{\"\"pronunciation\"\": \"\"/kæt/\"\"}]\"\n";
+        let response = server
+            .post("/admin/en/words/import")
+            .text(csv)
+            .content_type("text/csv")
+            .await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["committed"], true);
+        assert_eq!(json["rows"][0]["action"], "insert");
+        assert_eq!(count().await, 1);
+
+        // stored lemmas are duplicates in insert mode and updated in upsert mode
+        let rows = [row("cat", "a feline"), row("dog", "a loyal animal")];
+        let response = server.post("/admin/en/words/import").json(&rows).await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["rows"][0]["status"], "duplicate");
+        assert_eq!(count().await, 2);
+        let response = server
+            .post("/admin/en/words/import?mode=upsert")
+            .json(&rows)
+            .await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["rows"][0]["action"], "update");
+        assert_eq!(json["rows"][1]["action"], "update");
+        let page = Word::page(dbpool.clone(), "en", &ListParams::default())
+            .await
+            .unwrap();
+        let words = serde_json::to_value(page.words).unwrap();
+        assert_eq!(words[0]["senses"][0]["definition"], "a feline");
+
+        // replace mode moves the words missing from the import to the trash
+        let english = Language::find(&dbpool, "en").await.unwrap();
+        let dog = Word::find_id(&dbpool, &english, "dog").await.unwrap();
+        let response = server
+            .post("/admin/en/words/import?mode=replace")
+            .json(&[row("cow", "a farm animal")])
+            .await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["deleted"], 2);
+        assert_eq!(count().await, 1);
+        let history = WordRevision::history(&dbpool, "en", dog).await.unwrap();
+        let history = serde_json::to_value(history).unwrap();
+        assert_eq!(history[0]["action"], "delete");
+
+        // where they can be restored as they were
+        let restored = TrashedWord::restore(dbpool.clone(), "en", dog, None)
+            .await
+            .unwrap();
+        let restored = serde_json::to_value(restored).unwrap();
+        assert_eq!(restored["word"], "dog");
+        assert_eq!(restored["senses"][0]["definition"], "a loyal animal");
+        assert_eq!(count().await, 2);
+
+        // the format must be known and the body split into rows
+        server
+            .post("/admin/en/words/import")
+            .text("cat")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+        server
+            .post("/admin/en/words/import?format=json")
+            .text("{}")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_word_unknown_id() {
+        use super::{word_patch, word_read, word_update};
+        use crate::models::word::Word;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route(
+                "/admin/{lang}/words/{id}",
+                axum::routing::get(word_read)
+                    .put(word_update)
+                    .patch(word_patch),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let word = serde_json::json!({
+            "word": "cat",
+            "wordType": "noun",
+            "senses": [{"definition": "a small animal", "partOfSpeech": "noun"}],
+            "pronunciations": [{"pronunciation": "/kæt/"}],
+        });
+
+        // a missing ID is not found, whether read, updated or patched
+        server
+            .get("/admin/en/words/999999")
+            .await
+            .assert_status_not_found();
+        server
+            .put("/admin/en/words/999999")
+            .json(&word)
+            .await
+            .assert_status_not_found();
+        server
+            .patch("/admin/en/words/999999")
+            .json(&serde_json::json!({"usageNote": "x"}))
+            .await
+            .assert_status_not_found();
+        assert!(Word::read(dbpool.clone(), "en", 999999).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_user_editor_role() {
+        use super::{user_editor_grant, user_editor_revoke};
+        use crate::models::user::User;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route(
+                "/admin/users/{id}/editor",
+                axum::routing::put(user_editor_grant).delete(user_editor_revoke),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let user = User::create_user(&dbpool, "teacher", "hash", false)
+            .await
+            .unwrap();
+        assert!(!user.is_editor);
+        let path = format!("/admin/users/{}/editor", user.id);
+
+        // admins grant and revoke the role, the password hash isn't shown
+        let response = server.put(&path).await;
+        response.assert_status_ok();
+        let granted: serde_json::Value = response.json();
+        assert_eq!(granted["is_editor"], true);
+        assert!(granted.get("password_hash").is_none());
+        let stored = User::find_by_username(&dbpool, "teacher")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.is_editor);
+        let response = server.delete(&path).await;
+        response.assert_status_ok();
+        assert_eq!(response.json::<serde_json::Value>()["is_editor"], false);
+
+        // only known users have a role
+        server
+            .put("/admin/users/999999/editor")
+            .await
+            .assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_word_patch() {
+        use super::word_patch;
+        use crate::models::language::Language;
+        use crate::models::word::Word;
+        use axum::http::StatusCode;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route("/admin/{lang}/words/{id}", axum::routing::patch(word_patch))
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let english = Language::find(&dbpool, "en").await.unwrap();
+        let id = Word::find_id(&dbpool, &english, "cat").await.unwrap();
+        let path = format!("/admin/en/words/{id}");
+        let stored =
+            serde_json::to_value(Word::read(dbpool.clone(), "en", id).await.unwrap()).unwrap();
+
+        // only the fields of the patch change
+        let response = server
+            .patch(&path)
+            .text(r#"{"usageNote": "often affectionate"}"#)
+            .content_type("application/merge-patch+json")
+            .await;
+        response.assert_status_ok();
+        let word: serde_json::Value = response.json();
+        assert_eq!(word["usageNote"], "often affectionate");
+        assert_eq!(word["senses"], stored[0]["senses"]);
+        assert_eq!(word["pronunciations"], stored[0]["pronunciations"]);
+
+        // null clears a field and lists are replaced as a whole
+        let response = server
+            .patch(&path)
+            .json(&serde_json::json!({
+                "usageNote": null,
+                "senses": [{"definition": "a small domesticated feline", "partOfSpeech": "noun"}],
+            }))
+            .await;
+        response.assert_status_ok();
+        let word: serde_json::Value = response.json();
+        assert!(word.get("usageNote").is_none_or(|note| note.is_null()));
+        assert_eq!(word["senses"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            word["senses"][0]["definition"],
+            "a small domesticated feline"
+        );
+
+        // patched words are validated like updates
+        let response = server
+            .patch(&path)
+            .json(&serde_json::json!({"word": "two words"}))
+            .await;
+        assert!(!response.status_code().is_success());
+        server
+            .patch(&path)
+            .json(&serde_json::json!({"definition": "a feline"}))
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        server
+            .patch(&path)
+            .json(&serde_json::json!({"senses": "a feline"}))
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        server
+            .patch(&path)
+            .json(&serde_json::json!([]))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+        server
+            .patch("/admin/en/words/999999")
+            .json(&serde_json::json!({"usageNote": "x"}))
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+        // rules across fields hold when the patch changes one of their fields
+        let response = server
+            .patch(&path)
+            .json(&serde_json::json!({"categories": ["transitive"]}))
+            .await;
+        assert!(!response.status_code().is_success());
+        let word =
+            serde_json::to_value(Word::read(dbpool.clone(), "en", id).await.unwrap()).unwrap();
+        assert_eq!(word[0]["word"], "cat");
+
+        // only the fields of the patch are validated, a stored word breaking
+        // the profile of its language can still be patched
+        sqlx::query("UPDATE word_senses SET definition = 'кошка' WHERE word_id = $1")
+            .bind(id)
+            .execute(&dbpool)
+            .await
+            .unwrap();
+        let response = server
+            .patch(&path)
+            .json(&serde_json::json!({"usageNote": "often affectionate"}))
+            .await;
+        response.assert_status_ok();
+        let word: serde_json::Value = response.json();
+        assert_eq!(word["senses"][0]["definition"], "кошка");
+        let response = server
+            .patch(&path)
+            .json(&serde_json::json!({
+                "senses": [{"definition": "кошка", "partOfSpeech": "noun"}],
+            }))
+            .await;
+        assert!(!response.status_code().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_word_versions() {
+        use super::{word_delete, word_patch, word_read, word_update};
+        use crate::models::language::Language;
+        use crate::models::word::Word;
+        use axum::http::{HeaderName, StatusCode};
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route(
+                "/admin/{lang}/words/{id}",
+                axum::routing::get(word_read)
+                    .put(word_update)
+                    .patch(word_patch)
+                    .delete(word_delete),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let english = Language::find(&dbpool, "en").await.unwrap();
+        let id = Word::find_id(&dbpool, &english, "cat").await.unwrap();
+        let path = format!("/admin/en/words/{id}");
+        let if_match = HeaderName::from_static("if-match");
+        let etag = |response: &axum_test::TestResponse| {
+            response.header("etag").to_str().unwrap().to_string()
+        };
+
+        // reads carry the version, and cached copies are revalidated
+        let response = server.get(&path).await;
+        response.assert_status_ok();
+        let first = etag(&response);
+        let last_modified = response
+            .header("last-modified")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let response = server
+            .get(&path)
+            .add_header(HeaderName::from_static("if-none-match"), first.clone())
+            .await;
+        response.assert_status(StatusCode::NOT_MODIFIED);
+        assert_eq!(etag(&response), first);
+        server
+            .get(&path)
+            .add_header(HeaderName::from_static("if-modified-since"), last_modified)
+            .await
+            .assert_status(StatusCode::NOT_MODIFIED);
+        server
+            .get(&path)
+            .add_header(
+                HeaderName::from_static("if-modified-since"),
+                "Thu, 01 Jan 1970 00:00:00 GMT",
+            )
+            .await
+            .assert_status_ok();
+
+        // a write with the current version bumps it
+        let response = server
+            .patch(&path)
+            .add_header(if_match.clone(), first.clone())
+            .json(&serde_json::json!({"usageNote": "often affectionate"}))
+            .await;
+        response.assert_status_ok();
+        let second = etag(&response);
+        assert_ne!(second, first);
+
+        // writes with an outdated version fail instead of overwriting
+        server
+            .patch(&path)
+            .add_header(if_match.clone(), first.clone())
+            .json(&serde_json::json!({"usageNote": null}))
+            .await
+            .assert_status(StatusCode::PRECONDITION_FAILED);
+        let mut word: serde_json::Value = server.get(&path).await.json();
+        let word = word[0].take();
+        let upsert = serde_json::json!({
+            "word": word["word"],
+            "senses": word["senses"],
+            "pronunciations": word["pronunciations"],
+            "wordType": word["wordType"],
+        });
+        server
+            .put(&path)
+            .add_header(if_match.clone(), first.clone())
+            .json(&upsert)
+            .await
+            .assert_status(StatusCode::PRECONDITION_FAILED);
+        server
+            .delete(&path)
+            .add_header(if_match.clone(), first.clone())
+            .await
+            .assert_status(StatusCode::PRECONDITION_FAILED);
+        let response = server.get(&path).await;
+        let stored: serde_json::Value = response.json();
+        assert_eq!(stored[0]["usageNote"], "often affectionate");
+
+        // writes without a precondition still go through
+        let response = server.put(&path).json(&upsert).await;
+        response.assert_status_ok();
+        let third = etag(&response);
+        assert_ne!(third, second);
+        server
+            .delete(&path)
+            .add_header(if_match.clone(), third)
+            .await
+            .assert_status_ok();
+        server
+            .delete(&path)
+            .add_header(if_match, "*")
+            .await
+            .assert_status(StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[tokio::test]
+    async fn test_word_list_pages() {
+        use super::word_list;
+        use crate::models::listing::ListParams;
+        use crate::models::word::Word;
+        use axum::http::StatusCode;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route("/admin/{lang}/words", axum::routing::get(word_list))
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let all = Word::page(
+            dbpool.clone(),
+            "en",
+            &ListParams {
+                contains: Some("ab".to_string()),
+                limit: Some(500),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .words;
+        let next_link = |response: &axum_test::TestResponse| {
+            let links = response.header("link").to_str().unwrap().to_string();
+            links
+                .split(", ")
+                .find(|link| link.ends_with("rel=\"next\""))
+                .map(|link| link[1..link.find('>').unwrap()].to_string())
+        };
+        let lemmas = |response: &axum_test::TestResponse| {
+            let words: serde_json::Value = response.json();
+            words
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|w| w["word"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // pages follow each other through the next links, keeping the filters
+        let response = server.get("/admin/en/words?contains=ab&limit=2").await;
+        response.assert_status_ok();
+        let total: usize = response
+            .header("x-total-count")
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let expected: Vec<String> = {
+            let all = serde_json::to_value(&all).unwrap();
+            let mut expected: Vec<String> = all
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|w| w["word"].as_str().unwrap().to_string())
+                .filter(|w| w.contains("ab"))
+                .collect();
+            expected.sort();
+            expected
+        };
+        assert_eq!(total, expected.len());
+        assert!(total > 4);
+        let mut listed = lemmas(&response);
+        let mut next = next_link(&response);
+        while let Some(link) = next {
+            assert!(link.contains("contains=ab&limit=2&cursor="));
+            let response = server.get(&link).await;
+            assert!(lemmas(&response).len() <= 2);
+            listed.extend(lemmas(&response));
+            next = next_link(&response);
+        }
+        assert_eq!(listed, expected);
+
+        // words can be sorted the other way and narrowed down by type
+        let response = server
+            .get("/admin/en/words?order=desc&word_type=verb&limit=3")
+            .await;
+        let listed = lemmas(&response);
+        assert_eq!(listed.len(), 3);
+        assert!(listed.windows(2).all(|pair| pair[0] > pair[1]));
+        let words: serde_json::Value = response.json();
+        assert!(words
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|w| w["wordType"] == "verb"));
+
+        // the last page has no next link
+        let response = server
+            .get("/admin/en/words?sort=created_at&created_after=2999-01-01")
+            .await;
+        response.assert_status_ok();
+        assert_eq!(response.header("x-total-count"), "0");
+        assert_eq!(lemmas(&response).len(), 0);
+        assert_eq!(next_link(&response), None);
+
+        // cursors only page through the sort they were made for
+        let response = server.get("/admin/en/words?limit=1").await;
+        let link = next_link(&response).unwrap();
+        server
+            .get(&format!("{link}&sort=updated_at"))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+        server
+            .get("/admin/en/words?created_before=yesterday")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_word_export() {
+        use super::{word_export, word_import};
+        use crate::models::listing::ListParams;
+        use crate::models::word::Word;
+        use axum::http::StatusCode;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route(
+                "/admin/{lang}/words/import",
+                axum::routing::post(word_import),
+            )
+            .route(
+                "/admin/{lang}/words/export",
+                axum::routing::get(word_export),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        // every word of the language, following the pages of the word list
+        let words = || async {
+            let mut words = Vec::new();
+            let mut params = ListParams {
+                limit: Some(500),
+                ..Default::default()
+            };
+            loop {
+                let page = Word::page(dbpool.clone(), "en", &params).await.unwrap();
+                words.extend(page.words);
+                match page.next {
+                    Some(next) => params.cursor = Some(next.encode()),
+                    None => break,
+                }
+            }
+            serde_json::to_value(words).unwrap()
+        };
+        let before = words().await;
+        let count = before.as_array().unwrap().len();
+
+        // the JSON export holds the metadata and every word, sorted by lemma
+        let response = server.get("/admin/en/words/export").await;
+        response.assert_status_ok();
+        response.assert_header("content-type", "application/json");
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["metadata"]["language"], "en");
+        assert!(json["metadata"]["datasetVersion"]
+            .as_str()
+            .unwrap()
+            .ends_with(&format!("-{count}")));
+        let lemmas: Vec<&str> = json["words"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["word"].as_str().unwrap())
+            .collect();
+        assert_eq!(lemmas.len(), count);
+        assert!(lemmas.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        // two exports of the same data are identical
+        let csv = server.get("/admin/en/words/export?format=csv").await.text();
+        assert_eq!(
+            server.get("/admin/en/words/export?format=csv").await.text(),
+            csv
+        );
+        assert!(csv.starts_with("# {"));
+        assert_eq!(csv.lines().count(), count + 2);
+
+        // filters narrow the export down
+        let ndjson = server
+            .get("/admin/en/words/export?format=ndjson&word_type=verb")
+            .await
+            .text();
+        assert!(ndjson
+            .lines()
+            .skip(1)
+            .all(|line| line.contains("\"wordType\":\"verb\"")));
+        server
+            .get("/admin/en/words/export?format=xml")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+        server
+            .get("/admin/xx/words/export")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        // an export can be imported back as it is
+        for (format, content_type) in [("csv", "text/csv"), ("ndjson", "application/x-ndjson")] {
+            let body = server
+                .get(&format!("/admin/en/words/export?format={format}"))
+                .await
+                .text();
+            server
+                .post("/admin/en/words/import?mode=replace")
+                .text(body)
+                .content_type(content_type)
+                .await
+                .assert_status_ok();
+            let after = words().await;
+            let fields = |words: &serde_json::Value| {
+                let mut fields: Vec<(String, String)> = words
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    // stored definitions are lowercased, unlike the seeded ones
+                    .map(|w| {
+                        (
+                            w["word"].to_string(),
+                            w["senses"].to_string().to_lowercase(),
+                        )
+                    })
+                    .collect();
+                fields.sort();
+                fields
+            };
+            assert_eq!(fields(&after), fields(&before));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_word_trash() {
+        use super::{
+            trash_list, trash_purge, trash_purge_expired, trash_restore, word_create, word_delete,
+            word_read,
+        };
+        use crate::models::language::Language;
+        use crate::models::revision::WordRevision;
+        use crate::models::trash::TrashedWord;
+        use crate::models::word::Word;
+        use axum::http::StatusCode;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route("/admin/{lang}/words", axum::routing::post(word_create))
+            .route(
+                "/admin/{lang}/words/{id}",
+                axum::routing::get(word_read).delete(word_delete),
+            )
+            .route(
+                "/admin/{lang}/words/trash",
+                axum::routing::get(trash_list).delete(trash_purge_expired),
+            )
+            .route(
+                "/admin/{lang}/words/trash/{id}",
+                axum::routing::delete(trash_purge),
+            )
+            .route(
+                "/admin/{lang}/words/trash/{id}/restore",
+                axum::routing::post(trash_restore),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let english = Language::find(&dbpool, "en").await.unwrap();
+        let id = Word::find_id(&dbpool, &english, "cat").await.unwrap();
+        let path = format!("/admin/en/words/{id}");
+        let cat = Word::read(dbpool.clone(), "en", id)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let cat = serde_json::to_value(UpsertWord::from(cat)).unwrap();
+
+        // a deleted word is left out, but waits in the trash
+        server.delete(&path).await.assert_status_ok();
+        server.get(&path).await.assert_status_not_found();
+        assert!(Word::find_id(&dbpool, &english, "cat").await.is_err());
+        let trash: serde_json::Value = server.get("/admin/en/words/trash").await.json();
+        assert_eq!(trash.as_array().unwrap().len(), 1);
+        assert_eq!(trash[0]["word"], "cat");
+        assert!(trash[0]["purgeAt"].as_str().unwrap() > trash[0]["deletedAt"].as_str().unwrap());
+
+        // it holds its lemma until restored or purged
+        server
+            .post("/admin/en/words")
+            .json(&cat)
+            .await
+            .assert_status(StatusCode::CONFLICT);
+
+        // restoring brings it back with a new version
+        let response = server
+            .post(&format!("/admin/en/words/trash/{id}/restore"))
+            .await;
+        response.assert_status_ok();
+        assert_eq!(response.json::<serde_json::Value>()["word"], "cat");
+        assert!(response.maybe_header("etag").is_some());
+        server.get(&path).await.assert_status_ok();
+        server
+            .post(&format!("/admin/en/words/trash/{id}/restore"))
+            .await
+            .assert_status_not_found();
+
+        // words are only purged after the retention, unless purged by ID
+        server.delete(&path).await.assert_status_ok();
+        let report: serde_json::Value = server.delete("/admin/en/words/trash").await.json();
+        assert_eq!(report["purged"], 0);
+        server
+            .delete(&format!("/admin/en/words/trash/{id}"))
+            .await
+            .assert_status_ok();
+        let trash: serde_json::Value = server.get("/admin/en/words/trash").await.json();
+        assert!(trash.as_array().unwrap().is_empty());
+        server
+            .post("/admin/en/words")
+            .json(&cat)
+            .await
+            .assert_status_ok();
+
+        // past the retention, words are purged with everything else of theirs
+        let id = Word::find_id(&dbpool, &english, "dog").await.unwrap();
+        server
+            .delete(&format!("/admin/en/words/{id}"))
+            .await
+            .assert_status_ok();
+        let report = TrashedWord::purge_expired(&dbpool, None, 0, None)
+            .await
+            .unwrap();
+        assert_eq!(serde_json::to_value(report).unwrap()["purged"], 1);
+        let (senses,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM word_senses WHERE word_id = $1")
+                .bind(id)
+                .fetch_one(&dbpool)
+                .await
+                .unwrap();
+        assert_eq!(senses, 0);
+
+        // the history of a purged word is kept, ending with the purge
+        let history = WordRevision::history(&dbpool, "en", id).await.unwrap();
+        let history = serde_json::to_value(history).unwrap();
+        assert_eq!(history[0]["action"], "purge");
+        assert_eq!(history[1]["action"], "delete");
+        assert!(history[1]["before"].is_object());
+
+        // a word taking the ID of a purged one starts a history of its own
+        let id = Word::find_id(&dbpool, &english, "cat").await.unwrap();
+        server
+            .delete(&format!("/admin/en/words/{id}"))
+            .await
+            .assert_status_ok();
+        server
+            .delete(&format!("/admin/en/words/trash/{id}"))
+            .await
+            .assert_status_ok();
+        server
+            .post("/admin/en/words")
+            .json(&cat)
+            .await
+            .assert_status_ok();
+        assert_eq!(Word::find_id(&dbpool, &english, "cat").await.unwrap(), id);
+        let history = WordRevision::history(&dbpool, "en", id).await.unwrap();
+        let history = serde_json::to_value(history).unwrap();
+        assert_eq!(history.as_array().unwrap().len(), 1);
+        assert_eq!(history[0]["action"], "create");
+    }
+
+    #[tokio::test]
+    async fn test_word_history() {
+        use super::{trash_restore, word_delete, word_history, word_revert, word_update};
+        use crate::auth::JwtManager;
+        use crate::config::ApiConfig;
+        use crate::models::language::Language;
+        use crate::models::user::User;
+        use crate::models::word::Word;
+        use axum::http::StatusCode;
+
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route(
+                "/admin/{lang}/words/{id}",
+                axum::routing::put(word_update).delete(word_delete),
+            )
+            .route(
+                "/admin/{lang}/words/{id}/history",
+                axum::routing::get(word_history),
+            )
+            .route(
+                "/admin/{lang}/words/{id}/history/{revision_id}/revert",
+                axum::routing::post(word_revert),
+            )
+            .route(
+                "/admin/{lang}/words/trash/{id}/restore",
+                axum::routing::post(trash_restore),
+            )
+            .with_state(state);
+        let mut server = axum_test::TestServer::new(app).unwrap();
+        let admin = User {
+            id: 1,
+            username: "editor".to_string(),
+            password_hash: String::new(),
+            is_admin: true,
+            is_editor: false,
+            created_at: None,
+            updated_at: None,
+        };
+        let token =
+            JwtManager::generate_token(&admin, &ApiConfig::default().jwt_settings.secret, 5)
+                .unwrap();
+        server.add_header("Authorization", format!("Bearer {token}"));
+
+        let english = Language::find(&dbpool, "en").await.unwrap();
+        let id = Word::find_id(&dbpool, &english, "cat").await.unwrap();
+        let dog = Word::find_id(&dbpool, &english, "dog").await.unwrap();
+        let path = format!("/admin/en/words/{id}");
+        let history = format!("{path}/history");
+        let stamp = |id: u32| {
+            let dbpool = dbpool.clone();
+            async move {
+                sqlx::query_scalar::<_, Option<String>>(
+                    "SELECT updated_at FROM words WHERE id = $1",
+                )
+                .bind(id)
+                .fetch_one(&dbpool)
+                .await
+                .unwrap()
+            }
+        };
+        let dog_stamp = stamp(dog).await;
+
+        // seeded words have no history yet
+        let revisions: serde_json::Value = server.get(&history).await.json();
+        assert!(revisions.as_array().unwrap().is_empty());
+
+        // an update is recorded with the admin and both snapshots
+        let cat = Word::read(dbpool.clone(), "en", id)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let mut update = serde_json::to_value(UpsertWord::from(cat)).unwrap();
+        let original = update["senses"][0]["definition"].clone();
+        update["senses"][0]["definition"] = "a cat, as changed by an editor".into();
+        server.put(&path).json(&update).await.assert_status_ok();
+        let revisions: serde_json::Value = server.get(&history).await.json();
+        assert_eq!(revisions[0]["action"], "update");
+        assert_eq!(revisions[0]["username"], "editor");
+        assert_eq!(revisions[0]["userId"], 1);
+        assert_eq!(revisions[0]["before"]["senses"][0]["definition"], original);
+        assert_eq!(
+            revisions[0]["after"]["senses"][0]["definition"],
+            "a cat, as changed by an editor"
+        );
+        let first = revisions[0]["id"].as_u64().unwrap();
+
+        // only the updated word gets a new change time
+        assert!(stamp(id).await.is_some());
+        assert_eq!(stamp(dog).await, dog_stamp);
+
+        // deletions and restores are recorded too, newest first
+        server.delete(&path).await.assert_status_ok();
+        let revisions: serde_json::Value = server.get(&history).await.json();
+        assert_eq!(revisions[0]["action"], "delete");
+        assert!(revisions[0]["after"].is_null());
+        server
+            .post(&format!("/admin/en/words/{id}/history/{first}/revert"))
+            .await
+            .assert_status_not_found();
+        server
+            .post(&format!("/admin/en/words/trash/{id}/restore"))
+            .await
+            .assert_status_ok();
+        let revisions: serde_json::Value = server.get(&history).await.json();
+        assert_eq!(revisions[0]["action"], "restore");
+        assert!(revisions[0]["before"].is_null());
+
+        // reverting to the first update undoes the later one, as a revision of its own
+        update["senses"][0]["definition"] = "a cat, changed again".into();
+        server.put(&path).json(&update).await.assert_status_ok();
+        let response = server
+            .post(&format!("/admin/en/words/{id}/history/{first}/revert"))
+            .add_header("If-Match", "\"1\"")
+            .await;
+        response.assert_status(StatusCode::PRECONDITION_FAILED);
+        let response = server
+            .post(&format!("/admin/en/words/{id}/history/{first}/revert"))
+            .await;
+        response.assert_status_ok();
+        assert!(response.maybe_header("etag").is_some());
+        let reverted: serde_json::Value = response.json();
+        assert_eq!(
+            reverted["senses"][0]["definition"],
+            "a cat, as changed by an editor"
+        );
+        let revisions: serde_json::Value = server.get(&history).await.json();
+        assert_eq!(revisions[0]["action"], "revert");
+
+        // a revision of another word can't be used
+        server
+            .post(&format!("/admin/en/words/{dog}/history/{first}/revert"))
+            .await
+            .assert_status_not_found();
+        server
+            .get("/admin/en/words/999999/history")
+            .await
+            .assert_status_not_found();
     }
 }
@@ -84,7 +84,7 @@ mod tests {
     use std::sync::{Arc, Mutex};
     use tempfile::NamedTempFile;
 
-    async fn create_test_app() -> TestServer {
+    async fn create_test_app() -> (TestServer, NamedTempFile) {
         let temp_db = NamedTempFile::new().unwrap();
         let db_url = format!("sqlite:{}", temp_db.path().display());
 
@@ -99,23 +99,28 @@ mod tests {
                 3000,
                 db_url,
                 vec!["localhost".to_string()],
+                "data/pronunciation".into(),
             ),
             compression: crate::config::ApiCompression::default(),
             jwt_settings: crate::config::JwtSettings::new(5, "test_secret_key".to_string()),
-            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024),
+            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384),
             openapi: crate::config::OpenApiDocs::default(),
         };
 
         let state = AppState {
             apiconfig: Arc::new(Mutex::new(config)),
             dbpool,
+            ladders: Arc::new(crate::models::ladder::LadderIndex::new()),
+            pronunciations: Arc::new(
+                crate::models::pronunciation::PronunciationRegistry::default(),
+            ),
         };
 
         let app = axum::Router::new()
             .route("/auth/login", axum::routing::post(login))
             .with_state(state);
 
-        TestServer::new(app).unwrap()
+        (TestServer::new(app).unwrap(), temp_db)
     }
 
     #[tokio::test]
@@ -137,16 +142,21 @@ mod tests {
                 3000,
                 db_url,
                 vec!["localhost".to_string()],
+                "data/pronunciation".into(),
             ),
             compression: crate::config::ApiCompression::default(),
             jwt_settings: crate::config::JwtSettings::new(5, "test_secret_key".to_string()),
-            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024),
+            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384),
             openapi: crate::config::OpenApiDocs::default(),
         };
 
         let state = AppState {
             apiconfig: Arc::new(Mutex::new(config)),
             dbpool,
+            ladders: Arc::new(crate::models::ladder::LadderIndex::new()),
+            pronunciations: Arc::new(
+                crate::models::pronunciation::PronunciationRegistry::default(),
+            ),
         };
 
         let app = axum::Router::new()
@@ -171,7 +181,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_login_invalid_credentials() {
-        let server = create_test_app().await;
+        let (server, _db) = create_test_app().await;
 
         let login_body = json!({
             "username": "nonexistent",
@@ -201,16 +211,21 @@ mod tests {
                 3000,
                 db_url,
                 vec!["localhost".to_string()],
+                "data/pronunciation".into(),
             ),
             compression: crate::config::ApiCompression::default(),
             jwt_settings: crate::config::JwtSettings::new(5, "test_secret_key".to_string()),
-            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024),
+            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384),
             openapi: crate::config::OpenApiDocs::default(),
         };
 
         let state = AppState {
             apiconfig: Arc::new(Mutex::new(config)),
             dbpool,
+            ladders: Arc::new(crate::models::ladder::LadderIndex::new()),
+            pronunciations: Arc::new(
+                crate::models::pronunciation::PronunciationRegistry::default(),
+            ),
         };
 
         let app = axum::Router::new()
@@ -260,16 +275,21 @@ mod tests {
                 3000,
                 db_url,
                 vec!["localhost".to_string()],
+                "data/pronunciation".into(),
             ),
             compression: crate::config::ApiCompression::default(),
             jwt_settings: crate::config::JwtSettings::new(10, "test_secret_key".to_string()), // Custom expiration
-            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024),
+            api_limits: crate::config::ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384),
             openapi: crate::config::OpenApiDocs::default(),
         };
 
         let state = AppState {
             apiconfig: Arc::new(Mutex::new(config)),
             dbpool,
+            ladders: Arc::new(crate::models::ladder::LadderIndex::new()),
+            pronunciations: Arc::new(
+                crate::models::pronunciation::PronunciationRegistry::default(),
+            ),
         };
 
         let app = axum::Router::new()
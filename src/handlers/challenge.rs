@@ -0,0 +1,296 @@
+//! Daily challenge endpoints
+//!
+//! Provides the fixed daily word set, authenticated result submission, and
+//! the per-day leaderboard. Reading challenges and leaderboards is public;
+//! submitting results requires a valid JWT token.
+
+use crate::error::AppError;
+use crate::models::challenge::{
+    ChallengeResult, ChallengeSubmission, DailyChallenge, Leaderboard, LeaderboardParams,
+};
+use crate::models::user::AuthUser;
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+
+/// Retrieves the daily challenge for a date.
+///
+/// Every player gets the same words on the same day. The words are picked
+/// deterministically on first request and stored, so the challenge doesn't
+/// change if the dictionary is edited later that day. While the challenge is
+/// open only the definitions and pronunciations are returned; the words to
+/// spell are revealed once the day is over.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `date` - `today` or a `YYYY-MM-DD` date (UTC), not in the future
+///
+/// # Returns
+///
+/// * `200 OK` - Challenge words returned in play order, without the lemmas
+///   for today's challenge
+/// * `400 Bad Request` - Invalid language code or date
+/// * `404 Not Found` - Future date or no words available
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/challenge/{date}",
+    operation_id = "public_challenge_read",
+    tag = "public_endpoints",
+
+    responses(
+        (status = 200, description = "Daily challenge successfully retrieved and returned", body = DailyChallenge),
+        (status = 400, description = "Bad Request - Invalid language code or date provided"),
+        (status = 404, description = "Not Found - Challenge not available yet or no words in the language database"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for the challenge, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("date" = String, Path, description = "Challenge date as 'today' or 'YYYY-MM-DD' (UTC). Future dates are rejected.", example = "today"),
+    )
+)]
+pub async fn challenge_read(
+    State(state): State<AppState>,
+    Path((lang, date)): Path<(String, String)>,
+) -> Result<Json<DailyChallenge>, AppError> {
+    DailyChallenge::for_date(state.dbpool, &lang, &date)
+        .await
+        .map(Json::from)
+}
+
+/// Submits results for today's challenge.
+///
+/// Answers are graded server-side against the stored challenge words. Each
+/// user may submit once per challenge and each access token can only be
+/// used for a single submission.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `date` - `today` or today's `YYYY-MM-DD` date (UTC)
+///
+/// # Request Body
+///
+/// JSON object with `answers`: one spelled word per challenge word, in order.
+///
+/// # Returns
+///
+/// * `200 OK` - Submission graded and recorded
+/// * `400 Bad Request` - Invalid language code, date, closed challenge, or wrong answer count
+/// * `401 Unauthorized` - Invalid or missing authentication token
+/// * `409 Conflict` - Already submitted or token already used
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    post,
+    path = "/{lang}/challenge/{date}/submissions",
+    operation_id = "public_challenge_submit",
+    tag = "public_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = ChallengeSubmission, description = "Spelled answers for the challenge words, in order", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Submission graded and recorded", body = ChallengeResult),
+        (status = 400, description = "Bad Request - Invalid language code, date, closed challenge, or wrong number of answers"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token"),
+        (status = 409, description = "Conflict - Results already submitted or token already used"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for the challenge, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("date" = String, Path, description = "Challenge date as 'today' or today's 'YYYY-MM-DD' (UTC)", example = "today"),
+    )
+)]
+pub async fn challenge_submit(
+    State(state): State<AppState>,
+    Path((lang, date)): Path<(String, String)>,
+    user: AuthUser,
+    Json(submission): Json<ChallengeSubmission>,
+) -> Result<Json<ChallengeResult>, AppError> {
+    submission
+        .submit(state.dbpool, &lang, &date, &user)
+        .await
+        .map(Json::from)
+}
+
+/// Retrieves the leaderboard for a challenge date.
+///
+/// Ranks submissions by score, highest first. Players with the same score
+/// share a rank and are listed by submission time.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `date` - `today` or a `YYYY-MM-DD` date (UTC), not in the future
+/// * `limit` - Maximum number of entries (1-500, default: 50)
+///
+/// # Returns
+///
+/// * `200 OK` - Leaderboard returned (empty if nobody played that day)
+/// * `400 Bad Request` - Invalid language code or date
+/// * `404 Not Found` - Future date
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/challenge/{date}/leaderboard",
+    operation_id = "public_challenge_leaderboard",
+    tag = "public_endpoints",
+
+    responses(
+        (status = 200, description = "Leaderboard successfully retrieved and returned", body = Leaderboard),
+        (status = 400, description = "Bad Request - Invalid language code or date provided"),
+        (status = 404, description = "Not Found - Challenge not available yet"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for the challenge, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("date" = String, Path, description = "Challenge date as 'today' or 'YYYY-MM-DD' (UTC)", example = "today"),
+        LeaderboardParams,
+    )
+)]
+pub async fn challenge_leaderboard(
+    State(state): State<AppState>,
+    Path((lang, date)): Path<(String, String)>,
+    Query(params): Query<LeaderboardParams>,
+) -> Result<Json<Leaderboard>, AppError> {
+    Leaderboard::for_date(state.dbpool, &lang, &date, &params)
+        .await
+        .map(Json::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{JwtManager, PasswordHelper};
+    use crate::config::ApiConfig;
+    use crate::models::user::User;
+    use axum::http::{HeaderName, StatusCode};
+    use axum_test::TestServer;
+    use serde_json::{json, Value};
+    use sqlx::SqlitePool;
+    use tempfile::NamedTempFile;
+
+    async fn create_test_app() -> (TestServer, String, SqlitePool, NamedTempFile) {
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let password_hash = PasswordHelper::hash_password("challengepassword").unwrap();
+        let user = User::create_user(&dbpool, "player", &password_hash, false)
+            .await
+            .unwrap();
+
+        let secret = ApiConfig::default().jwt_settings.secret;
+        let token = JwtManager::generate_token(&user, &secret, 5).unwrap();
+
+        let app = axum::Router::new()
+            .route(
+                "/{lang}/challenge/{date}",
+                axum::routing::get(challenge_read),
+            )
+            .route(
+                "/{lang}/challenge/{date}/submissions",
+                axum::routing::post(challenge_submit),
+            )
+            .route(
+                "/{lang}/challenge/{date}/leaderboard",
+                axum::routing::get(challenge_leaderboard),
+            )
+            .with_state(state);
+
+        (TestServer::new(app).unwrap(), token, dbpool, temp_db)
+    }
+
+    #[tokio::test]
+    async fn test_challenge_is_stable() {
+        let (server, _token, _dbpool, _db) = create_test_app().await;
+
+        let first: Value = server.get("/en/challenge/2025-08-01").await.json();
+        let second: Value = server.get("/en/challenge/2025-08-01").await.json();
+        assert_eq!(first, second);
+        assert_eq!(first["words"].as_array().unwrap().len(), 10);
+
+        // the words of a past challenge are revealed
+        assert!(first["words"][0]["word"].is_string());
+
+        let response = server.get("/en/challenge/not-a-date").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+
+        let response = server.get("/en/challenge/2999-01-01").await;
+        response.assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_challenge_submission_and_leaderboard() {
+        let (server, token, dbpool, _db) = create_test_app().await;
+
+        // the words to spell of an open challenge aren't given away
+        let challenge: Value = server.get("/en/challenge/today").await.json();
+        let words = challenge["words"].as_array().unwrap();
+        assert_eq!(words.len(), 10);
+        assert!(words.iter().all(|w| w.get("word").is_none()));
+        assert!(words.iter().all(|w| w["definition"].is_string()));
+
+        let mut answers: Vec<String> = sqlx::query_scalar(
+            "SELECT w.word FROM daily_challenge_words w JOIN daily_challenges c ON c.id = w.challenge_id WHERE c.language = 'en' AND c.challenge_date = DATE('now') ORDER BY w.position",
+        )
+        .fetch_all(&dbpool)
+        .await
+        .unwrap();
+        answers[0] = "wrong".to_string();
+
+        // submissions require authentication
+        let response = server
+            .post("/en/challenge/today/submissions")
+            .json(&json!({ "answers": answers }))
+            .await;
+        response.assert_status(StatusCode::UNAUTHORIZED);
+
+        let response = server
+            .post("/en/challenge/today/submissions")
+            .add_header(
+                HeaderName::from_static("authorization"),
+                format!("Bearer {token}"),
+            )
+            .json(&json!({ "answers": answers }))
+            .await;
+        response.assert_status_ok();
+        let result: ChallengeResult = response.json();
+        assert_eq!(result.score, 9);
+        assert_eq!(result.total, 10);
+        assert!(!result.correct[0]);
+
+        // the same token can't be replayed
+        let response = server
+            .post("/en/challenge/today/submissions")
+            .add_header(
+                HeaderName::from_static("authorization"),
+                format!("Bearer {token}"),
+            )
+            .json(&json!({ "answers": answers }))
+            .await;
+        response.assert_status(StatusCode::CONFLICT);
+
+        let leaderboard: Value = server.get("/en/challenge/today/leaderboard").await.json();
+        let entries = leaderboard["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["username"], "player");
+        assert_eq!(entries[0]["rank"], 1);
+        assert_eq!(entries[0]["score"], 9);
+    }
+
+    #[tokio::test]
+    async fn test_challenge_submission_rejects_past_days() {
+        let (server, token, _dbpool, _db) = create_test_app().await;
+
+        let response = server
+            .post("/en/challenge/2025-08-01/submissions")
+            .add_header(
+                HeaderName::from_static("authorization"),
+                format!("Bearer {token}"),
+            )
+            .json(&json!({ "answers": ["one"] }))
+            .await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,552 @@
+//! Editorial endpoints for proposing words and changes.
+//!
+//! Editors propose new words and changes to existing ones, which wait in a
+//! review queue until an admin approves them, rejects them or asks for
+//! changes. Editors follow the state of their own proposals and revise
+//! those sent back to them. Nothing is written to the dictionary here.
+//! All endpoints require an editor or admin token and return JSON responses.
+
+use crate::error::AppError;
+use crate::models::proposal::WordProposal;
+use crate::models::user::AuthUser;
+use crate::models::word::UpsertWord;
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+
+/// Proposes a new word for review.
+///
+/// The word is validated like an admin write, so a proposal in the queue
+/// can be applied as it is. It is only added to the dictionary once an
+/// admin approves it.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+///
+/// # Request Body
+///
+/// JSON object with the same fields as a word creation.
+///
+/// # Returns
+///
+/// * `200 OK` - Proposal waiting for review
+/// * `400 Bad Request` - Unknown language, or a character isn't allowed by
+///   the validation profile of the language
+/// * `422 Unprocessable Entity` - Malformed word
+/// * `500 Internal Server Error` - Database error, or the word is invalid
+#[utoipa::path(
+    post,
+    context_path = "/editor",
+    path = "/{lang}/proposals",
+    operation_id = "editor_proposals_create",
+    tag = "editorial_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertWord, description = "Proposed word, with the same fields as a word creation", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Word proposed for review", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language, or a character isn't allowed by the validation profile of the language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token, or editor privileges required"),
+        (status = 422, description = "Malformed word"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposed word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+    )
+)]
+pub async fn proposal_create(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    user: AuthUser,
+    Json(word): Json<UpsertWord>,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::propose(&state.dbpool, &lang, None, word, &user).await?;
+    Ok(Json(proposal))
+}
+
+/// Proposes a change to an existing word for review.
+///
+/// The proposal holds the whole word as it should be after the change,
+/// and the version of the word it was made against. It can only be
+/// approved as long as the word isn't changed in the meantime.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique database identifier of the word to change
+///
+/// # Request Body
+///
+/// JSON object with the same fields as a word update.
+///
+/// # Returns
+///
+/// * `200 OK` - Proposal waiting for review
+/// * `400 Bad Request` - Unknown language, or a character isn't allowed by
+///   the validation profile of the language
+/// * `404 Not Found` - No word of the language exists with the ID
+/// * `422 Unprocessable Entity` - Malformed word
+/// * `500 Internal Server Error` - Database error, or the word is invalid
+#[utoipa::path(
+    post,
+    context_path = "/editor",
+    path = "/{lang}/words/{id}/proposals",
+    operation_id = "editor_proposals_create_change",
+    tag = "editorial_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertWord, description = "The word as it should be after the change, with the same fields as a word update", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Change proposed for review", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language, or a character isn't allowed by the validation profile of the language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token, or editor privileges required"),
+        (status = 404, description = "Couldn't find the word with {id}"),
+        (status = 422, description = "Malformed word"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique database identifier of the word to change", example = 1),
+    )
+)]
+pub async fn proposal_create_change(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: AuthUser,
+    Json(word): Json<UpsertWord>,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::propose(&state.dbpool, &lang, Some(id), word, &user).await?;
+    Ok(Json(proposal))
+}
+
+/// Lists the proposals of the editor.
+///
+/// Returns every proposal the editor made in a language, oldest first, in
+/// whatever state, with the comment of the last review.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+///
+/// # Returns
+///
+/// * `200 OK` - Proposals of the editor, the list is empty when there are none
+/// * `400 Bad Request` - Unknown language
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/editor",
+    path = "/{lang}/proposals",
+    operation_id = "editor_proposals_list",
+    tag = "editorial_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Proposals of the editor listed successfully", body = [WordProposal]),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token, or editor privileges required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposals, as registered by administrators (e.g. 'en' for English).", example = "en"),
+    )
+)]
+pub async fn proposal_list(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    user: AuthUser,
+) -> Result<Json<Vec<WordProposal>>, AppError> {
+    let proposals = WordProposal::list(&state.dbpool, &lang, None, Some(&user)).await?;
+    Ok(Json(proposals))
+}
+
+/// Retrieves a proposal of the editor.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the proposal
+///
+/// # Returns
+///
+/// * `200 OK` - The proposal, with its review state
+/// * `404 Not Found` - The editor made no proposal with the ID in the language
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    context_path = "/editor",
+    path = "/{lang}/proposals/{id}",
+    operation_id = "editor_proposals_read",
+    tag = "editorial_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Proposal retrieved successfully", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token, or editor privileges required"),
+        (status = 404, description = "Couldn't find the proposal with {id}"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposal, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the proposal", example = 1),
+    )
+)]
+pub async fn proposal_read(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: AuthUser,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::read(&state.dbpool, &lang, id, Some(&user)).await?;
+    Ok(Json(proposal))
+}
+
+/// Revises a proposal of the editor.
+///
+/// Replaces the proposed word of a pending proposal, or of one sent back
+/// for changes, which goes back to the review queue. A change is rebased
+/// on the current version of the word.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `id` - Unique identifier of the proposal
+///
+/// # Request Body
+///
+/// JSON object with the same fields as a word update.
+///
+/// # Returns
+///
+/// * `200 OK` - Revised proposal waiting for review
+/// * `400 Bad Request` - A character isn't allowed by the validation profile
+///   of the language
+/// * `404 Not Found` - The editor made no proposal with the ID in the
+///   language, or the word to change doesn't exist anymore
+/// * `409 Conflict` - The proposal was already approved or rejected
+/// * `422 Unprocessable Entity` - Malformed word
+/// * `500 Internal Server Error` - Database error, or the word is invalid
+#[utoipa::path(
+    put,
+    context_path = "/editor",
+    path = "/{lang}/proposals/{id}",
+    operation_id = "editor_proposals_revise",
+    tag = "editorial_endpoints",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body(content = UpsertWord, description = "Revised word, with the same fields as a word update", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Proposal revised and waiting for review", body = WordProposal),
+        (status = 400, description = "Bad request - Unknown language, or a character isn't allowed by the validation profile of the language"),
+        (status = 401, description = "Unauthorized - Invalid or missing authentication token, or editor privileges required"),
+        (status = 404, description = "Couldn't find the proposal with {id}, or the word it changes"),
+        (status = 409, description = "The proposal was already approved or rejected"),
+        (status = 422, description = "Malformed word"),
+        (status = 500, description = "Internal server error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the proposal, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("id" = u32, Path, description = "Unique identifier of the proposal", example = 1),
+    )
+)]
+pub async fn proposal_revise(
+    State(state): State<AppState>,
+    Path((lang, id)): Path<(String, u32)>,
+    user: AuthUser,
+    Json(word): Json<UpsertWord>,
+) -> Result<Json<WordProposal>, AppError> {
+    let proposal = WordProposal::revise(&state.dbpool, &lang, id, word, &user).await?;
+    Ok(Json(proposal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{editor_auth_middleware, JwtManager};
+    use crate::config::ApiConfig;
+    use crate::handlers::admin::{
+        proposal_approve, proposal_queue, proposal_reject, proposal_request_changes, word_update,
+    };
+    use crate::models::language::Language;
+    use crate::models::trash::TrashedWord;
+    use crate::models::user::User;
+    use crate::models::word::Word;
+    use axum::http::StatusCode;
+    use axum::middleware;
+
+    fn bearer(id: i64, username: &str, is_admin: bool, is_editor: bool) -> String {
+        let user = User {
+            id,
+            username: username.to_string(),
+            password_hash: String::new(),
+            is_admin,
+            is_editor,
+            created_at: None,
+            updated_at: None,
+        };
+        let secret = ApiConfig::default().jwt_settings.secret;
+        let token = JwtManager::generate_token(&user, &secret, 5).unwrap();
+        format!("Bearer {token}")
+    }
+
+    #[tokio::test]
+    async fn test_word_proposals() {
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let editor_routes = axum::Router::new()
+            .route(
+                "/editor/{lang}/proposals",
+                axum::routing::get(proposal_list).post(proposal_create),
+            )
+            .route(
+                "/editor/{lang}/proposals/{id}",
+                axum::routing::get(proposal_read).put(proposal_revise),
+            )
+            .route(
+                "/editor/{lang}/words/{id}/proposals",
+                axum::routing::post(proposal_create_change),
+            )
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                editor_auth_middleware,
+            ));
+        let app = axum::Router::new()
+            .merge(editor_routes)
+            .route(
+                "/admin/{lang}/proposals",
+                axum::routing::get(proposal_queue),
+            )
+            .route(
+                "/admin/{lang}/proposals/{id}/approve",
+                axum::routing::post(proposal_approve),
+            )
+            .route(
+                "/admin/{lang}/proposals/{id}/reject",
+                axum::routing::post(proposal_reject),
+            )
+            .route(
+                "/admin/{lang}/proposals/{id}/request-changes",
+                axum::routing::post(proposal_request_changes),
+            )
+            .route("/admin/{lang}/words/{id}", axum::routing::put(word_update))
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let teacher = bearer(7, "teacher", false, true);
+        let admin = bearer(1, "admin_user", true, false);
+
+        let english = Language::find(&dbpool, "en").await.unwrap();
+        let id = Word::find_id(&dbpool, &english, "cat").await.unwrap();
+        let cat = Word::read(dbpool.clone(), "en", id)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let cat = serde_json::to_value(UpsertWord::from(cat)).unwrap();
+        let mut kitten = cat.clone();
+        kitten["word"] = "zzkitten".into();
+
+        // only editors and admins can propose
+        server
+            .post("/editor/en/proposals")
+            .json(&kitten)
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+        server
+            .post("/editor/en/proposals")
+            .authorization(bearer(8, "reader", false, false))
+            .json(&kitten)
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+
+        // a new word waits in the queue instead of the dictionary
+        let response = server
+            .post("/editor/en/proposals")
+            .authorization(&teacher)
+            .json(&kitten)
+            .await;
+        response.assert_status_ok();
+        let proposal: serde_json::Value = response.json();
+        assert_eq!(proposal["status"], "pending");
+        assert_eq!(proposal["author"], "teacher");
+        assert!(proposal["wordId"].is_null());
+        let new_word = proposal["id"].as_u64().unwrap();
+        assert!(Word::find_id(&dbpool, &english, "zzkitten").await.is_err());
+
+        // a change records the version of the word it was made against
+        let mut change = cat.clone();
+        change["senses"][0]["definition"] = "a small, furry pet".into();
+        let response = server
+            .post(&format!("/editor/en/words/{id}/proposals"))
+            .authorization(&teacher)
+            .json(&change)
+            .await;
+        response.assert_status_ok();
+        let proposal: serde_json::Value = response.json();
+        assert_eq!(proposal["wordId"], id);
+        assert_eq!(proposal["baseVersion"], 1);
+        let edit = proposal["id"].as_u64().unwrap();
+        server
+            .post("/editor/en/words/999999/proposals")
+            .authorization(&teacher)
+            .json(&change)
+            .await
+            .assert_status_not_found();
+
+        // admins review the queue, oldest first
+        let queue: serde_json::Value = server
+            .get("/admin/en/proposals")
+            .authorization(&admin)
+            .await
+            .json();
+        let ids: Vec<u64> = queue
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(ids, vec![new_word, edit]);
+        server
+            .get("/admin/en/proposals?status=draft")
+            .await
+            .assert_status_bad_request();
+
+        // sending back for changes requires a comment, then the editor revises
+        let path = format!("/admin/en/proposals/{new_word}/request-changes");
+        server
+            .post(&path)
+            .json(&serde_json::json!({ "comment": " " }))
+            .await
+            .assert_status_bad_request();
+        let response = server
+            .post(&path)
+            .authorization(&admin)
+            .json(&serde_json::json!({ "comment": "add an example" }))
+            .await;
+        response.assert_status_ok();
+        let proposal: serde_json::Value = response.json();
+        assert_eq!(proposal["status"], "changes_requested");
+        assert_eq!(proposal["reviewer"], "admin_user");
+        server
+            .post(&format!("/admin/en/proposals/{new_word}/approve"))
+            .await
+            .assert_status(StatusCode::CONFLICT);
+        let proposal: serde_json::Value = server
+            .get(&format!("/editor/en/proposals/{new_word}"))
+            .authorization(&teacher)
+            .await
+            .json();
+        assert_eq!(proposal["reviewComment"], "add an example");
+        let response = server
+            .put(&format!("/editor/en/proposals/{new_word}"))
+            .authorization(&teacher)
+            .json(&kitten)
+            .await;
+        response.assert_status_ok();
+        assert_eq!(response.json::<serde_json::Value>()["status"], "pending");
+
+        // approving a new word adds it, as made by the reviewer
+        let response = server
+            .post(&format!("/admin/en/proposals/{new_word}/approve"))
+            .authorization(&admin)
+            .await;
+        response.assert_status_ok();
+        let proposal: serde_json::Value = response.json();
+        assert_eq!(proposal["status"], "approved");
+        let created = Word::find_id(&dbpool, &english, "zzkitten").await.unwrap();
+        assert_eq!(proposal["wordId"], created);
+        let (username,): (Option<String>,) =
+            sqlx::query_as("SELECT username FROM word_revisions WHERE word_id = $1")
+                .bind(created)
+                .fetch_one(&dbpool)
+                .await
+                .unwrap();
+        assert_eq!(username.as_deref(), Some("admin_user"));
+        server
+            .put(&format!("/editor/en/proposals/{new_word}"))
+            .authorization(&teacher)
+            .json(&kitten)
+            .await
+            .assert_status(StatusCode::CONFLICT);
+
+        // a change to a word changed in the meantime isn't applied
+        server
+            .put(&format!("/admin/en/words/{id}"))
+            .json(&cat)
+            .await
+            .assert_status_ok();
+        server
+            .post(&format!("/admin/en/proposals/{edit}/approve"))
+            .await
+            .assert_status(StatusCode::PRECONDITION_FAILED);
+        server
+            .post(&format!("/admin/en/proposals/{edit}/reject"))
+            .json(&serde_json::json!({ "comment": "already fixed" }))
+            .await
+            .assert_status_ok();
+
+        // editors only see their own proposals
+        let other = bearer(9, "other", false, true);
+        let mine: serde_json::Value = server
+            .get("/editor/en/proposals")
+            .authorization(&other)
+            .await
+            .json();
+        assert!(mine.as_array().unwrap().is_empty());
+        server
+            .get(&format!("/editor/en/proposals/{edit}"))
+            .authorization(&other)
+            .await
+            .assert_status_not_found();
+        let mine: serde_json::Value = server
+            .get("/editor/en/proposals")
+            .authorization(&teacher)
+            .await
+            .json();
+        assert_eq!(mine[1]["status"], "rejected");
+
+        // a change outlives its word, but can't be applied once it is purged
+        let dog = Word::find_id(&dbpool, &english, "dog").await.unwrap();
+        let change = serde_json::to_value(UpsertWord::from(
+            Word::read(dbpool.clone(), "en", dog)
+                .await
+                .unwrap()
+                .pop()
+                .unwrap(),
+        ))
+        .unwrap();
+        let response = server
+            .post(&format!("/editor/en/words/{dog}/proposals"))
+            .authorization(&teacher)
+            .json(&change)
+            .await;
+        let purged = response.json::<serde_json::Value>()["id"].as_u64().unwrap();
+        Word::delete(dbpool.clone(), "en", dog, None, None)
+            .await
+            .unwrap();
+        TrashedWord::purge(&dbpool, "en", dog, None).await.unwrap();
+        let proposal: serde_json::Value = server
+            .get(&format!("/editor/en/proposals/{purged}"))
+            .authorization(&teacher)
+            .await
+            .json();
+        assert!(proposal["wordId"].is_null());
+        assert_eq!(proposal["baseVersion"], 1);
+        server
+            .put(&format!("/editor/en/proposals/{purged}"))
+            .authorization(&teacher)
+            .json(&change)
+            .await
+            .assert_status_not_found();
+        server
+            .post(&format!("/admin/en/proposals/{purged}/approve"))
+            .await
+            .assert_status_not_found();
+    }
+}
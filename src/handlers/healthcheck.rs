@@ -117,10 +117,11 @@ mod tests {
                 3000,
                 "sqlite:test.db".to_string(),
                 vec!["localhost".to_string()],
+                "data/pronunciation".into(),
             ),
             compression: ApiCompression::default(),
             jwt_settings: JwtSettings::new(5, "test_secret".to_string()),
-            api_limits: ApiLimits::new(5, 10, 30, 1024),
+            api_limits: ApiLimits::new(5, 10, 30, 1024, 30, 10, 16384),
             openapi: OpenApiDocs::default(),
         };
 
@@ -0,0 +1,203 @@
+//! Public endpoints for homophone lookups
+//!
+//! Lists the words sounding like a given lemma, for spelling drills such as
+//! "their/there/they're". All endpoints are publicly accessible and return
+//! JSON responses.
+
+use crate::error::AppError;
+use crate::handlers::word::present_words;
+use crate::models::flag::SafeParams;
+use crate::models::homophone::find_homophones;
+use crate::models::pronunciation::PronunciationParams;
+use crate::models::user::AuthUser;
+use crate::models::word::{ExampleParams, GetWord, SenseParams};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+
+/// Retrieves the homophones of a lemma.
+///
+/// Returns every other word sharing an IPA transcription with the lemma, in
+/// any of its pronunciation variants, each with the shared pronunciation.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `lemma` - Word to find homophones for
+/// * `sense` - Optional sense selection: 'all' (default) or 'random'
+/// * `example` - Optional flag adding a random example sentence and the usage
+///   note of each word
+/// * `pronunciation_format` - Optional comma-separated extra notations
+///   ('respelling', 'arpabet', 'xsampa') derived from the IPA pronunciation
+/// * `safe` - Optional safe mode leaving out flagged words, on by default.
+///   Only administrators can turn it off with 'false'
+///
+/// # Returns
+///
+/// * `200 OK` - Homophones returned, the list is empty when there are none
+/// * `400 Bad Request` - Invalid language code, sense selection or pronunciation format
+/// * `403 Forbidden` - Safe mode turned off without administrator privileges
+/// * `404 Not Found` - Lemma is not in the dictionary
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/words/{lemma}/homophones",
+    operation_id = "public_word_homophones",
+    tag = "public_endpoints",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
+
+    responses(
+        (status = 200, description = "Homophones of the lemma successfully retrieved and returned", body = [GetWord]),
+        (status = 400, description = "Bad Request - Invalid language code, sense selection or pronunciation format provided"),
+        (status = 403, description = "Forbidden - Safe mode can only be turned off by administrators"),
+        (status = 404, description = "Not Found - Lemma is not in the dictionary"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("lemma" = String, Path, description = "Lemma to find homophones for", example = "there"),
+        SafeParams,
+        SenseParams,
+        ExampleParams,
+        PronunciationParams,
+    )
+)]
+pub async fn word_homophones(
+    State(state): State<AppState>,
+    Path((lang, lemma)): Path<(String, String)>,
+    user: Option<AuthUser>,
+    Query(safe): Query<SafeParams>,
+    Query(senses): Query<SenseParams>,
+    Query(examples): Query<ExampleParams>,
+    Query(params): Query<PronunciationParams>,
+) -> Result<Json<Vec<GetWord>>, AppError> {
+    let safe = safe.safe(user.as_ref())?;
+    let words = find_homophones(&state.dbpool, &lang, &lemma, safe).await?;
+
+    present_words(&state, &lang, words, &senses, &examples, &params)
+        .await
+        .map(Json::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::word::{word_random, word_type};
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use tempfile::NamedTempFile;
+
+    async fn create_test_app() -> (TestServer, NamedTempFile) {
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        for (word, word_type, pronunciation) in [
+            ("there", "adverb", "/ðɛr/"),
+            ("their", "noun", "/ðɛr/"),
+            ("zzthere", "noun", "/ðɛə/"),
+            ("zzbare", "noun", "/zzbɛr/"),
+            ("zzbear", "noun", "/zzbɛər/"),
+        ] {
+            let (id,): (i64,) = sqlx::query_as(
+                "INSERT INTO words (language, word, word_type) VALUES ('en', $1, $2) RETURNING id",
+            )
+            .bind(word)
+            .bind(word_type)
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+            sqlx::query(
+                "INSERT INTO word_senses (word_id, part_of_speech, definition) VALUES ($1, $2, $3)",
+            )
+            .bind(id)
+            .bind(word_type)
+            .bind(format!("homophone test {word}"))
+            .execute(&dbpool)
+            .await
+            .unwrap();
+            sqlx::query("INSERT INTO word_pronunciations (word_id, pronunciation) VALUES ($1, $2)")
+                .bind(id)
+                .bind(pronunciation)
+                .execute(&dbpool)
+                .await
+                .unwrap();
+        }
+
+        // a regional variant joins "there" to a second group
+        sqlx::query(
+            "INSERT INTO word_pronunciations (word_id, variant, pronunciation, position) SELECT id, 'uk', '/ðɛə/', 1 FROM words WHERE word = 'there'",
+        )
+        .execute(&dbpool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO word_pronunciations (word_id, variant, pronunciation, position) SELECT id, 'us', '/zzbɛr/', 1 FROM words WHERE word = 'zzbear'",
+        )
+        .execute(&dbpool)
+        .await
+        .unwrap();
+
+        let app = axum::Router::new()
+            .route(
+                "/{lang}/words/{lemma}/homophones",
+                axum::routing::get(word_homophones),
+            )
+            .route("/{lang}/random", axum::routing::get(word_random))
+            .route("/{lang}/{type}", axum::routing::get(word_type))
+            .with_state(state);
+
+        (TestServer::new(app).unwrap(), temp_db)
+    }
+
+    #[tokio::test]
+    async fn test_word_homophones() {
+        let (server, _db) = create_test_app().await;
+
+        let response = server.get("/en/words/There/homophones").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        let words: Vec<&str> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["word"].as_str().unwrap())
+            .collect();
+        assert_eq!(words, vec!["their", "zzthere"]);
+
+        let response = server.get("/en/words/zzzz/homophones").await;
+        response.assert_status(StatusCode::NOT_FOUND);
+
+        let response = server.get("/xyz/words/there/homophones").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_random_homophones_mode() {
+        let (server, _db) = create_test_app().await;
+
+        let response = server.get("/en/random?mode=homophones").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        let group = json.as_array().unwrap();
+        assert!(group.len() >= 2);
+        assert!(group
+            .iter()
+            .all(|w| w["pronunciation"] == group[0]["pronunciation"]));
+
+        // the "zzbare/zzbear" group has no adverb
+        let response = server.get("/en/adverb?mode=homophones").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert!(json
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|w| w["word"] == "there"));
+
+        let response = server.get("/en/random?mode=anagrams").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+}
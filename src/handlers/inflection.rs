@@ -0,0 +1,216 @@
+//! Public endpoints for resolving inflected forms
+//!
+//! Resolves an inflected form such as "ran" or "mice" back to its lemma
+//! for spelling practice. All endpoints are publicly accessible and return
+//! JSON responses.
+
+use crate::error::AppError;
+use crate::handlers::word::present_words;
+use crate::models::flag::SafeParams;
+use crate::models::inflection::{find_lemmas, LemmaMatch};
+use crate::models::pronunciation::PronunciationParams;
+use crate::models::user::AuthUser;
+use crate::models::word::{ExampleParams, SenseParams};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+
+/// Retrieves the lemmas of a word form.
+///
+/// Returns every lemma the form is an inflection of, with the kind of
+/// inflection, in alphabetical order. A form which is a lemma itself is
+/// returned with the `lemma` kind.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `form` - Inflected form to resolve (e.g. 'ran', 'mice')
+/// * `sense` - Optional sense selection: 'all' (default) or 'random'
+/// * `example` - Optional flag adding a random example sentence and the usage
+///   note of each word
+/// * `pronunciation_format` - Optional comma-separated extra notations
+///   ('respelling', 'arpabet', 'xsampa') derived from the IPA pronunciation
+/// * `safe` - Optional safe mode leaving out flagged words, on by default.
+///   Only administrators can turn it off with 'false'
+///
+/// # Returns
+///
+/// * `200 OK` - Lemmas of the form returned
+/// * `400 Bad Request` - Invalid language code, sense selection or pronunciation format
+/// * `403 Forbidden` - Safe mode turned off without administrator privileges
+/// * `404 Not Found` - Form is neither an inflection nor a lemma in the dictionary
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/words/{form}/lemma",
+    operation_id = "public_word_lemma",
+    tag = "public_endpoints",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
+
+    responses(
+        (status = 200, description = "Lemmas of the form successfully retrieved and returned", body = [LemmaMatch]),
+        (status = 400, description = "Bad Request - Invalid language code, sense selection or pronunciation format provided"),
+        (status = 403, description = "Forbidden - Safe mode can only be turned off by administrators"),
+        (status = 404, description = "Not Found - Form is neither an inflection nor a lemma in the dictionary"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("form" = String, Path, description = "Inflected form to resolve to its lemma", example = "ran"),
+        SafeParams,
+        SenseParams,
+        ExampleParams,
+        PronunciationParams,
+    )
+)]
+pub async fn word_lemma(
+    State(state): State<AppState>,
+    Path((lang, form)): Path<(String, String)>,
+    user: Option<AuthUser>,
+    Query(safe): Query<SafeParams>,
+    Query(senses): Query<SenseParams>,
+    Query(examples): Query<ExampleParams>,
+    Query(params): Query<PronunciationParams>,
+) -> Result<Json<Vec<LemmaMatch>>, AppError> {
+    let safe = safe.safe(user.as_ref())?;
+    let (inflections, words): (Vec<_>, Vec<_>) = find_lemmas(&state.dbpool, &lang, &form, safe)
+        .await?
+        .into_iter()
+        .map(|m| (m.inflection, m.word))
+        .unzip();
+
+    let words = present_words(&state, &lang, words, &senses, &examples, &params).await?;
+
+    Ok(Json(
+        inflections
+            .into_iter()
+            .zip(words)
+            .map(|(inflection, word)| LemmaMatch { inflection, word })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::inflection::WordInflection;
+    use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use tempfile::NamedTempFile;
+
+    fn upsert(word: &str, word_type: &str, inflections: &[(&str, &str)]) -> UpsertWord {
+        UpsertWord {
+            word: word.to_string(),
+            senses: vec![WordSense {
+                part_of_speech: word_type.to_string(),
+                definition: format!("inflection test {word} {word_type}"),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: inflections
+                .iter()
+                .map(|(kind, form)| WordInflection {
+                    kind: kind.to_string(),
+                    form: form.to_string(),
+                })
+                .collect(),
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: format!("/zz{word}/"),
+            }],
+            word_type: word_type.to_string(),
+        }
+    }
+
+    async fn create_test_app() -> (TestServer, NamedTempFile) {
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        sqlx::query("DELETE FROM words")
+            .execute(&dbpool)
+            .await
+            .unwrap();
+        for word in [
+            upsert("mouse", "noun", &[("plural", "mice")]),
+            upsert(
+                "run",
+                "verb",
+                &[("past_tense", "ran"), ("Past_Participle", "Run")],
+            ),
+            upsert(
+                "leave",
+                "verb",
+                &[("past_tense", "left"), ("past_participle", "left")],
+            ),
+            upsert("left", "adjective", &[]),
+        ] {
+            Word::create(dbpool.clone(), "en", word, None)
+                .await
+                .unwrap();
+        }
+
+        let app = axum::Router::new()
+            .route("/{lang}/words/{form}/lemma", axum::routing::get(word_lemma))
+            .with_state(state);
+
+        (TestServer::new(app).unwrap(), temp_db)
+    }
+
+    fn lemmas(json: &serde_json::Value) -> Vec<(&str, &str)> {
+        json.as_array()
+            .unwrap()
+            .iter()
+            .map(|m| {
+                (
+                    m["word"].as_str().unwrap(),
+                    m["inflection"].as_str().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_word_lemma() {
+        let (server, _db) = create_test_app().await;
+
+        let response = server.get("/en/words/MICE/lemma").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(lemmas(&json), vec![("mouse", "plural")]);
+        assert_eq!(json[0]["definition"], "inflection test mouse noun");
+
+        let response = server.get("/en/words/ran/lemma").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(lemmas(&json), vec![("run", "past_tense")]);
+
+        // a form can be a lemma and an inflection at once
+        let response = server.get("/en/words/run/lemma").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(
+            lemmas(&json),
+            vec![("run", "lemma"), ("run", "past_participle")]
+        );
+        let response = server.get("/en/words/left/lemma").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(
+            lemmas(&json),
+            vec![
+                ("leave", "past_participle"),
+                ("leave", "past_tense"),
+                ("left", "lemma")
+            ]
+        );
+
+        let response = server.get("/en/words/zzzz/lemma").await;
+        response.assert_status(StatusCode::NOT_FOUND);
+        let response = server.get("/xyz/words/ran/lemma").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+}
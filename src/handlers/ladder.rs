@@ -0,0 +1,168 @@
+//! Public endpoints for word ladder puzzles
+//!
+//! Solves the shortest one-letter-change ladder between two lemmas and
+//! generates random ladder puzzles from the dictionary.
+//! All endpoints are publicly accessible and return JSON responses.
+
+use crate::error::AppError;
+use crate::models::ladder::{LadderPuzzleParams, LadderSolveParams, WordLadder};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+
+/// Solves the shortest word ladder between two lemmas.
+///
+/// Walks the one-letter-change graph of all dictionary words sharing the
+/// same length and returns the shortest chain from `from` to `to`.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `from` - Starting lemma
+/// * `to` - Target lemma with the same number of letters as `from`
+///
+/// # Returns
+///
+/// * `200 OK` - Shortest ladder found and returned
+/// * `400 Bad Request` - Invalid language code or lemmas of different length
+/// * `404 Not Found` - Unknown lemma or no ladder connects the two words
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/ladder/solve",
+    operation_id = "public_ladder_solve",
+    tag = "public_endpoints",
+
+    responses(
+        (status = 200, description = "Shortest word ladder successfully found and returned", body = WordLadder),
+        (status = 400, description = "Bad Request - Invalid language code or lemmas of different length"),
+        (status = 404, description = "Not Found - Unknown lemma or no ladder connects the two words"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        LadderSolveParams,
+    )
+)]
+pub async fn ladder_solve(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<LadderSolveParams>,
+) -> Result<Json<WordLadder>, AppError> {
+    state
+        .ladders
+        .solve(&state.dbpool, &lang, &params.from, &params.to)
+        .await
+        .map(Json::from)
+}
+
+/// Generates a random word ladder puzzle.
+///
+/// Picks two lemmas whose shortest ladder has exactly the requested number
+/// of words. The full solution is included so clients can check answers.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `length` - Number of words in the ladder, including start and end (2-12)
+/// * `letters` - Optional number of letters per word
+///
+/// # Returns
+///
+/// * `200 OK` - Random ladder puzzle generated and returned
+/// * `400 Bad Request` - Invalid language code or ladder length
+/// * `404 Not Found` - No ladder of the requested shape exists in the dictionary
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/ladder/random",
+    operation_id = "public_ladder_random",
+    tag = "public_endpoints",
+
+    responses(
+        (status = 200, description = "Random word ladder puzzle successfully generated and returned", body = WordLadder),
+        (status = 400, description = "Bad Request - Invalid language code or ladder length"),
+        (status = 404, description = "Not Found - No ladder of the requested length exists in the dictionary"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        LadderPuzzleParams,
+    )
+)]
+pub async fn ladder_random(
+    State(state): State<AppState>,
+    Path(lang): Path<String>,
+    Query(params): Query<LadderPuzzleParams>,
+) -> Result<Json<WordLadder>, AppError> {
+    state
+        .ladders
+        .puzzle(&state.dbpool, &lang, &params)
+        .await
+        .map(Json::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum_test::TestServer;
+    use tempfile::NamedTempFile;
+
+    async fn create_test_app() -> (TestServer, NamedTempFile) {
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        for word in ["zzaa", "zzab", "zzbb"] {
+            sqlx::query("INSERT INTO words (language, word, word_type) VALUES ('en', $1, 'noun')")
+                .bind(word)
+                .execute(&dbpool)
+                .await
+                .unwrap();
+        }
+
+        let app = axum::Router::new()
+            .route("/{lang}/ladder/solve", axum::routing::get(ladder_solve))
+            .route("/{lang}/ladder/random", axum::routing::get(ladder_random))
+            .with_state(state);
+
+        (TestServer::new(app).unwrap(), temp_db)
+    }
+
+    #[tokio::test]
+    async fn test_ladder_solve() {
+        let (server, _db) = create_test_app().await;
+
+        let response = server.get("/en/ladder/solve?from=zzaa&to=zzbb").await;
+        response.assert_status_ok();
+
+        let ladder: WordLadder = response.json();
+        assert_eq!(ladder.ladder, vec!["zzaa", "zzab", "zzbb"]);
+        assert_eq!(ladder.steps, 2);
+
+        let response = server.get("/en/ladder/solve?from=zzaa&to=zzaaa").await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+        let response = server.get("/en/ladder/solve?from=zzaa&to=zzzz").await;
+        response.assert_status(axum::http::StatusCode::NOT_FOUND);
+
+        let response = server.get("/xyz/ladder/solve?from=zzaa&to=zzbb").await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_ladder_random() {
+        let (server, _db) = create_test_app().await;
+
+        let response = server.get("/en/ladder/random?length=3&letters=4").await;
+        response.assert_status_ok();
+
+        let ladder: WordLadder = response.json();
+        assert_eq!(ladder.ladder.len(), 3);
+
+        let response = server.get("/en/ladder/random?length=1").await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+        let response = server.get("/en/ladder/random?length=12&letters=30").await;
+        response.assert_status(axum::http::StatusCode::NOT_FOUND);
+    }
+}
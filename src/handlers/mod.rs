@@ -3,12 +3,28 @@
 //! # Modules
 //! - `admin`: Word management endpoints (requires auth)
 //! - `auth`: Authentication endpoints for login
+//! - `editor`: Word and change proposals of editors (requires auth)
+//! - `challenge`: Daily challenge and leaderboard endpoints
 //! - `healthcheck`: System status endpoints
+//! - `homophone`: Public homophone lookup endpoints
+//! - `inflection`: Public inflected form lookup endpoints
+//! - `ladder`: Public word ladder solver and puzzle endpoints
+//! - `relation`: Public related word lookup endpoints
+//! - `report`: Public problem report endpoints
+//! - `translation`: Public translation lookup endpoints
 //! - `word`: Public word retrieval endpoints
 //!
 //! All handlers return JSON responses and use centralized error handling.
 
 pub mod admin;
 pub mod auth;
+pub mod challenge;
+pub mod editor;
 pub mod healthcheck;
+pub mod homophone;
+pub mod inflection;
+pub mod ladder;
+pub mod relation;
+pub mod report;
+pub mod translation;
 pub mod word;
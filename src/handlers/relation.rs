@@ -0,0 +1,296 @@
+//! Public endpoints for related word lookups
+//!
+//! Lists the synonyms, antonyms, hypernyms and derived forms of a lemma
+//! for vocabulary lessons. All endpoints are publicly accessible and return
+//! JSON responses.
+
+use crate::error::AppError;
+use crate::handlers::word::present_words;
+use crate::models::flag::SafeParams;
+use crate::models::pronunciation::PronunciationParams;
+use crate::models::relation::{find_related, RelatedParams, RelatedWord};
+use crate::models::user::AuthUser;
+use crate::models::word::{ExampleParams, SenseParams};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+
+/// Retrieves the words related to a lemma.
+///
+/// Returns the related words grouped by kind, each in alphabetical order.
+/// Synonyms and antonyms are found from either word, hypernyms and derived
+/// forms only from the word they belong to.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `lemma` - Word to find related words for
+/// * `kind` - Optional relation kind: 'synonym', 'antonym', 'hypernym' or
+///   'derived', all kinds when absent
+/// * `sense` - Optional sense selection: 'all' (default) or 'random'
+/// * `example` - Optional flag adding a random example sentence and the usage
+///   note of each word
+/// * `pronunciation_format` - Optional comma-separated extra notations
+///   ('respelling', 'arpabet', 'xsampa') derived from the IPA pronunciation
+/// * `safe` - Optional safe mode leaving out flagged words, on by default.
+///   Only administrators can turn it off with 'false'
+///
+/// # Returns
+///
+/// * `200 OK` - Related words returned, the list is empty when there are none
+/// * `400 Bad Request` - Invalid language code, relation kind, sense selection
+///   or pronunciation format
+/// * `403 Forbidden` - Safe mode turned off without administrator privileges
+/// * `404 Not Found` - Lemma is not in the dictionary
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/words/{lemma}/related",
+    operation_id = "public_word_related",
+    tag = "public_endpoints",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
+
+    responses(
+        (status = 200, description = "Words related to the lemma successfully retrieved and returned", body = [RelatedWord]),
+        (status = 400, description = "Bad Request - Invalid language code, relation kind, sense selection or pronunciation format provided"),
+        (status = 403, description = "Forbidden - Safe mode can only be turned off by administrators"),
+        (status = 404, description = "Not Found - Lemma is not in the dictionary"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("lemma" = String, Path, description = "Lemma to find related words for", example = "happy"),
+        SafeParams,
+        RelatedParams,
+        SenseParams,
+        ExampleParams,
+        PronunciationParams,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn word_related(
+    State(state): State<AppState>,
+    Path((lang, lemma)): Path<(String, String)>,
+    user: Option<AuthUser>,
+    Query(safe): Query<SafeParams>,
+    Query(related): Query<RelatedParams>,
+    Query(senses): Query<SenseParams>,
+    Query(examples): Query<ExampleParams>,
+    Query(params): Query<PronunciationParams>,
+) -> Result<Json<Vec<RelatedWord>>, AppError> {
+    let safe = safe.safe(user.as_ref())?;
+    let (kinds, words): (Vec<_>, Vec<_>) =
+        find_related(&state.dbpool, &lang, &lemma, related.kind()?, safe)
+            .await?
+            .into_iter()
+            .map(|r| (r.kind, r.word))
+            .unzip();
+
+    let words = present_words(&state, &lang, words, &senses, &examples, &params).await?;
+
+    Ok(Json(
+        kinds
+            .into_iter()
+            .zip(words)
+            .map(|(kind, word)| RelatedWord { kind, word })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::relation::{UpsertRelation, WordRelation};
+    use crate::models::trash::TrashedWord;
+    use crate::models::word::Word;
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use std::collections::HashMap;
+    use tempfile::NamedTempFile;
+
+    async fn create_test_app() -> (
+        TestServer,
+        sqlx::SqlitePool,
+        HashMap<String, u32>,
+        NamedTempFile,
+    ) {
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let mut ids = HashMap::new();
+        for (word, word_type) in [
+            ("zzhappy", "adjective"),
+            ("zzglad", "adjective"),
+            ("zzsad", "adjective"),
+            ("zzhappiness", "noun"),
+            ("zzfeeling", "noun"),
+        ] {
+            let (id,): (u32,) = sqlx::query_as(
+                "INSERT INTO words (language, word, word_type) VALUES ('en', $1, $2) RETURNING id",
+            )
+            .bind(word)
+            .bind(word_type)
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+            sqlx::query(
+                "INSERT INTO word_senses (word_id, part_of_speech, definition) VALUES ($1, $2, $3)",
+            )
+            .bind(id)
+            .bind(word_type)
+            .bind(format!("relation test {word}"))
+            .execute(&dbpool)
+            .await
+            .unwrap();
+            sqlx::query("INSERT INTO word_pronunciations (word_id, pronunciation) VALUES ($1, $2)")
+                .bind(id)
+                .bind(format!("/{word}/"))
+                .execute(&dbpool)
+                .await
+                .unwrap();
+            ids.insert(word.to_string(), id);
+        }
+
+        let app = axum::Router::new()
+            .route(
+                "/{lang}/words/{lemma}/related",
+                axum::routing::get(word_related),
+            )
+            .with_state(state);
+
+        (TestServer::new(app).unwrap(), dbpool, ids, temp_db)
+    }
+
+    fn relation(kind: &str, related_id: u32) -> UpsertRelation {
+        UpsertRelation {
+            kind: kind.to_string(),
+            related_id,
+        }
+    }
+
+    fn related_words(json: &serde_json::Value) -> Vec<(String, String)> {
+        json.as_array()
+            .unwrap()
+            .iter()
+            .map(|w| {
+                (
+                    w["kind"].as_str().unwrap().to_string(),
+                    w["word"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_word_related() {
+        let (server, dbpool, ids, _db) = create_test_app().await;
+        let happy = ids["zzhappy"];
+
+        for (word_id, kind, related) in [
+            (ids["zzglad"], "synonym", "zzhappy"),
+            (happy, "antonym", "zzsad"),
+            (happy, "Hypernym", "zzfeeling"),
+            (happy, "derived", "zzhappiness"),
+        ] {
+            WordRelation::create(&dbpool, "en", word_id, relation(kind, ids[related]))
+                .await
+                .unwrap();
+        }
+
+        let response = server.get("/en/words/ZZhappy/related").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(
+            related_words(&json),
+            vec![
+                ("antonym".to_string(), "zzsad".to_string()),
+                ("derived".to_string(), "zzhappiness".to_string()),
+                ("hypernym".to_string(), "zzfeeling".to_string()),
+                ("synonym".to_string(), "zzglad".to_string()),
+            ]
+        );
+        assert_eq!(json[0]["definition"], "relation test zzsad");
+
+        // symmetric relations are found from both sides, directional ones are not
+        let response = server.get("/en/words/zzsad/related?kind=antonym").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(
+            related_words(&json),
+            vec![("antonym".to_string(), "zzhappy".to_string())]
+        );
+        let response = server.get("/en/words/zzfeeling/related").await;
+        let json: serde_json::Value = response.json();
+        assert!(json.as_array().unwrap().is_empty());
+
+        let response = server.get("/en/words/zzhappy/related?kind=meronym").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+        let response = server.get("/en/words/zzzz/related").await;
+        response.assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_word_relations_admin() {
+        let (_server, dbpool, ids, _db) = create_test_app().await;
+        let happy = ids["zzhappy"];
+
+        let created =
+            WordRelation::create(&dbpool, "en", happy, relation("synonym", ids["zzglad"]))
+                .await
+                .unwrap();
+        assert_eq!(created.len(), 1);
+        WordRelation::create(&dbpool, "en", happy, relation("hypernym", ids["zzfeeling"]))
+            .await
+            .unwrap();
+
+        // the reverse of a symmetric relation is the same relation
+        let duplicate =
+            WordRelation::create(&dbpool, "en", ids["zzglad"], relation("synonym", happy)).await;
+        assert!(duplicate.is_err());
+        // a word can't be related to itself, or to a word that doesn't exist
+        assert!(
+            WordRelation::create(&dbpool, "en", happy, relation("antonym", happy))
+                .await
+                .is_err()
+        );
+        assert!(
+            WordRelation::create(&dbpool, "en", happy, relation("antonym", 9999))
+                .await
+                .is_err()
+        );
+        assert!(
+            WordRelation::create(&dbpool, "en", happy, relation("meronym", ids["zzsad"]))
+                .await
+                .is_err()
+        );
+
+        // directional relations can only be deleted from the word they belong to
+        let listed = WordRelation::list(&dbpool, "en", happy).await.unwrap();
+        let json = serde_json::to_value(&listed).unwrap();
+        let hypernym_id = json[0]["id"].as_u64().unwrap() as u32;
+        assert_eq!(json[0]["kind"], "hypernym");
+        assert!(
+            WordRelation::delete(&dbpool, "en", ids["zzfeeling"], hypernym_id)
+                .await
+                .is_err()
+        );
+        WordRelation::delete(&dbpool, "en", happy, hypernym_id)
+            .await
+            .unwrap();
+
+        // relations go away with either of their words, once purged from the trash
+        Word::delete(dbpool.clone(), "en", ids["zzglad"], None, None)
+            .await
+            .unwrap();
+        TrashedWord::purge(&dbpool, "en", ids["zzglad"], None)
+            .await
+            .unwrap();
+        let (left,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM word_relations")
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+}
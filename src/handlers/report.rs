@@ -0,0 +1,229 @@
+//! Public endpoints for reporting problems with words
+//!
+//! Lets any client flag a word with a wrong definition, a bad pronunciation
+//! or inappropriate content. Reports wait in a triage queue for admins, and
+//! are rate limited per IP address. All endpoints are publicly accessible
+//! and return JSON responses.
+
+use crate::error::AppError;
+use crate::models::report::{NewReport, WordReport};
+use crate::state::AppState;
+use axum::extract::{Path, State};
+use axum::Json;
+
+/// Reports a problem with a word.
+///
+/// The report waits in the triage queue of the admins, with the reason and
+/// the optional note. Words in the trash can't be reported.
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered and enabled language (e.g. 'en' for English)
+/// * `lemma` - Word to report
+///
+/// # Request Body
+///
+/// JSON object with the `reason` ('wrong_definition', 'bad_pronunciation',
+/// 'inappropriate' or 'other') and an optional `note`.
+///
+/// # Returns
+///
+/// * `200 OK` - Problem reported
+/// * `400 Bad Request` - Unknown or disabled language, or invalid reason
+/// * `404 Not Found` - Lemma is not in the dictionary
+/// * `429 Too Many Requests` - Too many reports from the IP address
+/// * `500 Internal Server Error` - Database error, or the note is too long
+#[utoipa::path(
+    post,
+    path = "/{lang}/words/{lemma}/reports",
+    operation_id = "public_word_report",
+    tag = "public_endpoints",
+    request_body(content = NewReport, description = "Reason of the report and an optional note", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Problem with the word reported", body = WordReport),
+        (status = 400, description = "Bad Request - Invalid language code or reason provided"),
+        (status = 404, description = "Not Found - Lemma is not in the dictionary"),
+        (status = 429, description = "Too Many Requests - Report limit of the IP address reached"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code of the word, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("lemma" = String, Path, description = "Lemma to report a problem with", example = "cat"),
+    )
+)]
+pub async fn word_report(
+    State(state): State<AppState>,
+    Path((lang, lemma)): Path<(String, String)>,
+    Json(report): Json<NewReport>,
+) -> Result<Json<WordReport>, AppError> {
+    let report = WordReport::submit(&state.dbpool, &lang, &lemma, &report).await?;
+    Ok(Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::JwtManager;
+    use crate::config::ApiConfig;
+    use crate::handlers::admin::{report_dismiss, report_queue, report_resolve, reported_words};
+    use crate::models::language::{Language, UpsertLanguage};
+    use crate::models::user::User;
+    use crate::models::word::Word;
+    use axum::http::StatusCode;
+
+    #[tokio::test]
+    async fn test_word_reports() {
+        let (state, _temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        let app = axum::Router::new()
+            .route(
+                "/{lang}/words/{lemma}/reports",
+                axum::routing::post(word_report),
+            )
+            .route("/admin/{lang}/reports", axum::routing::get(report_queue))
+            .route(
+                "/admin/{lang}/reports/words",
+                axum::routing::get(reported_words),
+            )
+            .route(
+                "/admin/{lang}/reports/{id}/resolve",
+                axum::routing::post(report_resolve),
+            )
+            .route(
+                "/admin/{lang}/reports/{id}/dismiss",
+                axum::routing::post(report_dismiss),
+            )
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+        let admin = User {
+            id: 1,
+            username: "admin_user".to_string(),
+            password_hash: String::new(),
+            is_admin: true,
+            is_editor: false,
+            created_at: None,
+            updated_at: None,
+        };
+        let secret = ApiConfig::default().jwt_settings.secret;
+        let admin = format!(
+            "Bearer {}",
+            JwtManager::generate_token(&admin, &secret, 5).unwrap()
+        );
+
+        // anyone can report a word, with a known reason
+        let response = server
+            .post("/en/words/Cat/reports")
+            .json(&serde_json::json!({ "reason": "wrong_definition", "note": " not a dog " }))
+            .await;
+        response.assert_status_ok();
+        let report: serde_json::Value = response.json();
+        assert_eq!(report["word"], "cat");
+        assert_eq!(report["status"], "open");
+        assert_eq!(report["note"], "not a dog");
+        let first = report["id"].as_u64().unwrap();
+        for reason in ["bad_pronunciation", "wrong_definition"] {
+            server
+                .post("/en/words/cat/reports")
+                .json(&serde_json::json!({ "reason": reason }))
+                .await
+                .assert_status_ok();
+        }
+        let response = server
+            .post("/en/words/dog/reports")
+            .json(&serde_json::json!({ "reason": "inappropriate" }))
+            .await;
+        let dog = response.json::<serde_json::Value>()["id"].as_u64().unwrap();
+        server
+            .post("/en/words/cat/reports")
+            .json(&serde_json::json!({ "reason": "typo" }))
+            .await
+            .assert_status_bad_request();
+        server
+            .post("/en/words/zzunknown/reports")
+            .json(&serde_json::json!({ "reason": "other" }))
+            .await
+            .assert_status_not_found();
+
+        // words of disabled languages aren't served, nor reported
+        let german = UpsertLanguage {
+            code: "de".to_string(),
+            name: "German".to_string(),
+            script: "Latn".to_string(),
+            validation_profile: "latin".to_string(),
+            enabled: false,
+            type_names: Default::default(),
+        };
+        Language::create(&dbpool, german).await.unwrap();
+        let hund = serde_json::from_value(serde_json::json!({
+            "word": "hund",
+            "wordType": "noun",
+            "senses": [{"definition": "a dog", "partOfSpeech": "noun"}],
+            "pronunciations": [{"pronunciation": "/hʊnt/"}],
+        }))
+        .unwrap();
+        Word::create(dbpool.clone(), "de", hund, None)
+            .await
+            .unwrap();
+        server
+            .post("/de/words/hund/reports")
+            .json(&serde_json::json!({ "reason": "other" }))
+            .await
+            .assert_status_bad_request();
+
+        // admins triage the open reports, one by one or grouped by word
+        let queue: serde_json::Value = server
+            .get("/admin/en/reports")
+            .authorization(&admin)
+            .await
+            .json();
+        assert_eq!(queue.as_array().unwrap().len(), 4);
+        assert_eq!(queue[0]["id"], first);
+        let words: serde_json::Value = server.get("/admin/en/reports/words").await.json();
+        assert_eq!(words[0]["word"], "cat");
+        assert_eq!(words[0]["reports"], 3);
+        assert_eq!(
+            words[0]["reasons"],
+            serde_json::json!(["bad_pronunciation", "wrong_definition"])
+        );
+        assert_eq!(words[1]["word"], "dog");
+        server
+            .get("/admin/en/reports?status=closed")
+            .await
+            .assert_status_bad_request();
+
+        // open reports are resolved or dismissed once
+        let response = server
+            .post(&format!("/admin/en/reports/{first}/resolve"))
+            .authorization(&admin)
+            .json(&serde_json::json!({ "note": "definition fixed" }))
+            .await;
+        response.assert_status_ok();
+        let report: serde_json::Value = response.json();
+        assert_eq!(report["status"], "resolved");
+        assert_eq!(report["resolver"], "admin_user");
+        assert_eq!(report["resolutionNote"], "definition fixed");
+        server
+            .post(&format!("/admin/en/reports/{first}/dismiss"))
+            .json(&serde_json::json!({}))
+            .await
+            .assert_status(StatusCode::CONFLICT);
+        let response = server
+            .post(&format!("/admin/en/reports/{dog}/dismiss"))
+            .json(&serde_json::json!({}))
+            .await;
+        response.assert_status_ok();
+        assert_eq!(response.json::<serde_json::Value>()["status"], "dismissed");
+        server
+            .post("/admin/en/reports/999999/resolve")
+            .json(&serde_json::json!({}))
+            .await
+            .assert_status_not_found();
+
+        let queue: serde_json::Value = server.get("/admin/en/reports").await.json();
+        assert_eq!(queue.as_array().unwrap().len(), 2);
+        let resolved: serde_json::Value =
+            server.get("/admin/en/reports?status=resolved").await.json();
+        assert_eq!(resolved[0]["id"], first);
+    }
+}
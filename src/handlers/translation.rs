@@ -0,0 +1,390 @@
+//! Public endpoints for translation lookups
+//!
+//! Lists the translations of a lemma into other languages for language
+//! learning. All endpoints are publicly accessible and return JSON responses.
+
+use crate::error::AppError;
+use crate::handlers::word::present_words;
+use crate::models::flag::SafeParams;
+use crate::models::pronunciation::PronunciationParams;
+use crate::models::translation::{find_translations, TranslatedWord, TranslationParams};
+use crate::models::user::AuthUser;
+use crate::models::word::{ExampleParams, SenseParams};
+use crate::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+
+/// Retrieves the translations of a lemma.
+///
+/// Returns the translations grouped by language, each in alphabetical order.
+/// Translations linked to one sense come with the definition of that sense,
+/// both for the lemma (`sense`) and for the translation (`definition`).
+///
+/// # Parameters
+///
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `lemma` - Word to find translations for
+/// * `to` - Optional code of the language to translate to, every enabled
+///   language when absent
+/// * `sense` - Optional sense selection: 'all' (default) or 'random'
+/// * `example` - Optional flag adding a random example sentence and the usage
+///   note of each translation
+/// * `pronunciation_format` - Optional comma-separated extra notations
+///   ('respelling', 'arpabet', 'xsampa') derived from the IPA pronunciation
+/// * `safe` - Optional safe mode leaving out flagged words, on by default.
+///   Only administrators can turn it off with 'false'
+///
+/// # Returns
+///
+/// * `200 OK` - Translations returned, the list is empty when there are none
+/// * `400 Bad Request` - Invalid language code, sense selection or
+///   pronunciation format
+/// * `403 Forbidden` - Safe mode turned off without administrator privileges
+/// * `404 Not Found` - Lemma is not in the dictionary
+/// * `500 Internal Server Error` - Database connection or query error
+#[utoipa::path(
+    get,
+    path = "/{lang}/words/{lemma}/translations",
+    operation_id = "public_word_translations",
+    tag = "public_endpoints",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
+
+    responses(
+        (status = 200, description = "Translations of the lemma successfully retrieved and returned", body = [TranslatedWord]),
+        (status = 400, description = "Bad Request - Invalid language code, sense selection or pronunciation format provided"),
+        (status = 403, description = "Forbidden - Safe mode can only be turned off by administrators"),
+        (status = 404, description = "Not Found - Lemma is not in the dictionary"),
+        (status = 500, description = "Internal Server Error - Database connection or query error"),
+    ),
+    params(
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("lemma" = String, Path, description = "Lemma to find translations for", example = "dog"),
+        SafeParams,
+        TranslationParams,
+        SenseParams,
+        ExampleParams,
+        PronunciationParams,
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn word_translations(
+    State(state): State<AppState>,
+    Path((lang, lemma)): Path<(String, String)>,
+    user: Option<AuthUser>,
+    Query(safe): Query<SafeParams>,
+    Query(translation): Query<TranslationParams>,
+    Query(senses): Query<SenseParams>,
+    Query(examples): Query<ExampleParams>,
+    Query(params): Query<PronunciationParams>,
+) -> Result<Json<Vec<TranslatedWord>>, AppError> {
+    let safe = safe.safe(user.as_ref())?;
+    let translations = find_translations(
+        &state.dbpool,
+        &lang,
+        &lemma,
+        translation.to.as_deref(),
+        safe,
+    )
+    .await?;
+
+    // each translation is presented in its own language
+    let mut presented = Vec::with_capacity(translations.len());
+    for translated in translations {
+        let words = present_words(
+            &state,
+            &translated.language,
+            vec![translated.word],
+            &senses,
+            &examples,
+            &params,
+        )
+        .await?;
+        presented.extend(words.into_iter().map(|word| TranslatedWord {
+            language: translated.language.clone(),
+            sense: translated.sense.clone(),
+            word,
+        }));
+    }
+
+    Ok(Json(presented))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::language::{Language, UpsertLanguage};
+    use crate::models::translation::{UpsertTranslation, WordTranslation};
+    use crate::models::trash::TrashedWord;
+    use crate::models::word::Word;
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use std::collections::HashMap;
+    use tempfile::NamedTempFile;
+
+    async fn create_test_app() -> (
+        TestServer,
+        sqlx::SqlitePool,
+        HashMap<String, u32>,
+        NamedTempFile,
+    ) {
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        Language::create(
+            &dbpool,
+            UpsertLanguage {
+                code: "de".to_string(),
+                name: "German".to_string(),
+                script: "Latn".to_string(),
+                validation_profile: "latin".to_string(),
+                enabled: true,
+                type_names: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let mut ids = HashMap::new();
+        for (language, word, definitions) in [
+            (
+                "en",
+                "zzbank",
+                vec!["a place for money", "the side of a river"],
+            ),
+            ("en", "zzdog", vec!["a domestic animal"]),
+            ("de", "zzufer", vec!["das land am rand eines flusses"]),
+            ("de", "zzbank", vec!["ein geldinstitut"]),
+            ("de", "zzhund", vec!["ein haustier"]),
+        ] {
+            let (id,): (u32,) = sqlx::query_as(
+                "INSERT INTO words (language, word, word_type) VALUES ($1, $2, 'noun') RETURNING id",
+            )
+            .bind(language)
+            .bind(word)
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+            for (position, definition) in definitions.into_iter().enumerate() {
+                sqlx::query(
+                    "INSERT INTO word_senses (word_id, part_of_speech, definition, position) VALUES ($1, 'noun', $2, $3)",
+                )
+                .bind(id)
+                .bind(definition)
+                .bind(position as i64)
+                .execute(&dbpool)
+                .await
+                .unwrap();
+            }
+            sqlx::query("INSERT INTO word_pronunciations (word_id, pronunciation) VALUES ($1, $2)")
+                .bind(id)
+                .bind(format!("/{word}/"))
+                .execute(&dbpool)
+                .await
+                .unwrap();
+            ids.insert(format!("{language}:{word}"), id);
+        }
+
+        let app = axum::Router::new()
+            .route(
+                "/{lang}/words/{lemma}/translations",
+                axum::routing::get(word_translations),
+            )
+            .route(
+                "/{lang}/random",
+                axum::routing::get(crate::handlers::word::word_random),
+            )
+            .with_state(state);
+
+        (TestServer::new(app).unwrap(), dbpool, ids, temp_db)
+    }
+
+    fn translation(
+        translation_id: u32,
+        sense: Option<u32>,
+        translation_sense: Option<u32>,
+    ) -> UpsertTranslation {
+        UpsertTranslation {
+            translation_id,
+            sense,
+            translation_sense,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_word_translations() {
+        let (server, dbpool, ids, _db) = create_test_app().await;
+        let bank = ids["en:zzbank"];
+
+        WordTranslation::create(
+            &dbpool,
+            "en",
+            bank,
+            translation(ids["de:zzbank"], Some(0), None),
+        )
+        .await
+        .unwrap();
+        WordTranslation::create(
+            &dbpool,
+            "en",
+            bank,
+            translation(ids["de:zzufer"], Some(1), None),
+        )
+        .await
+        .unwrap();
+        WordTranslation::create(
+            &dbpool,
+            "de",
+            ids["de:zzhund"],
+            translation(ids["en:zzdog"], None, None),
+        )
+        .await
+        .unwrap();
+
+        let response = server.get("/en/words/zzbank/translations?to=de").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert_eq!(json[0]["word"], "zzbank");
+        assert_eq!(json[0]["language"], "de");
+        assert_eq!(json[0]["sense"], "a place for money");
+        assert_eq!(json[1]["word"], "zzufer");
+        assert_eq!(json[1]["sense"], "the side of a river");
+
+        // links are found from either word, with the sense on the other side
+        let response = server.get("/de/words/zzufer/translations").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "zzbank");
+        assert_eq!(json[0]["definition"], "the side of a river");
+        assert!(json[0].get("sense").is_none());
+        let response = server.get("/en/words/zzdog/translations?to=de").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "zzhund");
+
+        // no translations into the language of the lemma, unknown lemmas and languages
+        let response = server.get("/en/words/zzdog/translations?to=en").await;
+        let json: serde_json::Value = response.json();
+        assert!(json.as_array().unwrap().is_empty());
+        let response = server.get("/en/words/zzzz/translations").await;
+        response.assert_status(StatusCode::NOT_FOUND);
+        let response = server.get("/en/words/zzdog/translations?to=xx").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_word_random_bilingual() {
+        let (server, dbpool, ids, _db) = create_test_app().await;
+
+        // no links yet, and a language to translate to is required
+        let response = server.get("/de/random?mode=bilingual&to=en").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert!(json.as_array().unwrap().is_empty());
+        let response = server.get("/de/random?mode=bilingual").await;
+        response.assert_status(StatusCode::BAD_REQUEST);
+
+        WordTranslation::create(
+            &dbpool,
+            "de",
+            ids["de:zzufer"],
+            translation(ids["en:zzbank"], None, Some(1)),
+        )
+        .await
+        .unwrap();
+
+        // the pair comes in the order of the languages, with the linked senses
+        let response = server.get("/en/random?mode=bilingual&to=de").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "zzbank");
+        assert_eq!(json[0]["definition"], "the side of a river");
+        assert_eq!(json[1]["word"], "zzufer");
+    }
+
+    #[tokio::test]
+    async fn test_word_translations_admin() {
+        let (_server, dbpool, ids, _db) = create_test_app().await;
+        let dog = ids["en:zzdog"];
+
+        let created = WordTranslation::create(
+            &dbpool,
+            "en",
+            dog,
+            translation(ids["de:zzhund"], None, None),
+        )
+        .await
+        .unwrap();
+        assert_eq!(created.len(), 1);
+
+        // the reverse of a link is the same link
+        assert!(WordTranslation::create(
+            &dbpool,
+            "de",
+            ids["de:zzhund"],
+            translation(dog, None, None)
+        )
+        .await
+        .is_err());
+        // translations belong to another language, and to senses that exist
+        assert!(WordTranslation::create(
+            &dbpool,
+            "en",
+            dog,
+            translation(ids["en:zzbank"], None, None)
+        )
+        .await
+        .is_err());
+        assert!(WordTranslation::create(
+            &dbpool,
+            "en",
+            dog,
+            translation(ids["de:zzbank"], Some(5), None)
+        )
+        .await
+        .is_err());
+        assert!(
+            WordTranslation::create(&dbpool, "en", dog, translation(9999, None, None))
+                .await
+                .is_err()
+        );
+
+        // links can be deleted from either word and go away with their words
+        let listed = WordTranslation::list(&dbpool, "de", ids["de:zzhund"])
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&listed).unwrap();
+        assert_eq!(json[0]["translation"], "zzdog");
+        assert_eq!(json[0]["language"], "en");
+        let link_id = json[0]["id"].as_u64().unwrap() as u32;
+        assert!(
+            WordTranslation::delete(&dbpool, "en", ids["en:zzbank"], link_id)
+                .await
+                .is_err()
+        );
+        WordTranslation::delete(&dbpool, "de", ids["de:zzhund"], link_id)
+            .await
+            .unwrap();
+
+        WordTranslation::create(
+            &dbpool,
+            "en",
+            dog,
+            translation(ids["de:zzhund"], None, None),
+        )
+        .await
+        .unwrap();
+        Word::delete(dbpool.clone(), "de", ids["de:zzhund"], None, None)
+            .await
+            .unwrap();
+        TrashedWord::purge(&dbpool, "de", ids["de:zzhund"], None)
+            .await
+            .unwrap();
+        let (left,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM word_translations")
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+}
@@ -4,9 +4,16 @@
 //! All endpoints are publicly accessible and return JSON responses.
 
 use crate::error::AppError;
-use crate::models::word::GetWord;
+use crate::models::category::CategoryParams;
+use crate::models::flag::SafeParams;
+use crate::models::homophone::random_homophones;
+use crate::models::pronunciation::PronunciationParams;
+use crate::models::tag::TagParams;
+use crate::models::translation::random_translation;
+use crate::models::user::AuthUser;
+use crate::models::word::{ExampleParams, GetWord, RandomMode, RandomParams, SenseParams};
 use crate::state::AppState;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 
 /// Retrieves a random word from the database.
@@ -16,13 +23,30 @@ use axum::Json;
 ///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
+/// * `pronunciation_format` - Optional comma-separated extra notations
+///   ('respelling', 'arpabet', 'xsampa') derived from the IPA pronunciation
+/// * `variant` - Optional preferred pronunciation variant (e.g. 'us', 'uk'),
+///   words without it fall back to their primary pronunciation
+/// * `mode` - Optional random mode: 'word' (default), 'homophones' for a
+///   group of words sharing a pronunciation or 'bilingual' for a word
+///   followed by its translation
+/// * `to` - Code of the language to translate to, required in 'bilingual' mode
+/// * `sense` - Optional sense selection: 'all' (default) or 'random' for a
+///   single random sense
+/// * `example` - Optional flag adding a random example sentence and the usage
+///   note of the word
+/// * `tags` - Optional comma-separated tags, the word has at least one of them
+/// * `exclude_tags` - Optional comma-separated tags, the word has none of them
+/// * `safe` - Optional safe mode leaving out flagged words, on by default.
+///   Only administrators can turn it off with 'false'
 ///
 /// # Returns
 ///
 /// * `200 OK` - Random word successfully retrieved and returned
-/// * `400 Bad Request` - Invalid language code provided
+/// * `400 Bad Request` - Invalid language code, random mode or tag name provided, or
+///   no language to translate to in bilingual mode
+/// * `403 Forbidden` - Safe mode turned off without administrator privileges
 /// * `404 Not Found` - No words available in database
 /// * `500 Internal Server Error` - Database connection or query error
 #[utoipa::path(
@@ -30,22 +54,72 @@ use axum::Json;
     path = "/{lang}/random",
     operation_id = "public_word_random",
     tag = "public_endpoints",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
 
     responses(
         (status = 200, description = "Random word successfully retrieved and returned", body = [GetWord]),
-        (status = 400, description = "Bad Request - Invalid language code provided"),
+        (status = 400, description = "Bad Request - Invalid language code, random mode, tag name, sense selection or pronunciation format provided"),
+        (status = 403, description = "Forbidden - Safe mode can only be turned off by administrators"),
         (status = 404, description = "Not Found - No words available in the specified language database"),
         (status = 500, description = "Internal Server Error - Database connection or query error"),
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word retrieval. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        SafeParams,
+        RandomParams,
+        TagParams,
+        SenseParams,
+        ExampleParams,
+        PronunciationParams,
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn word_random(
     State(state): State<AppState>,
     Path(lang): Path<String>,
+    user: Option<AuthUser>,
+    Query(safe): Query<SafeParams>,
+    Query(random): Query<RandomParams>,
+    Query(tags): Query<TagParams>,
+    Query(senses): Query<SenseParams>,
+    Query(examples): Query<ExampleParams>,
+    Query(params): Query<PronunciationParams>,
 ) -> Result<Json<Vec<GetWord>>, AppError> {
-    GetWord::random_word(state.dbpool, &lang)
+    let safe = safe.safe(user.as_ref())?;
+    let tags = tags.filter()?;
+    let words = match random.mode()? {
+        RandomMode::Word => {
+            GetWord::random_word(
+                state.dbpool.clone(),
+                &lang,
+                params.variant().as_deref(),
+                &tags,
+                safe,
+            )
+            .await?
+        }
+        RandomMode::Homophones => {
+            random_homophones(&state.dbpool, &lang, None, &tags, safe).await?
+        }
+        RandomMode::Bilingual => {
+            // the translation is presented in its own language
+            let to = random.to()?;
+            let (word, translation): (Vec<_>, Vec<_>) =
+                random_translation(&state.dbpool, &lang, to, None, &tags, safe)
+                    .await?
+                    .into_iter()
+                    .unzip();
+            let mut words = present_words(&state, &lang, word, &senses, &examples, &params).await?;
+            words
+                .extend(present_words(&state, to, translation, &senses, &examples, &params).await?);
+            return Ok(Json(words));
+        }
+    };
+
+    present_words(&state, &lang, words, &senses, &examples, &params)
         .await
         .map(Json::from)
 }
@@ -57,8 +131,7 @@ pub async fn word_random(
 ///
 /// # Parameters
 ///
-/// * `lang` - Language code (currently supports 'en' for English; future versions
-///   will support additional languages with separate database tables)
+/// * `lang` - Code of a registered language (e.g. 'en' for English)
 /// * `type` - Grammatical type filter with accepted values:
 ///   - 'noun' (people, places, things)
 ///   - 'verb' (actions, states)
@@ -70,10 +143,32 @@ pub async fn word_random(
 ///   - 'interjection' (exclamatory words)
 ///   - 'article' (definite and indefinite articles)
 ///
+///   or the name the language gives the type (e.g. 'substantiv' in German)
+/// * `category` - Optional sub-category of the type in 'word' mode: 'proper',
+///   'countable' or 'uncountable' for nouns, 'transitive' or 'intransitive' for verbs
+/// * `pronunciation_format` - Optional comma-separated extra notations
+///   ('respelling', 'arpabet', 'xsampa') derived from the IPA pronunciation
+/// * `variant` - Optional preferred pronunciation variant (e.g. 'us', 'uk'),
+///   words without it fall back to their primary pronunciation
+/// * `mode` - Optional random mode: 'word' (default), 'homophones' for a
+///   group of words sharing a pronunciation or 'bilingual' for a word
+///   followed by its translation
+/// * `to` - Code of the language to translate to, required in 'bilingual' mode
+/// * `sense` - Optional sense selection: 'all' (default) or 'random' for a
+///   single random sense
+/// * `example` - Optional flag adding a random example sentence and the usage
+///   note of the word
+/// * `tags` - Optional comma-separated tags, the word has at least one of them
+/// * `exclude_tags` - Optional comma-separated tags, the word has none of them
+/// * `safe` - Optional safe mode leaving out flagged words, on by default.
+///   Only administrators can turn it off with 'false'
+///
 /// # Returns
 ///
 /// * `200 OK` - Random word of specified type successfully retrieved
-/// * `400 Bad Request` - Invalid language code or unsupported word type
+/// * `400 Bad Request` - Invalid language code, unsupported word type, category, random mode
+///   or tag name, or no language to translate to in bilingual mode
+/// * `403 Forbidden` - Safe mode turned off without administrator privileges
 /// * `404 Not Found` - No words of specified type available in database
 /// * `500 Internal Server Error` - Database connection or query error
 #[utoipa::path(
@@ -81,43 +176,131 @@ pub async fn word_random(
     path = "/{lang}/{type}",
     operation_id = "public_word_random_type",
     tag = "public_endpoints",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
 
     responses(
         (status = 200, description = "Random word of specified type successfully retrieved and returned", body = [GetWord]),
-        (status = 400, description = "Bad Request - Invalid language code or unsupported word type provided"),
+        (status = 400, description = "Bad Request - Invalid language code, unsupported word type, category, random mode, tag name, sense selection or pronunciation format provided"),
+        (status = 403, description = "Forbidden - Safe mode can only be turned off by administrators"),
         (status = 404, description = "Not Found - No words of specified type available in the language database"),
         (status = 500, description = "Internal Server Error - Database connection or query error"),
     ),
     params(
-        ("lang" = String, Path, description = "Language code for word retrieval. Currently supports: 'en' (English). Future versions will support additional languages with separate database tables.", example = "en"),
-        ("type" = String, Path, description = "Grammatical type filter for word selection. Accepted values: 'noun' (people, places, things), 'verb' (actions, states), 'adjective' (descriptive words), 'adverb' (modifiers), 'pronoun' (words that replace nouns), 'preposition' (words showing relationships), 'conjunction' (connecting words), 'interjection' (exclamatory words), 'article' (definite and indefinite articles).", example = "noun"),
+        ("lang" = String, Path, description = "Language code for word retrieval, as registered by administrators (e.g. 'en' for English).", example = "en"),
+        ("type" = String, Path, description = "Grammatical type filter for word selection. Accepted values: 'noun' (people, places, things), 'verb' (actions, states), 'adjective' (descriptive words), 'adverb' (modifiers), 'pronoun' (words that replace nouns), 'preposition' (words showing relationships), 'conjunction' (connecting words), 'interjection' (exclamatory words), 'article' (definite and indefinite articles). Types can also be named in the language of the words, as registered by administrators (e.g. 'substantiv' in German).", example = "noun"),
+        CategoryParams,
+        SafeParams,
+        RandomParams,
+        TagParams,
+        SenseParams,
+        ExampleParams,
+        PronunciationParams,
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn word_type(
     State(state): State<AppState>,
     Path((lang, word_type)): Path<(String, String)>,
+    user: Option<AuthUser>,
+    Query(category): Query<CategoryParams>,
+    Query(safe): Query<SafeParams>,
+    Query(random): Query<RandomParams>,
+    Query(tags): Query<TagParams>,
+    Query(senses): Query<SenseParams>,
+    Query(examples): Query<ExampleParams>,
+    Query(params): Query<PronunciationParams>,
 ) -> Result<Json<Vec<GetWord>>, AppError> {
-    GetWord::random_type(state.dbpool, &lang, &word_type)
+    let safe = safe.safe(user.as_ref())?;
+    let tags = tags.filter()?;
+    let words = match random.mode()? {
+        RandomMode::Word => {
+            GetWord::random_type(
+                state.dbpool.clone(),
+                &lang,
+                &word_type,
+                &category,
+                params.variant().as_deref(),
+                &tags,
+                safe,
+            )
+            .await?
+        }
+        RandomMode::Homophones => {
+            random_homophones(&state.dbpool, &lang, Some(&word_type), &tags, safe).await?
+        }
+        RandomMode::Bilingual => {
+            // the translation is presented in its own language
+            let to = random.to()?;
+            let (word, translation): (Vec<_>, Vec<_>) =
+                random_translation(&state.dbpool, &lang, to, Some(&word_type), &tags, safe)
+                    .await?
+                    .into_iter()
+                    .unzip();
+            let mut words = present_words(&state, &lang, word, &senses, &examples, &params).await?;
+            words
+                .extend(present_words(&state, to, translation, &senses, &examples, &params).await?);
+            return Ok(Json(words));
+        }
+    };
+
+    present_words(&state, &lang, words, &senses, &examples, &params)
         .await
         .map(Json::from)
 }
 
+/// Adds the requested senses, example and pronunciation notations to each word
+///
+/// Shared by every public endpoint returning `GetWord` lists.
+pub(crate) async fn present_words(
+    state: &AppState,
+    lang: &str,
+    words: Vec<GetWord>,
+    senses: &SenseParams,
+    examples: &ExampleParams,
+    params: &PronunciationParams,
+) -> Result<Vec<GetWord>, AppError> {
+    let selection = senses.selection()?;
+    let formats = params.formats()?;
+
+    let mut presented = Vec::with_capacity(words.len());
+    for word in words {
+        let mut word = word.with_senses(&state.dbpool, lang, selection).await?;
+        if examples.example {
+            word = word.with_example(&state.dbpool, lang).await?;
+        }
+        presented.push(
+            word.with_pronunciation_formats(state.pronunciations.for_language(lang), &formats),
+        );
+    }
+    Ok(presented)
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::error::{AppError, PathError};
-    use crate::models::word::{GrammaticalType, LanguageCode};
+    use crate::models::language::UpsertLanguage;
+    use crate::models::word::GrammaticalType;
     use std::str::FromStr;
 
     #[test]
     fn test_language_validation_logic() {
-        // Test language validation used by word handlers
-        let valid_lang = LanguageCode::from_str("en");
-        assert!(valid_lang.is_ok());
-        assert_eq!(valid_lang.unwrap().table_name(), "words");
-
-        let invalid_lang = LanguageCode::from_str("xyz");
-        assert!(invalid_lang.is_err());
+        // Test language registration validation behind the `{lang}` segment
+        let language = |code: &str| UpsertLanguage {
+            code: code.to_string(),
+            name: "English".to_string(),
+            script: "Latn".to_string(),
+            validation_profile: "latin".to_string(),
+            enabled: true,
+            type_names: Default::default(),
+        };
+        assert_eq!(language("en").code().unwrap(), "en");
+        assert!(language("ita").code().is_ok());
+        assert!(language("english").code().is_err());
+        assert!(language("e1").code().is_err());
 
         // Test PathError creation for invalid language
         let path_error = PathError::InvalidPath("xyz".to_string());
@@ -150,11 +333,11 @@ mod tests {
             "verb",
             "adjective",
             "adverb",
-            // "pronoun",
-            // "preposition",
-            // "conjunction",
-            // "interjection",
-            // "article",
+            "pronoun",
+            "preposition",
+            "conjunction",
+            "interjection",
+            "article",
         ];
 
         for word_type in supported_types {
@@ -178,4 +361,661 @@ mod tests {
             );
         }
     }
+
+    /// Serves `/{lang}/random` from a dictionary holding only `word`
+    async fn create_single_word_app(
+        word: crate::models::word::UpsertWord,
+    ) -> (
+        axum_test::TestServer,
+        sqlx::SqlitePool,
+        u32,
+        tempfile::NamedTempFile,
+    ) {
+        use super::word_random;
+        use crate::models::word::Word;
+
+        let (state, temp_db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+
+        // migrated seed words keep their pronunciation and definition as the primary ones
+        let seeded: Vec<(String,)> = sqlx::query_as(
+            "SELECT w.word FROM words w LEFT JOIN word_pronunciations p ON p.word_id = w.id LEFT JOIN word_senses s ON s.word_id = w.id WHERE p.id IS NULL OR p.variant != 'general' OR s.id IS NULL OR s.part_of_speech != w.word_type",
+        )
+        .fetch_all(&dbpool)
+        .await
+        .unwrap();
+        assert!(seeded.is_empty());
+
+        sqlx::query("DELETE FROM words")
+            .execute(&dbpool)
+            .await
+            .unwrap();
+        let created = Word::create(dbpool.clone(), "en", word, None)
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&created[0]).unwrap();
+        let id = json["id"].as_u64().unwrap() as u32;
+
+        let app = axum::Router::new()
+            .route("/{lang}/random", axum::routing::get(word_random))
+            .with_state(state);
+
+        (
+            axum_test::TestServer::new(app).unwrap(),
+            dbpool,
+            id,
+            temp_db,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_word_random_pronunciation_variant() {
+        use crate::models::trash::TrashedWord;
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+
+        let (server, dbpool, id, _db) = create_single_word_app(UpsertWord {
+            word: "tomato".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a red fruit".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![
+                PronunciationVariant {
+                    variant: "us".to_string(),
+                    pronunciation: "/təˈmeɪtoʊ/".to_string(),
+                },
+                PronunciationVariant {
+                    variant: "uk".to_string(),
+                    pronunciation: "/təˈmɑːtəʊ/".to_string(),
+                },
+            ],
+            word_type: "noun".to_string(),
+        })
+        .await;
+
+        for (query, variant, pronunciation) in [
+            ("", "us", "/təˈmeɪtoʊ/"),
+            ("?variant=UK", "uk", "/təˈmɑːtəʊ/"),
+            ("?variant=au", "us", "/təˈmeɪtoʊ/"),
+        ] {
+            let response = server.get(&format!("/en/random{query}")).await;
+            response.assert_status_ok();
+            let json: serde_json::Value = response.json();
+            assert_eq!(json[0]["variant"], variant);
+            assert_eq!(json[0]["pronunciation"], pronunciation);
+        }
+
+        // pronunciations go away with their word, once purged from the trash
+        Word::delete(dbpool.clone(), "en", id, None, None)
+            .await
+            .unwrap();
+        TrashedWord::purge(&dbpool, "en", id, None).await.unwrap();
+        let (left,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM word_pronunciations")
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+
+    #[tokio::test]
+    async fn test_word_random_senses() {
+        use crate::models::trash::TrashedWord;
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+
+        let (server, dbpool, id, _db) = create_single_word_app(UpsertWord {
+            word: "bank".to_string(),
+            senses: vec![
+                WordSense {
+                    part_of_speech: "noun".to_string(),
+                    definition: "the land alongside a river".to_string(),
+                    example: Some("we sat on the bank".to_string()),
+                },
+                WordSense {
+                    part_of_speech: "verb".to_string(),
+                    definition: "to deposit money".to_string(),
+                    example: None,
+                },
+            ],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/bæŋk/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        })
+        .await;
+
+        let response = server.get("/en/random").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["definition"], "the land alongside a river");
+        assert_eq!(json[0]["senses"].as_array().unwrap().len(), 2);
+        assert_eq!(json[0]["senses"][0]["example"], "we sat on the bank");
+        assert_eq!(json[0]["senses"][1]["partOfSpeech"], "verb");
+
+        let response = server.get("/en/random?sense=random").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["senses"].as_array().unwrap().len(), 1);
+        assert_eq!(json[0]["definition"], json[0]["senses"][0]["definition"]);
+
+        let response = server.get("/en/random?sense=first").await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+        // senses go away with their word, once purged from the trash
+        Word::delete(dbpool.clone(), "en", id, None, None)
+            .await
+            .unwrap();
+        TrashedWord::purge(&dbpool, "en", id, None).await.unwrap();
+        let (left,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM word_senses")
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+
+    #[tokio::test]
+    async fn test_word_random_example() {
+        use crate::models::trash::TrashedWord;
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordExample, WordSense};
+
+        let (server, dbpool, id, _db) = create_single_word_app(UpsertWord {
+            word: "run".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "verb".to_string(),
+                definition: "to move swiftly on foot".to_string(),
+                example: None,
+            }],
+            examples: vec![WordExample {
+                sentence: "\"Run!\" she shouted.".to_string(),
+                target_start: 1,
+                target_end: 4,
+            }],
+            usage_note: Some("Also used for machines that operate.".to_string()),
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/rʌn/".to_string(),
+            }],
+            word_type: "verb".to_string(),
+        })
+        .await;
+
+        let response = server.get("/en/random").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert!(json[0].get("example").is_none());
+        assert!(json[0].get("usageNote").is_none());
+
+        let response = server.get("/en/random?example=true").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["example"]["sentence"], "\"Run!\" she shouted.");
+        assert_eq!(json[0]["example"]["targetStart"], 1);
+        assert_eq!(json[0]["example"]["targetEnd"], 4);
+        assert_eq!(json[0]["usageNote"], "Also used for machines that operate.");
+
+        let response = server.get("/en/random?example=maybe").await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+        // examples go away with their word, once purged from the trash
+        Word::delete(dbpool.clone(), "en", id, None, None)
+            .await
+            .unwrap();
+        TrashedWord::purge(&dbpool, "en", id, None).await.unwrap();
+        let (left,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM word_examples")
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+
+    #[tokio::test]
+    async fn test_word_random_tags() {
+        use crate::models::tag::{Tag, UpsertTag};
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+
+        let (server, dbpool, id, _db) = create_single_word_app(UpsertWord {
+            word: "cat".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a small furry animal".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/kæt/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        })
+        .await;
+
+        for name in ["Animals", "food", "pets"] {
+            Tag::create(
+                &dbpool,
+                UpsertTag {
+                    name: name.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        }
+        let duplicate = Tag::create(
+            &dbpool,
+            UpsertTag {
+                name: "ANIMALS".to_string(),
+            },
+        )
+        .await;
+        assert!(duplicate.is_err());
+        let invalid = UpsertTag {
+            name: "-animals".to_string(),
+        };
+        assert!(invalid.name().is_err());
+
+        Tag::attach(&dbpool, "en", id, "animals").await.unwrap();
+        Tag::attach(&dbpool, "en", id, "Pets").await.unwrap();
+        // tagging twice has no effect, unknown words and tags are rejected
+        Tag::attach(&dbpool, "en", id, "pets").await.unwrap();
+        assert!(Tag::attach(&dbpool, "en", 9999, "pets").await.is_err());
+        assert!(Tag::attach(&dbpool, "en", id, "school").await.is_err());
+
+        let read = Word::read(dbpool.clone(), "en", id).await.unwrap();
+        let json = serde_json::to_value(&read[0]).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["animals", "pets"]));
+
+        for (query, found) in [
+            ("?tags=animals", true),
+            ("?tags=food,Pets", true),
+            ("?tags=food", false),
+            ("?exclude_tags=food", true),
+            ("?tags=animals&exclude_tags=pets", false),
+        ] {
+            let response = server.get(&format!("/en/random{query}")).await;
+            response.assert_status_ok();
+            let json: serde_json::Value = response.json();
+            assert_eq!(!json.as_array().unwrap().is_empty(), found, "{query}");
+        }
+
+        let response = server.get("/en/random?tags=an%20imals").await;
+        response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+        // deleting a tag untags its words
+        Tag::detach(&dbpool, "en", id, "pets").await.unwrap();
+        let listed = Tag::list(&dbpool).await.unwrap();
+        let json = serde_json::to_value(&listed).unwrap();
+        assert_eq!(json[0]["name"], "animals");
+        assert_eq!(json[0]["wordCount"], 1);
+        let animals_id = json[0]["id"].as_u64().unwrap() as u32;
+        Tag::delete(&dbpool, animals_id).await.unwrap();
+        assert!(Tag::delete(&dbpool, animals_id).await.is_err());
+        let (left,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM word_tags")
+            .fetch_one(&dbpool)
+            .await
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+
+    #[tokio::test]
+    async fn test_word_random_safe_mode() {
+        use crate::auth::JwtManager;
+        use crate::config::ApiConfig;
+        use crate::models::user::User;
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+        use axum::http::{HeaderName, StatusCode};
+
+        let upsert = |flags: Vec<&str>| UpsertWord {
+            word: "thou".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "you, addressing one person".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: flags.into_iter().map(String::from).collect(),
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/ðaʊ/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+        assert!(upsert(vec!["rude"]).flags().is_err());
+        assert!(upsert(vec!["slang", "Slang"]).flags().is_err());
+
+        let (server, dbpool, id, _db) = create_single_word_app(upsert(vec!["Archaic"])).await;
+        let read = Word::read(dbpool.clone(), "en", id).await.unwrap();
+        let json = serde_json::to_value(&read[0]).unwrap();
+        assert_eq!(json["flags"], serde_json::json!(["archaic"]));
+
+        // flagged words are left out unless an administrator turns safe mode off
+        let response = server.get("/en/random").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert!(json.as_array().unwrap().is_empty());
+
+        let token = |is_admin| {
+            let user = User {
+                id: 1,
+                username: "teacher".to_string(),
+                password_hash: String::new(),
+                is_admin,
+                is_editor: false,
+                created_at: None,
+                updated_at: None,
+            };
+            let secret = ApiConfig::default().jwt_settings.secret;
+            let token = JwtManager::generate_token(&user, &secret, 5).unwrap();
+            format!("Bearer {token}")
+        };
+        let authorization = HeaderName::from_static("authorization");
+
+        let response = server.get("/en/random?safe=false").await;
+        response.assert_status(StatusCode::FORBIDDEN);
+        let response = server
+            .get("/en/random?safe=false")
+            .add_header(authorization.clone(), token(false))
+            .await;
+        response.assert_status(StatusCode::FORBIDDEN);
+        let response = server
+            .get("/en/random?safe=false")
+            .add_header(authorization.clone(), token(true))
+            .await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "thou");
+
+        // clearing the flags brings the word back
+        Word::update(dbpool.clone(), "en", id, upsert(vec![]), None, None)
+            .await
+            .unwrap();
+        let response = server.get("/en/random").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "thou");
+    }
+
+    #[tokio::test]
+    async fn test_word_random_language_registry() {
+        use crate::models::language::{Language, UpsertLanguage};
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+        use axum::http::StatusCode;
+
+        let upsert = |word: &str| UpsertWord {
+            word: word.to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a domestic animal".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/hʊnt/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+        let german = |enabled| UpsertLanguage {
+            code: "de".to_string(),
+            name: "German".to_string(),
+            script: "Latn".to_string(),
+            validation_profile: "latin".to_string(),
+            enabled,
+            type_names: Default::default(),
+        };
+
+        let (server, dbpool, _id, _db) = create_single_word_app(upsert("dog")).await;
+
+        // unregistered languages aren't served
+        server
+            .get("/de/random")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        // a registered language is served right away, with its own words
+        Language::create(&dbpool, german(true)).await.unwrap();
+        assert!(Language::create(&dbpool, german(true)).await.is_err());
+        Word::create(dbpool.clone(), "de", upsert("hund"), None)
+            .await
+            .unwrap();
+
+        // lemmas are unique per language only
+        assert!(Word::create(dbpool.clone(), "de", upsert("hund"), None)
+            .await
+            .is_err());
+        let created = Word::create(dbpool.clone(), "en", upsert("hund"), None)
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&created[0]).unwrap();
+        let english_id = json["id"].as_u64().unwrap() as u32;
+        Word::delete(dbpool.clone(), "en", english_id, None, None)
+            .await
+            .unwrap();
+
+        let response = server.get("/de/random").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "hund");
+
+        let response = server.get("/en/random").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "dog");
+
+        // disabled languages keep their words but aren't served
+        Language::update(&dbpool, "de", german(false))
+            .await
+            .unwrap();
+        server
+            .get("/de/random")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+        let page = Word::page(dbpool.clone(), "de", &Default::default())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_word_validation_profiles() {
+        use crate::models::language::{Language, UpsertLanguage};
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+        use axum::{http::StatusCode, response::IntoResponse};
+
+        let upsert = |word: &str, definition: &str, pronunciation: &str| UpsertWord {
+            word: word.to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: definition.to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: pronunciation.to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+
+        let (server, dbpool, _id, _db) =
+            create_single_word_app(upsert("dog", "a domestic animal", "/dɔɡ/")).await;
+        Language::create(
+            &dbpool,
+            UpsertLanguage {
+                code: "el".to_string(),
+                name: "Greek".to_string(),
+                script: "Grek".to_string(),
+                validation_profile: "greek".to_string(),
+                enabled: true,
+                type_names: Default::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+        // words are checked against the profile of the language in the path
+        Word::create(
+            dbpool.clone(),
+            "el",
+            upsert("σκύλος", "κατοικίδιο ζώο", "/ˈscilos/"),
+            None,
+        )
+        .await
+        .unwrap();
+        let error = Word::create(dbpool.clone(), "el", upsert("dog", "ζώο", "/dɔɡ/"), None)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+        let error = Word::create(
+            dbpool.clone(),
+            "en",
+            upsert("σκύλος", "ζώο", "/ˈscilos/"),
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let response = server.get("/el/random").await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "σκύλος");
+    }
+
+    #[tokio::test]
+    async fn test_word_type_localized_names_and_categories() {
+        use super::word_type;
+        use crate::models::language::{Language, UpsertLanguage};
+        use crate::models::word::{PronunciationVariant, UpsertWord, Word, WordSense};
+        use axum::http::StatusCode;
+
+        let upsert = |word: &str, categories: &[&str]| UpsertWord {
+            word: word.to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a place name".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/bɛʁˈliːn/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+        let german = |type_names: &[(&str, &str)]| UpsertLanguage {
+            code: "de".to_string(),
+            name: "German".to_string(),
+            script: "Latn".to_string(),
+            validation_profile: "latin".to_string(),
+            enabled: true,
+            type_names: type_names
+                .iter()
+                .map(|(t, n)| (t.to_string(), n.to_string()))
+                .collect(),
+        };
+
+        let (state, _db) = crate::state::test_state().await;
+        let dbpool = state.dbpool.clone();
+        let app = axum::Router::new()
+            .route("/{lang}/{type}", axum::routing::get(word_type))
+            .with_state(state);
+        let server = axum_test::TestServer::new(app).unwrap();
+
+        // a name can't stand for two types or shadow another type
+        assert!(Language::create(&dbpool, german(&[("noun", "verb")]))
+            .await
+            .is_err());
+        assert!(
+            Language::create(&dbpool, german(&[("noun", "wort"), ("verb", "Wort")]))
+                .await
+                .is_err()
+        );
+        assert!(
+            Language::create(&dbpool, german(&[("particle", "partikel")]))
+                .await
+                .is_err()
+        );
+
+        let created = Language::create(&dbpool, german(&[("noun", "Substantiv")]))
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&created[0]).unwrap();
+        assert_eq!(json["typeNames"]["noun"], "substantiv");
+        Word::create(dbpool.clone(), "de", upsert("berlin", &["proper"]), None)
+            .await
+            .unwrap();
+
+        // the English and the localized names of a type both work, in any case
+        for path in ["/de/noun", "/de/substantiv", "/de/Substantiv"] {
+            let response = server.get(path).await;
+            response.assert_status_ok();
+            let json: serde_json::Value = response.json();
+            assert_eq!(json[0]["word"], "berlin");
+        }
+
+        // localized names belong to their language
+        server
+            .get("/en/substantiv")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        // the category narrows the draw down and must apply to the type
+        let response = server.get("/en/noun?category=proper").await;
+        let json: serde_json::Value = response.json();
+        assert!(json.as_array().unwrap().is_empty());
+        let response = server.get("/de/substantiv?category=proper").await;
+        let json: serde_json::Value = response.json();
+        assert_eq!(json[0]["word"], "berlin");
+        server
+            .get("/de/noun?category=transitive")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        // categories must apply to the word type or one of its senses
+        let mut verb = upsert("laufen", &["proper"]);
+        verb.word_type = "verb".to_string();
+        verb.senses[0].part_of_speech = "verb".to_string();
+        assert!(Word::create(dbpool.clone(), "de", verb, None)
+            .await
+            .is_err());
+
+        // type names are replaced as a whole on update
+        Language::update(&dbpool, "de", german(&[])).await.unwrap();
+        server
+            .get("/de/substantiv")
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
 }
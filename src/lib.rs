@@ -115,10 +115,24 @@ pub async fn run_app(cli: cli::Cli) -> Result<(), AppError> {
         .await
         .context("couldn't initialize the database connection pool")?;
 
+    // Load the pronunciation conversion tables
+    let pronunciations = models::pronunciation::PronunciationRegistry::load(
+        &apiconfig.server_settings.pronunciation_dir,
+    )
+    .context("couldn't load the pronunciation tables")?;
+
+    // Purge the words in the trash for longer than the retention
+    tokio::spawn(models::trash::purge_periodically(
+        dbpool.clone(),
+        apiconfig.api_limits.trash_retention_days,
+    ));
+
     // Setup the shared mutable state
     let shared_state = state::AppState {
         apiconfig: Arc::new(Mutex::new(apiconfig.clone())),
         dbpool: dbpool.clone(),
+        ladders: Arc::new(models::ladder::LadderIndex::new()),
+        pronunciations: Arc::new(pronunciations),
     };
 
     // Setup top-level router (includes SwaggerUI)
@@ -0,0 +1,191 @@
+//! Grammatical sub-categories of words
+//!
+//! Besides its grammatical type, a word can belong to sub-categories of
+//! that type: a noun can be a proper noun, countable or uncountable, and a
+//! verb transitive or intransitive. A word can have several of them (e.g.
+//! "run" is both transitive and intransitive), and the random word of a
+//! type can be narrowed down to one of them.
+
+use serde::Deserialize;
+use sqlx::{query, query_scalar, SqliteConnection};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::IntoParams;
+use validator::ValidationError;
+
+use crate::error::{AppError, QueryError};
+use crate::models::word::GrammaticalType;
+
+/// Grammatical sub-categories supported by the API
+///
+/// # Supported Categories
+/// - `proper` - Nouns naming a specific person, place or thing (e.g. "london")
+/// - `countable` - Nouns with a plural (e.g. "cat")
+/// - `uncountable` - Nouns without a plural (e.g. "freedom", "rice")
+/// - `transitive` - Verbs taking a direct object (e.g. "hold")
+/// - `intransitive` - Verbs without a direct object (e.g. "sleep")
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum TypeCategory {
+    #[strum(serialize = "proper")]
+    Proper,
+    #[strum(serialize = "countable")]
+    Countable,
+    #[strum(serialize = "uncountable")]
+    Uncountable,
+    #[strum(serialize = "transitive")]
+    Transitive,
+    #[strum(serialize = "intransitive")]
+    Intransitive,
+}
+
+impl TypeCategory {
+    pub fn category_name(&self) -> &str {
+        match self {
+            TypeCategory::Proper => "proper",
+            TypeCategory::Countable => "countable",
+            TypeCategory::Uncountable => "uncountable",
+            TypeCategory::Transitive => "transitive",
+            TypeCategory::Intransitive => "intransitive",
+        }
+    }
+
+    /// Whether words of a grammatical type can belong to this category
+    pub fn applies_to(&self, grammatical_type: &GrammaticalType) -> bool {
+        matches!(
+            (self, grammatical_type),
+            (
+                TypeCategory::Proper | TypeCategory::Countable | TypeCategory::Uncountable,
+                GrammaticalType::Noun
+            ) | (
+                TypeCategory::Transitive | TypeCategory::Intransitive,
+                GrammaticalType::Verb
+            )
+        )
+    }
+
+    /// Loads the categories of a single word, in alphabetical order
+    pub(crate) async fn for_word(
+        conn: &mut SqliteConnection,
+        word_id: u32,
+    ) -> Result<Vec<String>, AppError> {
+        query_scalar!(
+            "SELECT category FROM word_categories WHERE word_id = $1 ORDER BY category",
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the categories of a word
+    pub(crate) async fn replace_all(
+        tx: &mut SqliteConnection,
+        word_id: u32,
+        categories: &[String],
+    ) -> Result<(), AppError> {
+        query!("DELETE FROM word_categories WHERE word_id = $1", word_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for category in categories {
+            query!(
+                "INSERT INTO word_categories (word_id, category) VALUES ($1, $2)",
+                word_id,
+                category
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates a list of categories for allowed categories and duplicates
+pub(crate) fn validate_categories(list: &[String]) -> Result<(), ValidationError> {
+    if list
+        .iter()
+        .any(|c| TypeCategory::from_str(&c.to_lowercase()).is_err())
+    {
+        return Err(ValidationError::new("invalid_category"));
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    if !list.iter().all(|c| seen.insert(c.to_lowercase())) {
+        return Err(ValidationError::new("duplicate_category"));
+    }
+    Ok(())
+}
+
+/// Query parameters narrowing a random word down to a sub-category of its type
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryParams {
+    /// Sub-category of the grammatical type: 'proper', 'countable' or
+    /// 'uncountable' for nouns, 'transitive' or 'intransitive' for verbs
+    pub category: Option<String>,
+}
+
+impl CategoryParams {
+    /// Returns the requested category after checking it applies to the type
+    ///
+    /// Returns `QueryError::InvalidParameter` for unknown categories and
+    /// categories of another grammatical type.
+    pub fn category(
+        &self,
+        grammatical_type: &GrammaticalType,
+    ) -> Result<Option<TypeCategory>, AppError> {
+        let Some(name) = &self.category else {
+            return Ok(None);
+        };
+
+        match TypeCategory::from_str(&name.to_lowercase()) {
+            Ok(category) if category.applies_to(grammatical_type) => Ok(Some(category)),
+            _ => Err(QueryError::InvalidParameter("category".to_string(), name.clone()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_applies_to() {
+        assert!(TypeCategory::Proper.applies_to(&GrammaticalType::Noun));
+        assert!(TypeCategory::Uncountable.applies_to(&GrammaticalType::Noun));
+        assert!(TypeCategory::Transitive.applies_to(&GrammaticalType::Verb));
+        assert!(!TypeCategory::Countable.applies_to(&GrammaticalType::Verb));
+        assert!(!TypeCategory::Intransitive.applies_to(&GrammaticalType::Noun));
+        assert!(!TypeCategory::Proper.applies_to(&GrammaticalType::Pronoun));
+    }
+
+    #[test]
+    fn test_validate_categories() {
+        assert!(validate_categories(&[]).is_ok());
+        assert!(validate_categories(&["proper".to_string(), "Countable".to_string()]).is_ok());
+        assert!(validate_categories(&["plural".to_string()]).is_err());
+        assert!(validate_categories(&["proper".to_string(), "PROPER".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_category_params() {
+        let params = |category: &str| CategoryParams {
+            category: Some(category.to_string()),
+        };
+
+        assert_eq!(
+            params("Transitive")
+                .category(&GrammaticalType::Verb)
+                .unwrap(),
+            Some(TypeCategory::Transitive)
+        );
+        assert_eq!(
+            CategoryParams::default()
+                .category(&GrammaticalType::Verb)
+                .unwrap(),
+            None
+        );
+        assert!(params("proper").category(&GrammaticalType::Verb).is_err());
+        assert!(params("plural").category(&GrammaticalType::Noun).is_err());
+    }
+}
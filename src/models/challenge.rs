@@ -0,0 +1,522 @@
+//! Daily spelling challenge with a per-day leaderboard
+//!
+//! Every language gets a fixed set of words per calendar day (UTC). The set is
+//! picked deterministically from the date and language the first time it's
+//! requested, then stored so it stays stable even if the dictionary changes.
+//! Words with content flags are never picked.
+//! Authenticated users submit their answers once per day; results are graded
+//! server-side and ranked on the leaderboard. The words to spell are only
+//! revealed once their day is over, so players only get the definitions and
+//! pronunciations while the challenge is open.
+
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar, SqlitePool};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{AppError, ChallengeError};
+use crate::models::language::Language;
+use crate::models::user::AuthUser;
+
+/// Number of words in every daily challenge
+pub const CHALLENGE_SIZE: usize = 10;
+
+/// Default number of leaderboard entries returned
+const DEFAULT_LEADERBOARD_LIMIT: u32 = 50;
+
+/// Largest number of leaderboard entries that can be requested
+const MAX_LEADERBOARD_LIMIT: u32 = 500;
+
+/// Parses a challenge date from the URL path
+///
+/// Accepts `today` or an ISO 8601 calendar date (`YYYY-MM-DD`). Future dates
+/// are rejected so upcoming challenges can't be peeked at.
+pub fn parse_challenge_date(date: &str) -> Result<NaiveDate, ChallengeError> {
+    let today = Utc::now().date_naive();
+
+    if date == "today" {
+        return Ok(today);
+    }
+
+    let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| ChallengeError::InvalidDate(date.to_string()))?;
+
+    if parsed > today {
+        return Err(ChallengeError::FutureDate(parsed));
+    }
+
+    Ok(parsed)
+}
+
+/// Derives a stable seed from the language and the date (FNV-1a)
+fn challenge_seed(lang: &str, date: NaiveDate) -> u64 {
+    format!("{lang}:{date}")
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+/// Picks `count` distinct indices out of `0..len` from a seed (SplitMix64)
+///
+/// The same seed always yields the same picks, in the same order.
+fn pick_indices(len: usize, count: usize, seed: u64) -> Vec<usize> {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+
+    // partial Fisher-Yates shuffle
+    let mut indices: Vec<usize> = (0..len).collect();
+    let count = count.min(len);
+    for i in 0..count {
+        let j = i + (next() % (len - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+    indices.truncate(count);
+    indices
+}
+
+/// Grades answers against the expected words, ignoring case and whitespace
+pub fn grade_answers(expected: &[String], answers: &[String]) -> Vec<bool> {
+    expected
+        .iter()
+        .zip(answers)
+        .map(|(word, answer)| word.to_lowercase() == answer.trim().to_lowercase())
+        .collect()
+}
+
+/// Looks up an existing challenge without creating it
+async fn find_challenge(
+    dbpool: &SqlitePool,
+    language: &Language,
+    date: NaiveDate,
+) -> Result<Option<i64>, AppError> {
+    let code = language.code();
+    let date = date.to_string();
+    query_scalar!(
+        "SELECT id FROM daily_challenges WHERE language = $1 AND challenge_date = $2",
+        code,
+        date
+    )
+    .fetch_optional(dbpool)
+    .await
+    .map_err(Into::into)
+}
+
+/// Returns the challenge for a day, picking and storing its words on first use
+async fn find_or_create_challenge(
+    dbpool: &SqlitePool,
+    language: &Language,
+    date: NaiveDate,
+) -> Result<i64, AppError> {
+    if let Some(id) = find_challenge(dbpool, language, date).await? {
+        return Ok(id);
+    }
+
+    // perform the actual query, checked at compile time, candidates with
+    // their primary sense and pronunciation, neither trashed nor flagged
+    let code = language.code();
+    let candidates = query!(
+        "SELECT w.word, s.definition, p.pronunciation FROM words w JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY position LIMIT 1) WHERE w.language = $1 AND w.deleted_at IS NULL AND NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id) ORDER BY w.id",
+        code
+    )
+    .fetch_all(dbpool)
+    .await?;
+
+    if candidates.is_empty() {
+        return Err(ChallengeError::NoWords.into());
+    }
+
+    let picks = pick_indices(
+        candidates.len(),
+        CHALLENGE_SIZE,
+        challenge_seed(language.code(), date),
+    );
+
+    // a concurrent request may have created the same challenge meanwhile
+    let mut tx = dbpool.begin().await?;
+    let challenge_date = date.to_string();
+    let inserted = query!(
+        "INSERT OR IGNORE INTO daily_challenges (language, challenge_date) VALUES ($1, $2)",
+        code,
+        challenge_date
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if inserted.rows_affected() == 1 {
+        let challenge_id = inserted.last_insert_rowid();
+        for (position, &index) in picks.iter().enumerate() {
+            let position = position as i64;
+            let candidate = &candidates[index];
+            query!(
+                "INSERT INTO daily_challenge_words (challenge_id, position, word, definition, pronunciation) VALUES ($1, $2, $3, $4, $5)",
+                challenge_id,
+                position,
+                candidate.word,
+                candidate.definition,
+                candidate.pronunciation
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+    tx.commit().await?;
+
+    find_challenge(dbpool, language, date)
+        .await?
+        .ok_or_else(|| ChallengeError::NoWords.into())
+}
+
+/// A word to spell in a daily challenge
+///
+/// # Fields
+///
+/// - `word`: The lemma to spell, absent until the day of the challenge is over
+/// - `definition`: Definition given as the clue
+/// - `pronunciation`: IPA phonetic notation given as the clue
+#[derive(ToSchema, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeWord {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    word: Option<String>,
+    definition: String,
+    pronunciation: String,
+}
+
+/// A daily challenge and its words, in play order
+///
+/// # Fields
+///
+/// - `language`: Language code of the challenge
+/// - `date`: Calendar day (UTC) the challenge belongs to
+/// - `words`: The fixed words to spell, same for every player on that day,
+///   with the lemmas only once the day is over
+#[derive(ToSchema, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallenge {
+    language: String,
+    date: NaiveDate,
+    words: Vec<ChallengeWord>,
+}
+
+impl DailyChallenge {
+    /// Retrieves the challenge for a date, creating it on first request
+    ///
+    /// Today's challenge is still open, so its words come without the
+    /// lemmas, which are the answers.
+    pub async fn for_date(dbpool: SqlitePool, lang: &str, date: &str) -> Result<Self, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(&dbpool, lang).await?;
+
+        let date = parse_challenge_date(date)?;
+        let challenge_id = find_or_create_challenge(&dbpool, &language, date).await?;
+
+        let mut words = query_as!(
+            ChallengeWord,
+            r#"SELECT word AS "word?", definition, pronunciation FROM daily_challenge_words WHERE challenge_id = $1 ORDER BY position"#,
+            challenge_id
+        )
+        .fetch_all(&dbpool)
+        .await?;
+
+        // the answers are only revealed once submissions are closed
+        if date >= Utc::now().date_naive() {
+            for word in words.iter_mut() {
+                word.word = None;
+            }
+        }
+
+        Ok(Self {
+            language: language.code().to_string(),
+            date,
+            words,
+        })
+    }
+}
+
+/// A player's answers for the daily challenge
+///
+/// Answers are matched in order against the challenge words. Letter case
+/// and surrounding whitespace are ignored.
+#[derive(ToSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeSubmission {
+    pub answers: Vec<String>,
+}
+
+/// Server-side grading of a challenge submission
+///
+/// # Fields
+///
+/// - `score`: Number of correctly spelled words
+/// - `total`: Number of words in the challenge
+/// - `correct`: Per-word outcome, in challenge order
+#[derive(ToSchema, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeResult {
+    pub score: u32,
+    pub total: u32,
+    pub correct: Vec<bool>,
+}
+
+impl ChallengeSubmission {
+    /// Grades and records a submission for today's challenge
+    ///
+    /// Each user may submit once per challenge, and each access token may
+    /// only ever be used for one submission (its `jti` is stored), which
+    /// prevents replaying a captured request.
+    pub async fn submit(
+        self,
+        dbpool: SqlitePool,
+        lang: &str,
+        date: &str,
+        user: &AuthUser,
+    ) -> Result<ChallengeResult, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(&dbpool, lang).await?;
+
+        let date = parse_challenge_date(date)?;
+        if date != Utc::now().date_naive() {
+            return Err(ChallengeError::Closed(date).into());
+        }
+
+        let challenge_id = find_or_create_challenge(&dbpool, &language, date).await?;
+
+        let replayed = query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM challenge_submissions WHERE token_id = $1) AS "replayed!: bool""#,
+            user.jti
+        )
+        .fetch_one(&dbpool)
+        .await?;
+        if replayed {
+            return Err(ChallengeError::ReplayedToken.into());
+        }
+
+        let submitted = query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM challenge_submissions WHERE challenge_id = $1 AND user_id = $2) AS "submitted!: bool""#,
+            challenge_id,
+            user.id
+        )
+        .fetch_one(&dbpool)
+        .await?;
+        if submitted {
+            return Err(ChallengeError::AlreadySubmitted.into());
+        }
+
+        let expected = query_scalar!(
+            "SELECT word FROM daily_challenge_words WHERE challenge_id = $1 ORDER BY position",
+            challenge_id
+        )
+        .fetch_all(&dbpool)
+        .await?;
+
+        if self.answers.len() != expected.len() {
+            return Err(ChallengeError::AnswerCount {
+                expected: expected.len(),
+                got: self.answers.len(),
+            }
+            .into());
+        }
+
+        let correct = grade_answers(&expected, &self.answers);
+        let result = ChallengeResult {
+            score: correct.iter().filter(|c| **c).count() as u32,
+            total: expected.len() as u32,
+            correct,
+        };
+
+        query!(
+            "INSERT INTO challenge_submissions (challenge_id, user_id, token_id, score, total) VALUES ($1, $2, $3, $4, $5)",
+            challenge_id,
+            user.id,
+            user.jti,
+            result.score,
+            result.total
+        )
+        .execute(&dbpool)
+        .await
+        .map_err(|e| match e {
+            // lost a race against a concurrent submission by the same user
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::from(ChallengeError::AlreadySubmitted)
+            }
+            e => AppError::from(e),
+        })?;
+
+        Ok(result)
+    }
+}
+
+/// A single ranked row on the daily leaderboard
+///
+/// Players with the same score share a rank (1, 2, 2, 4...). Ties are
+/// listed by submission time, earliest first.
+#[derive(ToSchema, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    pub rank: u32,
+    pub username: String,
+    pub score: u32,
+    pub total: u32,
+    pub submitted_at: Option<NaiveDateTime>,
+}
+
+/// Rankings for one daily challenge
+#[derive(ToSchema, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Leaderboard {
+    language: String,
+    date: NaiveDate,
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// Query parameters for the leaderboard endpoint
+#[derive(Deserialize, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct LeaderboardParams {
+    /// Maximum number of entries to return (1-500, default: 50)
+    pub limit: Option<u32>,
+}
+
+/// Assigns competition ranks to entries already sorted by score
+fn assign_ranks(entries: &mut [LeaderboardEntry]) {
+    let mut previous_score = None;
+    let mut rank = 0;
+    for (i, entry) in entries.iter_mut().enumerate() {
+        if previous_score != Some(entry.score) {
+            rank = i as u32 + 1;
+            previous_score = Some(entry.score);
+        }
+        entry.rank = rank;
+    }
+}
+
+impl Leaderboard {
+    /// Retrieves the ranked submissions for a challenge date
+    pub async fn for_date(
+        dbpool: SqlitePool,
+        lang: &str,
+        date: &str,
+        params: &LeaderboardParams,
+    ) -> Result<Self, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(&dbpool, lang).await?;
+
+        let date = parse_challenge_date(date)?;
+        let limit = params
+            .limit
+            .unwrap_or(DEFAULT_LEADERBOARD_LIMIT)
+            .clamp(1, MAX_LEADERBOARD_LIMIT);
+
+        let mut entries = match find_challenge(&dbpool, &language, date).await? {
+            // ranks are assigned below, once sorted by score
+            Some(challenge_id) => {
+                query_as!(
+                    LeaderboardEntry,
+                    r#"SELECT 0 AS "rank!: u32", u.username, s.score AS "score: u32", s.total AS "total: u32", s.created_at AS "submitted_at: NaiveDateTime" FROM challenge_submissions s JOIN users u ON u.id = s.user_id WHERE s.challenge_id = $1 ORDER BY s.score DESC, s.created_at ASC, s.id ASC LIMIT $2"#,
+                    challenge_id,
+                    limit
+                )
+                .fetch_all(&dbpool)
+                .await?
+            }
+            None => Vec::new(),
+        };
+
+        assign_ranks(&mut entries);
+
+        Ok(Self {
+            language: language.code().to_string(),
+            date,
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_challenge_date() {
+        let today = Utc::now().date_naive();
+        assert_eq!(parse_challenge_date("today").unwrap(), today);
+        assert_eq!(
+            parse_challenge_date("2025-01-31").unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+        );
+
+        assert!(matches!(
+            parse_challenge_date("31-01-2025"),
+            Err(ChallengeError::InvalidDate(_))
+        ));
+        assert!(matches!(
+            parse_challenge_date("yesterday"),
+            Err(ChallengeError::InvalidDate(_))
+        ));
+
+        let tomorrow = (today + chrono::Duration::days(1)).to_string();
+        assert!(matches!(
+            parse_challenge_date(&tomorrow),
+            Err(ChallengeError::FutureDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_pick_indices_is_deterministic() {
+        let date = NaiveDate::from_ymd_opt(2025, 8, 1).unwrap();
+        let seed = challenge_seed("en", date);
+
+        let first = pick_indices(100, CHALLENGE_SIZE, seed);
+        let second = pick_indices(100, CHALLENGE_SIZE, seed);
+        assert_eq!(first, second);
+        assert_eq!(first.len(), CHALLENGE_SIZE);
+
+        // all picks are distinct and in range
+        let mut sorted = first.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), CHALLENGE_SIZE);
+        assert!(sorted.iter().all(|i| *i < 100));
+
+        // another day gives another challenge
+        let next_day = challenge_seed("en", date.succ_opt().unwrap());
+        assert_ne!(first, pick_indices(100, CHALLENGE_SIZE, next_day));
+    }
+
+    #[test]
+    fn test_pick_indices_small_dictionary() {
+        let picks = pick_indices(3, CHALLENGE_SIZE, 42);
+        assert_eq!(picks.len(), 3);
+        assert!(pick_indices(0, CHALLENGE_SIZE, 42).is_empty());
+    }
+
+    #[test]
+    fn test_grade_answers() {
+        let expected = vec!["apple".to_string(), "banana".to_string()];
+        let answers = vec![" Apple ".to_string(), "bananna".to_string()];
+        assert_eq!(grade_answers(&expected, &answers), vec![true, false]);
+    }
+
+    #[test]
+    fn test_assign_ranks() {
+        let entry = |score| LeaderboardEntry {
+            rank: 0,
+            username: "player".to_string(),
+            score,
+            total: 10,
+            submitted_at: None,
+        };
+        let mut entries = vec![entry(9), entry(7), entry(7), entry(3)];
+        assign_ranks(&mut entries);
+
+        let ranks: Vec<u32> = entries.iter().map(|e| e.rank).collect();
+        assert_eq!(ranks, vec![1, 2, 2, 4]);
+    }
+}
@@ -0,0 +1,347 @@
+//! Streaming bulk export of the words of a language
+//!
+//! Exports are written row by row while words are read from the database,
+//! so the size of the dictionary doesn't matter. Rows hold the same fields
+//! as an import, in a stable column order and sorted by lemma, and the
+//! output starts with metadata naming the dataset version. Exports don't
+//! carry a timestamp, so two exports of the same data are identical and
+//! can be diffed. An export can be imported back as it is.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{query, SqlitePool};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use tokio::sync::mpsc;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{AppError, QueryError};
+use crate::models::category::TypeCategory;
+use crate::models::import::CSV_COLUMNS;
+use crate::models::language::Language;
+use crate::models::tag::TagFilter;
+use crate::models::word::{GrammaticalType, UpsertWord, Word};
+
+/// Words read ahead of the writer, bounding the memory an export takes
+const EXPORT_BUFFER: usize = 64;
+
+/// Formats the words can be exported in
+///
+/// # Supported Formats
+/// - `csv` - A `#` metadata comment, a header line and one word per line
+/// - `ndjson` - A metadata object line, then one JSON word per line
+/// - `json` - A JSON object with the `metadata` and the array of `words`
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum ExportFormat {
+    #[strum(serialize = "csv")]
+    Csv,
+    #[strum(serialize = "ndjson")]
+    Ndjson,
+    #[default]
+    #[strum(serialize = "json")]
+    Json,
+}
+
+impl ExportFormat {
+    pub fn format_name(&self) -> &str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// Media type of the exported body
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Ndjson => "application/x-ndjson",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+/// Query parameters of a bulk export
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ExportParams {
+    /// Format of the export: 'csv', 'ndjson' or 'json' (default)
+    pub format: Option<String>,
+    /// Only export words of this grammatical type (e.g. 'noun')
+    pub word_type: Option<String>,
+    /// Only export words of this sub-category (e.g. 'proper', 'transitive')
+    pub category: Option<String>,
+}
+
+impl ExportParams {
+    /// Returns the export format, `json` if absent
+    pub fn format(&self) -> Result<ExportFormat, QueryError> {
+        match &self.format {
+            Some(format) => ExportFormat::from_str(&format.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("format".to_string(), format.clone())),
+            None => Ok(ExportFormat::default()),
+        }
+    }
+}
+
+/// Metadata at the start of an export
+///
+/// # Fields
+///
+/// - `language`: Code of the exported language
+/// - `dataset_version`: Version of the words of the language, which changes whenever a
+///   word is added, changed or deleted, made of the time of the last change and the
+///   number of words (e.g. "20261018200000123-2000")
+/// - `format`: Format of the export
+/// - `columns`: Fields of each word, in the order they are written
+///
+#[derive(ToSchema, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMetadata {
+    language: String,
+    dataset_version: String,
+    format: String,
+    columns: Vec<String>,
+}
+
+/// Starts exporting the words of a language (admin only)
+///
+/// Checks the language and filters, then returns the chunks of the body as
+/// a channel filled by a background task. A database error after the first
+/// chunk ends the body early, as the response status is already sent.
+pub async fn export_words(
+    dbpool: SqlitePool,
+    lang: &str,
+    format: ExportFormat,
+    params: &ExportParams,
+    tags: TagFilter,
+) -> Result<mpsc::Receiver<Result<String, std::io::Error>>, AppError> {
+    // if the language is registered
+    let language = Language::find(&dbpool, lang).await?;
+
+    // if the type and category are in the allowed ones
+    let word_type = match &params.word_type {
+        Some(t) => Some(
+            GrammaticalType::resolve(&dbpool, &language, t)
+                .await?
+                .type_name()
+                .to_string(),
+        ),
+        None => None,
+    };
+    let category = match &params.category {
+        Some(c) => Some(
+            TypeCategory::from_str(&c.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("category".to_string(), c.clone()))?
+                .category_name()
+                .to_string(),
+        ),
+        None => None,
+    };
+
+    let metadata = ExportMetadata {
+        language: language.code().to_string(),
+        dataset_version: dataset_version(&dbpool, language.code()).await?,
+        format: format.format_name().to_string(),
+        columns: CSV_COLUMNS.iter().map(|c| c.to_string()).collect(),
+    };
+
+    let (words_tx, mut words_rx) = mpsc::channel(EXPORT_BUFFER);
+    let (chunks_tx, chunks_rx) = mpsc::channel(EXPORT_BUFFER);
+    let code = metadata.language.clone();
+    tokio::spawn(async move {
+        let result = Word::send_all(
+            &dbpool,
+            &code,
+            word_type.as_deref(),
+            category.as_deref(),
+            &tags,
+            &words_tx,
+        )
+        .await;
+        if let Err(e) = result {
+            let _ = words_tx.send(Err(e)).await;
+        }
+    });
+    tokio::spawn(async move {
+        let mut first = true;
+        let _ = chunks_tx.send(Ok(header(format, &metadata))).await;
+        while let Some(word) = words_rx.recv().await {
+            let chunk = word
+                .map(|w| row(format, UpsertWord::from(w), first))
+                .map_err(|e| std::io::Error::other(format!("{e:?}")));
+            first = false;
+            if chunks_tx.send(chunk).await.is_err() {
+                return;
+            }
+        }
+        if format == ExportFormat::Json {
+            let _ = chunks_tx.send(Ok("\n]}\n".to_string())).await;
+        }
+    });
+
+    Ok(chunks_rx)
+}
+
+/// Version of the words of a language, from the time of the last change and the count
+///
+/// Words in the trash aren't counted, but deleting a word is a change.
+async fn dataset_version(dbpool: &SqlitePool, code: &str) -> Result<String, AppError> {
+    let stats = query!(
+        r#"SELECT COUNT(CASE WHEN deleted_at IS NULL THEN 1 END) AS "count!: i64", MAX(COALESCE(updated_at, created_at)) AS "last_change: String" FROM words WHERE language = $1"#,
+        code
+    )
+    .fetch_one(dbpool)
+    .await?;
+
+    let digits: String = stats
+        .last_change
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect();
+    Ok(format!(
+        "{}-{}",
+        if digits.is_empty() { "0" } else { &digits },
+        stats.count
+    ))
+}
+
+/// First chunk of an export, holding its metadata
+fn header(format: ExportFormat, metadata: &ExportMetadata) -> String {
+    let json = serde_json::to_string(metadata).unwrap_or_default();
+    match format {
+        ExportFormat::Csv => format!("# {}\n{}\n", json, CSV_COLUMNS.join(",")),
+        ExportFormat::Ndjson => format!("{{\"metadata\":{json}}}\n"),
+        ExportFormat::Json => format!("{{\"metadata\":{json},\"words\":["),
+    }
+}
+
+/// A word as one chunk of an export
+fn row(format: ExportFormat, word: UpsertWord, first: bool) -> String {
+    match format {
+        ExportFormat::Csv => csv_row(&word),
+        ExportFormat::Ndjson => format!("{}\n", serde_json::to_string(&word).unwrap_or_default()),
+        ExportFormat::Json => format!(
+            "{}\n{}",
+            if first { "" } else { "," },
+            serde_json::to_string(&word).unwrap_or_default()
+        ),
+    }
+}
+
+/// A word as a CSV line, list columns holding JSON arrays
+fn csv_row(word: &UpsertWord) -> String {
+    let cells = [
+        word.word.clone(),
+        word.word_type.clone(),
+        json_cell(&word.senses),
+        json_cell(&word.examples),
+        word.usage_note.clone().unwrap_or_default(),
+        json_cell(&word.inflections),
+        json_cell(&word.categories),
+        json_cell(&word.flags),
+        json_cell(&word.pronunciations),
+    ];
+
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+    let _ = writer.write_record(&cells);
+    writer
+        .into_inner()
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .unwrap_or_default()
+}
+
+/// A list as the JSON array of a CSV cell, empty lists leaving the cell empty
+fn json_cell<T: Serialize>(items: &[T]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+    serde_json::to_string(items).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::word::{PronunciationVariant, WordSense};
+
+    fn cat() -> UpsertWord {
+        UpsertWord {
+            word: "cat".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a small, furry animal".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec!["countable".to_string()],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: "general".to_string(),
+                pronunciation: "/kæt/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_export_params_format() {
+        let params = |format: &str| ExportParams {
+            format: Some(format.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params("CSV").format().unwrap(), ExportFormat::Csv);
+        assert_eq!(params("ndjson").format().unwrap(), ExportFormat::Ndjson);
+        assert_eq!(
+            ExportParams::default().format().unwrap(),
+            ExportFormat::Json
+        );
+        assert!(params("xml").format().is_err());
+    }
+
+    #[test]
+    fn test_export_header() {
+        let metadata = ExportMetadata {
+            language: "en".to_string(),
+            dataset_version: "0-0".to_string(),
+            format: "csv".to_string(),
+            columns: CSV_COLUMNS.iter().map(|c| c.to_string()).collect(),
+        };
+        let csv = header(ExportFormat::Csv, &metadata);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("# {\"language\":\"en\",\"datasetVersion\":\"0-0\""));
+        assert_eq!(lines[1], CSV_COLUMNS.join(","));
+
+        let ndjson = header(ExportFormat::Ndjson, &metadata);
+        let value: serde_json::Value = serde_json::from_str(&ndjson).unwrap();
+        assert_eq!(value["metadata"]["columns"][0], "word");
+
+        // the JSON export is valid once the words are closed
+        let json = header(ExportFormat::Json, &metadata)
+            + &row(ExportFormat::Json, cat(), true)
+            + &row(ExportFormat::Json, cat(), false)
+            + "\n]}\n";
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["words"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_csv_row() {
+        let line = csv_row(&cat());
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(line.as_bytes());
+        let record = reader.records().next().unwrap().unwrap();
+        assert_eq!(record.len(), CSV_COLUMNS.len());
+        assert_eq!(&record[0], "cat");
+        assert_eq!(&record[1], "noun");
+        assert_eq!(&record[3], "");
+        assert_eq!(&record[6], "[\"countable\"]");
+        let senses: serde_json::Value = serde_json::from_str(&record[2]).unwrap();
+        assert_eq!(senses[0]["definition"], "a small, furry animal");
+    }
+}
@@ -0,0 +1,122 @@
+//! Content safety flags for words and safe mode
+//!
+//! Words can be flagged as offensive, mature, archaic or slang. Public
+//! endpoints run in safe mode by default and leave flagged words out of
+//! their results. Only administrators can turn safe mode off. Daily
+//! challenges and word ladders are shared puzzles and never use flagged
+//! words.
+
+use serde::Deserialize;
+use sqlx::{query, query_scalar, SqliteConnection};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::IntoParams;
+use validator::ValidationError;
+
+use crate::error::{AppError, SafetyError};
+use crate::models::user::AuthUser;
+
+/// Content flags supported by the API
+///
+/// # Supported Flags
+/// - `offensive` - Slurs, insults and other offensive words
+/// - `mature` - Words about adult topics (e.g. violence, drugs)
+/// - `archaic` - Words no longer in everyday use (e.g. "thou")
+/// - `slang` - Informal words not suited to a classroom
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum ContentFlag {
+    #[strum(serialize = "offensive")]
+    Offensive,
+    #[strum(serialize = "mature")]
+    Mature,
+    #[strum(serialize = "archaic")]
+    Archaic,
+    #[strum(serialize = "slang")]
+    Slang,
+}
+
+impl ContentFlag {
+    pub fn flag_name(&self) -> &str {
+        match self {
+            ContentFlag::Offensive => "offensive",
+            ContentFlag::Mature => "mature",
+            ContentFlag::Archaic => "archaic",
+            ContentFlag::Slang => "slang",
+        }
+    }
+
+    /// Loads the flags of a single word, in alphabetical order
+    pub(crate) async fn for_word(
+        conn: &mut SqliteConnection,
+        word_id: u32,
+    ) -> Result<Vec<String>, AppError> {
+        query_scalar!(
+            "SELECT flag FROM word_flags WHERE word_id = $1 ORDER BY flag",
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the flags of a word
+    pub(crate) async fn replace_all(
+        tx: &mut SqliteConnection,
+        word_id: u32,
+        flags: &[String],
+    ) -> Result<(), AppError> {
+        query!("DELETE FROM word_flags WHERE word_id = $1", word_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for flag in flags {
+            query!(
+                "INSERT INTO word_flags (word_id, flag) VALUES ($1, $2)",
+                word_id,
+                flag
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates a list of flags for allowed flags and duplicates
+pub(crate) fn validate_flags(list: &[String]) -> Result<(), ValidationError> {
+    if list
+        .iter()
+        .any(|f| ContentFlag::from_str(&f.to_lowercase()).is_err())
+    {
+        return Err(ValidationError::new("invalid_content_flag"));
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    if !list.iter().all(|f| seen.insert(f.to_lowercase())) {
+        return Err(ValidationError::new("duplicate_content_flag"));
+    }
+    Ok(())
+}
+
+/// Query parameters controlling safe mode
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct SafeParams {
+    /// Leave out words flagged as offensive, mature, archaic or slang, `true` if absent.
+    /// Only administrators can set it to `false`
+    pub safe: Option<bool>,
+}
+
+impl SafeParams {
+    /// Returns whether flagged words are left out for this caller
+    ///
+    /// Safe mode is on unless the caller asks to turn it off and has a
+    /// role allowed to do so.
+    pub fn safe(&self, user: Option<&AuthUser>) -> Result<bool, SafetyError> {
+        match self.safe {
+            Some(false) if user.is_some_and(|u| u.is_admin) => Ok(false),
+            Some(false) => Err(SafetyError::SafeModeRequired),
+            _ => Ok(true),
+        }
+    }
+}
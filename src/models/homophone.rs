@@ -0,0 +1,104 @@
+//! Homophone lookup for spelling drills
+//!
+//! Homophones are words sharing an IPA transcription, in any of their
+//! pronunciation variants (e.g. "their", "there" and "they're"). Lookups
+//! match on the stored `word_pronunciations`, so a word can belong to
+//! different groups through its regional variants.
+
+use sqlx::{query_as, query_scalar, SqlitePool};
+
+use crate::error::AppError;
+use crate::models::language::Language;
+use crate::models::tag::TagFilter;
+use crate::models::word::{GetWord, GrammaticalType, RandomWordRow, Word};
+
+/// Retrieves the words sounding like a lemma, in alphabetical order
+///
+/// Each word is returned with the pronunciation it shares with the lemma.
+/// The lemma itself is not part of the result, nor are flagged words in
+/// safe mode.
+pub async fn find_homophones(
+    dbpool: &SqlitePool,
+    lang: &str,
+    lemma: &str,
+    safe: bool,
+) -> Result<Vec<GetWord>, AppError> {
+    // if the language is registered and enabled
+    let language = Language::resolve(dbpool, lang).await?;
+
+    let word_id = Word::find_id(dbpool, &language, lemma).await?;
+
+    // perform the actual query, checked at compile time, the primary sense
+    // of each homophone and the safe mode filter included
+    let code = language.code();
+    let words = query_as!(
+        RandomWordRow,
+        "SELECT w.word, s.definition, p.pronunciation, p.variant FROM words w JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.word_id = w.id WHERE w.language = $3 AND w.deleted_at IS NULL AND w.id != $1 AND p.pronunciation IN (SELECT pronunciation FROM word_pronunciations WHERE word_id = $1) AND ($2 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) GROUP BY w.id ORDER BY w.word",
+        word_id,
+        safe,
+        code
+    )
+    .fetch_all(dbpool)
+    .await?;
+
+    Ok(words.into_iter().map(GetWord::from).collect())
+}
+
+/// Retrieves a random group of two or more words sharing a pronunciation
+///
+/// With a `word_type`, at least one word of the group has that type. Only
+/// words passing the tag filter take part in groups, and in safe mode only
+/// words without content flags. Returns an empty list when the dictionary
+/// has no homophones.
+pub async fn random_homophones(
+    dbpool: &SqlitePool,
+    lang: &str,
+    word_type: Option<&str>,
+    tags: &TagFilter,
+    safe: bool,
+) -> Result<Vec<GetWord>, AppError> {
+    // if the language is registered and enabled
+    let language = Language::resolve(dbpool, lang).await?;
+
+    // if the grammatical type is in the allowed ones
+    let grammatical_type = match word_type {
+        Some(t) => Some(GrammaticalType::resolve(dbpool, &language, t).await?),
+        None => None,
+    };
+
+    // perform the actual query, checked at compile time, with the tag
+    // filters bound as JSON arrays and flagged words left out in safe mode
+    let code = language.code();
+    let type_name = grammatical_type.as_ref().map(GrammaticalType::type_name);
+    let include = tags.include_json();
+    let exclude = tags.exclude_json();
+    let pronunciation = query_scalar!(
+        "SELECT p.pronunciation FROM word_pronunciations p JOIN words w ON w.id = p.word_id WHERE w.language = $5 AND w.deleted_at IS NULL AND ($2 IS NULL OR EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($2)))) AND ($3 IS NULL OR NOT EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($3)))) AND ($4 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) GROUP BY p.pronunciation HAVING COUNT(DISTINCT w.id) > 1 AND ($1 IS NULL OR SUM(w.word_type = $1) > 0) ORDER BY random() LIMIT 1",
+        type_name,
+        include,
+        exclude,
+        safe,
+        code
+    )
+    .fetch_optional(dbpool)
+    .await?;
+
+    let Some(pronunciation) = pronunciation else {
+        return Ok(Vec::new());
+    };
+
+    // perform the actual query, spelled out like the previous one
+    let words = query_as!(
+        RandomWordRow,
+        "SELECT w.word, s.definition, p.pronunciation, p.variant FROM words w JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.word_id = w.id WHERE w.language = $5 AND w.deleted_at IS NULL AND p.pronunciation = $1 AND ($2 IS NULL OR EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($2)))) AND ($3 IS NULL OR NOT EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($3)))) AND ($4 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) ORDER BY w.word",
+        pronunciation,
+        include,
+        exclude,
+        safe,
+        code
+    )
+    .fetch_all(dbpool)
+    .await?;
+
+    Ok(words.into_iter().map(GetWord::from).collect())
+}
@@ -0,0 +1,639 @@
+//! Bulk word import from CSV, JSON or NDJSON
+//!
+//! Administrators load whole word lists in one request instead of one
+//! `POST` per word. Every row goes through the same validation as
+//! [`UpsertWord`], and the import is applied in a single transaction: when
+//! a row is invalid nothing is written, and the report lists the problems
+//! of each row. A dry run checks and reports the rows without writing them.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::{query, query_scalar, SqliteConnection, SqlitePool};
+use std::collections::HashSet;
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+use crate::error::{AppError, ImportError, ProfileError, QueryError, TrashError};
+use crate::models::language::Language;
+use crate::models::profile::ValidationProfile;
+use crate::models::revision::RevisionAction;
+use crate::models::user::AuthUser;
+use crate::models::word::{UpsertWord, Word};
+
+/// Columns of a CSV import, in the order of exports
+///
+/// Text columns hold the field as is. List columns hold a JSON array in the
+/// same shape as in a JSON body (e.g. `[{"definition": "...", "partOfSpeech": "noun"}]`),
+/// an empty cell being an empty list.
+pub const CSV_COLUMNS: [&str; 9] = [
+    "word",
+    "wordType",
+    "senses",
+    "examples",
+    "usageNote",
+    "inflections",
+    "categories",
+    "flags",
+    "pronunciations",
+];
+
+/// Text columns of a CSV import, the other ones hold JSON arrays
+const CSV_TEXT_COLUMNS: [&str; 3] = ["word", "wordType", "usageNote"];
+
+/// Formats an import body can be written in
+///
+/// # Supported Formats
+/// - `csv` - A header line naming the columns, then one word per line
+/// - `json` - A JSON array of words
+/// - `ndjson` - One JSON word per line
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum ImportFormat {
+    #[strum(serialize = "csv")]
+    Csv,
+    #[strum(serialize = "json")]
+    Json,
+    #[strum(serialize = "ndjson")]
+    Ndjson,
+}
+
+impl ImportFormat {
+    pub fn format_name(&self) -> &str {
+        match self {
+            ImportFormat::Csv => "csv",
+            ImportFormat::Json => "json",
+            ImportFormat::Ndjson => "ndjson",
+        }
+    }
+
+    /// Format matching the media type of a `Content-Type` header
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        match media_type.to_lowercase().as_str() {
+            "text/csv" => Some(ImportFormat::Csv),
+            "application/json" => Some(ImportFormat::Json),
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => {
+                Some(ImportFormat::Ndjson)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// How imported words are applied to the words of the language
+///
+/// # Supported Modes
+/// - `insert` - Adds new words, lemmas already stored are reported as duplicates
+/// - `upsert` - Adds new words and updates the ones already stored
+/// - `replace` - Adds and updates the imported words, and moves the other words
+///   of the language to the trash
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum ImportMode {
+    #[default]
+    #[strum(serialize = "insert")]
+    Insert,
+    #[strum(serialize = "upsert")]
+    Upsert,
+    #[strum(serialize = "replace")]
+    Replace,
+}
+
+impl ImportMode {
+    pub fn mode_name(&self) -> &str {
+        match self {
+            ImportMode::Insert => "insert",
+            ImportMode::Upsert => "upsert",
+            ImportMode::Replace => "replace",
+        }
+    }
+}
+
+/// Query parameters of a bulk import
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ImportParams {
+    /// Format of the body: 'csv', 'json' or 'ndjson', taken from the
+    /// `Content-Type` header if absent
+    pub format: Option<String>,
+    /// How words are applied: 'insert' (default), 'upsert' or 'replace'
+    pub mode: Option<String>,
+    /// Check and report the rows without writing them, `false` if absent
+    pub dry_run: Option<bool>,
+}
+
+impl ImportParams {
+    /// Returns the format of the body, from the parameter or the content type
+    pub fn format(&self, content_type: Option<&str>) -> Result<ImportFormat, QueryError> {
+        match &self.format {
+            Some(format) => ImportFormat::from_str(&format.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("format".to_string(), format.clone())),
+            None => content_type
+                .and_then(ImportFormat::from_content_type)
+                .ok_or_else(|| QueryError::MissingParameter("format".to_string())),
+        }
+    }
+
+    /// Returns the import mode, `insert` if absent
+    pub fn mode(&self) -> Result<ImportMode, QueryError> {
+        match &self.mode {
+            Some(mode) => ImportMode::from_str(&mode.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("mode".to_string(), mode.clone())),
+            None => Ok(ImportMode::default()),
+        }
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run.unwrap_or(false)
+    }
+}
+
+/// A problem with a field of an imported row
+///
+/// # Fields
+///
+/// - `field`: Path of the field in the word (e.g. "senses[0].definition"), "row" when the
+///   row couldn't be read at all
+/// - `code`: Validation code of the problem (e.g. "invalid_lemma", "invalid_character")
+/// - `message`: Details of the problem, when there are any
+///
+#[derive(ToSchema, Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldError {
+    field: String,
+    code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+/// The outcome of an imported row
+///
+/// # Fields
+///
+/// - `row`: Position of the row in the body, starting at 1 without the CSV header
+/// - `word`: Lemma of the row, when it could be read
+/// - `status`: "accepted", "duplicate" (of an earlier row, or of a stored word in insert
+///   mode) or "invalid"
+/// - `action`: "insert" or "update" for accepted rows
+/// - `errors`: Problems of invalid rows
+///
+#[derive(ToSchema, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRow {
+    row: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    word: Option<String>,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<FieldError>,
+}
+
+/// Report of a bulk import
+///
+/// # Fields
+///
+/// - `mode`: Import mode the rows were applied with
+/// - `dry_run`: Whether the import was only checked
+/// - `committed`: Whether the accepted rows were written, false for dry runs and
+///   imports with invalid rows
+/// - `deleted`: Words of the language missing from the import, moved to the
+///   trash in replace mode
+/// - `accepted`, `duplicates`, `invalid`: Number of rows of each status
+/// - `rows`: Outcome of every row, in the order of the body
+///
+#[derive(ToSchema, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    mode: String,
+    dry_run: bool,
+    committed: bool,
+    deleted: u64,
+    accepted: usize,
+    duplicates: usize,
+    invalid: usize,
+    rows: Vec<ImportRow>,
+}
+
+impl ImportReport {
+    pub fn committed(&self) -> bool {
+        self.committed
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.invalid
+    }
+}
+
+/// Imports words into a language (admin only)
+///
+/// Rows are read from the body, validated and conformed to the validation
+/// profile of the language, then applied in a single transaction. The
+/// transaction is only committed when no row is invalid and it isn't a dry
+/// run, so a dry run reports exactly what a real import would do. Every
+/// stored row is recorded as a revision made by the acting admin.
+pub async fn import_words(
+    dbpool: &SqlitePool,
+    lang: &str,
+    format: ImportFormat,
+    mode: ImportMode,
+    dry_run: bool,
+    actor: Option<&AuthUser>,
+    body: &[u8],
+) -> Result<ImportReport, AppError> {
+    // if the language is registered
+    let language = Language::find(dbpool, lang).await?;
+
+    let rows = read_rows(format, body)?;
+    let mut report = ImportReport {
+        mode: mode.mode_name().to_string(),
+        dry_run,
+        committed: false,
+        deleted: 0,
+        accepted: 0,
+        duplicates: 0,
+        invalid: 0,
+        rows: Vec::with_capacity(rows.len()),
+    };
+
+    let code = language.code();
+    let mut tx = dbpool.begin().await?;
+    let mut seen = HashSet::new();
+    for (i, row) in rows.into_iter().enumerate() {
+        let outcome = match row.and_then(|word| checked(word, language.profile())) {
+            Ok(word) => apply(&mut tx, code, mode, &mut seen, word, actor).await?,
+            Err(errors) => ImportRow {
+                row: 0,
+                word: None,
+                status: "invalid".to_string(),
+                action: None,
+                errors,
+            },
+        };
+        match outcome.status.as_str() {
+            "accepted" => report.accepted += 1,
+            "duplicate" => report.duplicates += 1,
+            _ => report.invalid += 1,
+        }
+        report.rows.push(ImportRow {
+            row: i + 1,
+            ..outcome
+        });
+    }
+
+    // the words missing from the import go to the trash, where they can be
+    // restored like any deleted word
+    if mode == ImportMode::Replace {
+        let imported = serde_json::to_string(&seen)?;
+        let missing = query_scalar!(
+            r#"SELECT id AS "id!: u32" FROM words WHERE language = $1 AND deleted_at IS NULL AND word NOT IN (SELECT value FROM json_each($2)) ORDER BY id"#,
+            code,
+            imported
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for id in missing {
+            if Word::trash(&mut tx, code, id, actor).await? {
+                report.deleted += 1;
+            }
+        }
+    }
+
+    // nothing is written unless every row could be applied
+    if !dry_run && report.invalid == 0 {
+        tx.commit().await?;
+        report.committed = true;
+    }
+    Ok(report)
+}
+
+/// Validates a row and conforms it to the validation profile of the language
+fn checked(word: UpsertWord, profile: ValidationProfile) -> Result<UpsertWord, Vec<FieldError>> {
+    if let Err(errors) = word.validate() {
+        let mut list = Vec::new();
+        field_errors(&errors, "", &mut list);
+        return Err(list);
+    }
+
+    word.conform(profile).map_err(|e| {
+        let ProfileError::InvalidCharacter { field, .. } = &e;
+        vec![FieldError {
+            field: field.clone(),
+            code: "invalid_character".to_string(),
+            message: Some(e.to_string()),
+        }]
+    })
+}
+
+/// Stores a valid row, unless its lemma is a duplicate
+async fn apply(
+    tx: &mut SqliteConnection,
+    code: &str,
+    mode: ImportMode,
+    seen: &mut HashSet<String>,
+    word: UpsertWord,
+    actor: Option<&AuthUser>,
+) -> Result<ImportRow, AppError> {
+    let lemma = word.word()?.to_lowercase();
+    let mut outcome = ImportRow {
+        row: 0,
+        word: Some(lemma.clone()),
+        status: "duplicate".to_string(),
+        action: None,
+        errors: Vec::new(),
+    };
+    if !seen.insert(lemma.clone()) {
+        return Ok(outcome);
+    }
+
+    let existing = query!(
+        r#"SELECT id AS "id: u32", deleted_at IS NOT NULL AS "trashed!: bool" FROM words WHERE language = $1 AND word = $2"#,
+        code,
+        lemma
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    // a deleted word holds its lemma until it is restored or purged
+    if existing.as_ref().is_some_and(|row| row.trashed) {
+        outcome.status = "invalid".to_string();
+        outcome.errors.push(FieldError {
+            field: "word".to_string(),
+            code: "in_trash".to_string(),
+            message: Some(TrashError::InTrash(lemma).to_string()),
+        });
+        return Ok(outcome);
+    }
+
+    let action = match (existing.map(|row| row.id), mode) {
+        (Some(_), ImportMode::Insert) => return Ok(outcome),
+        (Some(id), _) => {
+            Word::store_revision(tx, code, Some(id), &word, RevisionAction::Update, actor).await?;
+            "update"
+        }
+        (None, _) => {
+            Word::store_revision(tx, code, None, &word, RevisionAction::Create, actor).await?;
+            "insert"
+        }
+    };
+    outcome.status = "accepted".to_string();
+    outcome.action = Some(action.to_string());
+    Ok(outcome)
+}
+
+/// Splits a body into rows, each a word or the reason it couldn't be read
+///
+/// The metadata of an export is skipped, so that exports can be imported
+/// back as they are. Returns `ImportError::MalformedBody` when the body can't be split into
+/// rows at all (e.g. a JSON body that isn't an array).
+fn read_rows(
+    format: ImportFormat,
+    body: &[u8],
+) -> Result<Vec<Result<UpsertWord, Vec<FieldError>>>, AppError> {
+    let malformed =
+        |message: String| ImportError::MalformedBody(format.format_name().to_string(), message);
+
+    let values: Vec<Result<Value, String>> = match format {
+        ImportFormat::Json => {
+            // either an array of words, or the words of an export
+            let words = match serde_json::from_slice(body).map_err(|e| malformed(e.to_string()))? {
+                Value::Array(words) => words,
+                Value::Object(mut export) => match export.remove("words") {
+                    Some(Value::Array(words)) => words,
+                    _ => return Err(malformed("missing 'words' array".to_string()).into()),
+                },
+                _ => return Err(malformed("expected an array of words".to_string()).into()),
+            };
+            words.into_iter().map(Ok).collect()
+        }
+        ImportFormat::Ndjson => std::str::from_utf8(body)
+            .map_err(|e| malformed(e.to_string()))?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str::<Value>(line).map_err(|e| e.to_string()))
+            // the metadata line of an export
+            .filter(|value| !matches!(value, Ok(Value::Object(o)) if o.contains_key("metadata")))
+            .collect(),
+        ImportFormat::Csv => {
+            // the metadata of an export is a comment
+            let mut reader = csv::ReaderBuilder::new()
+                .comment(Some(b'#'))
+                .from_reader(body);
+            let headers = reader
+                .headers()
+                .map_err(|e| malformed(e.to_string()))?
+                .clone();
+            if let Some(column) = headers.iter().find(|h| !CSV_COLUMNS.contains(h)) {
+                return Err(malformed(format!("unknown column '{column}'")).into());
+            }
+            reader
+                .records()
+                .map(|record| {
+                    record
+                        .map_err(|e| e.to_string())
+                        .and_then(|record| csv_object(&headers, &record))
+                })
+                .collect()
+        }
+    };
+
+    Ok(values
+        .into_iter()
+        .map(|value| {
+            value
+                .and_then(|v| serde_json::from_value::<UpsertWord>(v).map_err(|e| e.to_string()))
+                .map_err(|message| {
+                    vec![FieldError {
+                        field: "row".to_string(),
+                        code: "malformed_row".to_string(),
+                        message: Some(message),
+                    }]
+                })
+        })
+        .collect())
+}
+
+/// Turns a CSV record into the JSON object of a word
+///
+/// Empty cells are left out, so that optional fields take their defaults.
+fn csv_object(headers: &csv::StringRecord, record: &csv::StringRecord) -> Result<Value, String> {
+    let mut object = Map::new();
+    for (column, cell) in headers.iter().zip(record.iter()) {
+        if cell.is_empty() {
+            continue;
+        }
+        let value = if CSV_TEXT_COLUMNS.contains(&column) {
+            Value::String(cell.to_string())
+        } else {
+            serde_json::from_str(cell).map_err(|e| format!("column '{column}': {e}"))?
+        };
+        object.insert(column.to_string(), value);
+    }
+    Ok(Value::Object(object))
+}
+
+/// Flattens nested validation errors into field paths such as "senses[0].definition"
+fn field_errors(errors: &ValidationErrors, prefix: &str, list: &mut Vec<FieldError>) {
+    let mut fields: Vec<_> = errors.errors().iter().collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (field, kind) in fields {
+        let path = match (prefix.is_empty(), field.as_ref()) {
+            // errors of the whole word, such as inapplicable inflections
+            (true, "__all__") => "row".to_string(),
+            (false, "__all__") => prefix.to_string(),
+            (true, field) => camel_case(field),
+            (false, field) => format!("{prefix}.{}", camel_case(field)),
+        };
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                list.extend(errors.iter().map(|e| FieldError {
+                    field: path.clone(),
+                    code: e.code.to_string(),
+                    message: e.message.as_ref().map(|m| m.to_string()),
+                }));
+            }
+            ValidationErrorsKind::Struct(errors) => field_errors(errors, &path, list),
+            ValidationErrorsKind::List(items) => {
+                for (i, errors) in items {
+                    field_errors(errors, &format!("{path}[{i}]"), list);
+                }
+            }
+        }
+    }
+}
+
+/// Converts a Rust field name to the camelCase name of the JSON body
+fn camel_case(field: &str) -> String {
+    let mut parts = field.split('_');
+    let first = parts.next().unwrap_or_default().to_string();
+    parts.fold(first, |mut name, part| {
+        let mut chars = part.chars();
+        if let Some(c) = chars.next() {
+            name.extend(c.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+        name
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_format_and_mode() {
+        assert_eq!(
+            ImportFormat::from_content_type("text/csv; charset=utf-8"),
+            Some(ImportFormat::Csv)
+        );
+        assert_eq!(
+            ImportFormat::from_content_type("application/x-ndjson"),
+            Some(ImportFormat::Ndjson)
+        );
+        assert_eq!(ImportFormat::from_content_type("text/plain"), None);
+
+        let params = ImportParams {
+            format: Some("JSON".to_string()),
+            mode: Some("upsert".to_string()),
+            dry_run: None,
+        };
+        assert_eq!(params.format(Some("text/csv")).unwrap(), ImportFormat::Json);
+        assert_eq!(params.mode().unwrap(), ImportMode::Upsert);
+        assert!(!params.dry_run());
+
+        let params = ImportParams::default();
+        assert_eq!(params.format(Some("text/csv")).unwrap(), ImportFormat::Csv);
+        assert!(params.format(None).is_err());
+        assert_eq!(params.mode().unwrap(), ImportMode::Insert);
+        let params = ImportParams {
+            mode: Some("merge".to_string()),
+            ..Default::default()
+        };
+        assert!(params.mode().is_err());
+    }
+
+    #[test]
+    fn test_read_csv_rows() {
+        let body = "word,wordType,senses,pronunciations,flags\n\
+            cat,noun,\"[{\"\"definition\"\": \"\"a small animal\"\", \"\"partOfSpeech\"\": \"\"noun\"\"}]\",\"[{\"\"pronunciation\"\": \"\"/kæt/\"\"}]\",\n\
+            dog,noun,not json,,\n";
+        let rows = read_rows(ImportFormat::Csv, body.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+        let cat = rows[0].as_ref().ok().unwrap();
+        assert_eq!(cat.word, "cat");
+        assert!(cat.flags.is_empty());
+        let errors = rows[1].as_ref().err().unwrap();
+        assert_eq!(errors[0].field, "row");
+        assert!(errors[0].message.as_ref().unwrap().contains("senses"));
+
+        // unknown columns are a mistake in the whole file
+        assert!(read_rows(ImportFormat::Csv, b"lemma,wordType\ncat,noun\n").is_err());
+    }
+
+    #[test]
+    fn test_read_ndjson_and_json_rows() {
+        let body = "{\"word\": \"cat\"}\n\n[1, 2]\n";
+        let rows = read_rows(ImportFormat::Ndjson, body.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| row.is_err()));
+
+        assert!(read_rows(ImportFormat::Json, b"{\"word\": \"cat\"}").is_err());
+        assert_eq!(read_rows(ImportFormat::Json, b"[]").unwrap().len(), 0);
+
+        // the metadata of an export is skipped
+        let body = "{\"metadata\": {\"language\": \"en\"}}\n{\"word\": \"cat\"}\n";
+        assert_eq!(
+            read_rows(ImportFormat::Ndjson, body.as_bytes())
+                .unwrap()
+                .len(),
+            1
+        );
+        let body = b"{\"metadata\": {\"language\": \"en\"}, \"words\": [{}, {}]}";
+        assert_eq!(read_rows(ImportFormat::Json, body).unwrap().len(), 2);
+        let body = b"# {\"language\": \"en\"}\nword,wordType\ncat,noun\n";
+        assert_eq!(read_rows(ImportFormat::Csv, body).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_field_error_paths() {
+        let word: UpsertWord = serde_json::from_value(serde_json::json!({
+            "word": "two words",
+            "wordType": "noun",
+            "senses": [{"definition": "a thing", "partOfSpeech": "determiner"}],
+            "pronunciations": [{"pronunciation": "/kæt/"}],
+            "inflections": [{"kind": "past_tense", "form": "catted"}],
+        }))
+        .unwrap();
+        let errors = checked(word, ValidationProfile::Latin).err().unwrap();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["senses[0].partOfSpeech", "word"]);
+
+        // checks of the whole word are reported on the row
+        let word: UpsertWord = serde_json::from_value(serde_json::json!({
+            "word": "cat",
+            "wordType": "noun",
+            "senses": [{"definition": "a small animal", "partOfSpeech": "noun"}],
+            "pronunciations": [{"pronunciation": "/kæt/"}],
+            "inflections": [{"kind": "past_tense", "form": "catted"}],
+        }))
+        .unwrap();
+        let errors = checked(word, ValidationProfile::Latin).err().unwrap();
+        assert_eq!(errors[0].field, "row");
+        assert_eq!(errors[0].code, "inflection_not_applicable");
+
+        // characters outside of the profile name their field
+        let word: UpsertWord = serde_json::from_value(serde_json::json!({
+            "word": "γάτα",
+            "wordType": "noun",
+            "senses": [{"definition": "a small animal", "partOfSpeech": "noun"}],
+            "pronunciations": [{"pronunciation": "/kæt/"}],
+        }))
+        .unwrap();
+        let errors = checked(word, ValidationProfile::Latin).err().unwrap();
+        assert_eq!(errors[0].field, "word");
+        assert_eq!(errors[0].code, "invalid_character");
+        assert_eq!(camel_case("usage_note"), "usageNote");
+    }
+}
@@ -0,0 +1,201 @@
+//! Inflected forms of words and lookup by inflection
+//!
+//! Words are stored as Merriam-Webster-style lemmas. Their inflections
+//! (e.g. "mice" for "mouse", "ran" for "run") are stored alongside, each
+//! with the kind of inflection, so that an inflected form can be resolved
+//! back to its lemma. Which kinds a word can have depends on its
+//! grammatical type.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, SqliteConnection, SqlitePool};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::error::{AppError, LookupError};
+use crate::models::language::Language;
+use crate::models::word::{is_valid_lemma, GetWord, GrammaticalType, RandomWordRow};
+
+/// Kinds of inflection supported by the API
+///
+/// # Supported Kinds
+/// - `plural` - Nouns (e.g. "mouse" → "mice")
+/// - `past_tense` - Verbs (e.g. "run" → "ran")
+/// - `past_participle` - Verbs (e.g. "run" → "run", "write" → "written")
+/// - `comparative` - Adjectives and adverbs (e.g. "good" → "better")
+/// - `superlative` - Adjectives and adverbs (e.g. "good" → "best")
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum InflectionKind {
+    #[strum(serialize = "plural")]
+    Plural,
+    #[strum(serialize = "past_tense")]
+    PastTense,
+    #[strum(serialize = "past_participle")]
+    PastParticiple,
+    #[strum(serialize = "comparative")]
+    Comparative,
+    #[strum(serialize = "superlative")]
+    Superlative,
+}
+
+impl InflectionKind {
+    pub fn kind_name(&self) -> &str {
+        match self {
+            InflectionKind::Plural => "plural",
+            InflectionKind::PastTense => "past_tense",
+            InflectionKind::PastParticiple => "past_participle",
+            InflectionKind::Comparative => "comparative",
+            InflectionKind::Superlative => "superlative",
+        }
+    }
+
+    /// Whether words of a grammatical type can have this inflection
+    pub fn applies_to(&self, grammatical_type: &GrammaticalType) -> bool {
+        matches!(
+            (self, grammatical_type),
+            (InflectionKind::Plural, GrammaticalType::Noun)
+                | (
+                    InflectionKind::PastTense | InflectionKind::PastParticiple,
+                    GrammaticalType::Verb
+                )
+                | (
+                    InflectionKind::Comparative | InflectionKind::Superlative,
+                    GrammaticalType::Adjective | GrammaticalType::Adverb
+                )
+        )
+    }
+}
+
+/// An inflected form of a word
+///
+/// # Fields
+///
+/// - `kind`: Kind of inflection (plural, past_tense, past_participle, comparative, superlative)
+/// - `form`: The inflected form, following the same rules as lemmas
+///
+#[derive(Debug, PartialEq, ToSchema, Deserialize, Serialize, Clone, Validate, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct WordInflection {
+    #[validate(length(min = 1), custom(function = "validate_inflection_kind"))]
+    pub kind: String,
+    #[validate(length(min = 1), custom(function = "validate_form"))]
+    pub form: String,
+}
+
+/// Validates a kind field for allowed inflection kinds
+fn validate_inflection_kind(text: &str) -> Result<(), ValidationError> {
+    let _ = InflectionKind::from_str(&text.to_lowercase())
+        .map_err(|_| ValidationError::new("invalid_inflection_kind"))?;
+    Ok(())
+}
+
+/// Validates an inflected form using the lemma rules
+fn validate_form(text: &str) -> Result<(), ValidationError> {
+    if !is_valid_lemma(text) {
+        return Err(ValidationError::new("invalid_inflection_form"));
+    }
+    Ok(())
+}
+
+impl WordInflection {
+    /// Loads the inflections of a single word, in display order
+    pub(crate) async fn for_word(
+        conn: &mut SqliteConnection,
+        word_id: u32,
+    ) -> Result<Vec<Self>, AppError> {
+        query_as!(
+            Self,
+            "SELECT kind, form FROM word_inflections WHERE word_id = $1 ORDER BY position",
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the inflections of a word, in the given display order
+    pub(crate) async fn replace_all(
+        tx: &mut SqliteConnection,
+        word_id: u32,
+        inflections: &[Self],
+    ) -> Result<(), AppError> {
+        query!("DELETE FROM word_inflections WHERE word_id = $1", word_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for (position, i) in inflections.iter().enumerate() {
+            let position = position as i64;
+            query!(
+                "INSERT INTO word_inflections (word_id, kind, form, position) VALUES ($1, $2, $3, $4)",
+                word_id,
+                i.kind,
+                i.form,
+                position
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// A lemma matching an inflected form in public API responses
+///
+/// # Fields
+///
+/// - `inflection`: Kind of inflection the looked up form is, or `lemma`
+///   when the form is the lemma itself
+/// - the fields of [`GetWord`] for the lemma
+#[derive(ToSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LemmaMatch {
+    pub inflection: String,
+    #[serde(flatten)]
+    pub word: GetWord,
+}
+
+/// Resolves a word form back to its lemmas, in alphabetical order
+///
+/// A form can match several lemmas (e.g. "left" is the past tense of
+/// "leave" and a lemma of its own). Flagged lemmas are left out in safe
+/// mode. Returns `LookupError::UnknownWord` when the form matches nothing.
+pub async fn find_lemmas(
+    dbpool: &SqlitePool,
+    lang: &str,
+    form: &str,
+    safe: bool,
+) -> Result<Vec<LemmaMatch>, AppError> {
+    // if the language is registered and enabled
+    let language = Language::resolve(dbpool, lang).await?;
+
+    // perform the actual query, checked at compile time, each lemma with its
+    // primary sense and pronunciation, flagged lemmas left out in safe mode
+    let lowercase = form.to_lowercase();
+    let code = language.code();
+    let matches: Vec<LemmaMatch> = query!(
+        r#"SELECT m.inflection AS "inflection!: String", w.word, s.definition, p.pronunciation, p.variant FROM (SELECT word_id, kind AS inflection FROM word_inflections WHERE form = $1 UNION SELECT id, 'lemma' FROM words WHERE word = $1) m JOIN words w ON w.id = m.word_id JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY position LIMIT 1) WHERE w.language = $3 AND w.deleted_at IS NULL AND ($2 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) ORDER BY w.word, m.inflection"#,
+        lowercase,
+        safe,
+        code
+    )
+    .fetch_all(dbpool)
+    .await?
+    .into_iter()
+    .map(|row| LemmaMatch {
+        inflection: row.inflection,
+        word: RandomWordRow {
+            word: row.word,
+            definition: row.definition,
+            pronunciation: row.pronunciation,
+            variant: row.variant,
+        }
+        .into(),
+    })
+    .collect();
+
+    if matches.is_empty() {
+        return Err(LookupError::UnknownWord(form.to_string()).into());
+    }
+    Ok(matches)
+}
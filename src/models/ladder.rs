@@ -0,0 +1,517 @@
+//! Word ladder puzzles built on an in-memory adjacency index
+//!
+//! A word ladder connects two lemmas of equal length through a chain of
+//! dictionary words where each step changes exactly one letter (e.g.
+//! "cold" → "cord" → "card" → "ward" → "warm"). The adjacency index is kept
+//! per language and per word length, built from the words without content
+//! flags. Administrators changing the dictionary drop the index of the
+//! language, which is rebuilt on the next ladder request.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{query_scalar, SqlitePool};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, PoisonError};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{AppError, LadderError};
+use crate::models::language::Language;
+
+/// Smallest ladder that can be requested (start and end words only)
+pub const MIN_LADDER_LENGTH: usize = 2;
+
+/// Largest ladder that can be requested, keeps puzzle generation bounded
+pub const MAX_LADDER_LENGTH: usize = 12;
+
+/// How many random starting words are tried before giving up on a puzzle
+const PUZZLE_ATTEMPTS: usize = 64;
+
+/// One-letter-change graph for all dictionary words of the same length
+///
+/// Words are stored once and referenced by index. Neighbours are found by
+/// grouping words on "wildcard" patterns (e.g. `c_ld`), so building the
+/// graph is linear in the number of words times their length.
+#[derive(Debug, Default)]
+pub struct LadderGraph {
+    words: Vec<String>,
+    positions: HashMap<String, usize>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl LadderGraph {
+    /// Builds the adjacency graph for a set of words sharing the same length
+    pub fn new(mut words: Vec<String>) -> Self {
+        words.sort();
+        words.dedup();
+
+        let positions: HashMap<String, usize> = words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (w.clone(), i))
+            .collect();
+
+        // group words by every single-letter wildcard pattern they match
+        let mut buckets: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, word) in words.iter().enumerate() {
+            let chars: Vec<char> = word.chars().collect();
+            for pos in 0..chars.len() {
+                let pattern: String = chars
+                    .iter()
+                    .enumerate()
+                    .map(|(j, c)| if j == pos { '_' } else { *c })
+                    .collect();
+                buckets.entry(pattern).or_default().push(i);
+            }
+        }
+
+        // words sharing a bucket differ by exactly one letter
+        let mut neighbours = vec![Vec::new(); words.len()];
+        for bucket in buckets.values() {
+            for &a in bucket {
+                for &b in bucket {
+                    if a != b {
+                        neighbours[a].push(b);
+                    }
+                }
+            }
+        }
+        for list in neighbours.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Self {
+            words,
+            positions,
+            neighbours,
+        }
+    }
+
+    /// Number of words in the graph
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether the graph holds no words at all
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Whether the word is part of the graph
+    pub fn contains(&self, word: &str) -> bool {
+        self.positions.contains_key(word)
+    }
+
+    /// Breadth-first search returning the predecessor of every reachable word
+    fn search(&self, start: usize) -> Vec<Option<usize>> {
+        let mut previous = vec![None; self.words.len()];
+        let mut visited = vec![false; self.words.len()];
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+
+        while let Some(current) = queue.pop_front() {
+            for &next in &self.neighbours[current] {
+                if !visited[next] {
+                    visited[next] = true;
+                    previous[next] = Some(current);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        previous
+    }
+
+    /// Walks the predecessor chain back from `end` and returns the ladder
+    fn path(&self, previous: &[Option<usize>], end: usize) -> Vec<String> {
+        let mut ladder = vec![self.words[end].clone()];
+        let mut current = end;
+        while let Some(prev) = previous[current] {
+            ladder.push(self.words[prev].clone());
+            current = prev;
+        }
+        ladder.reverse();
+        ladder
+    }
+
+    /// Returns the shortest ladder between two words, if one exists
+    pub fn shortest_ladder(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let start = *self.positions.get(from)?;
+        let end = *self.positions.get(to)?;
+
+        if start == end {
+            return Some(vec![self.words[start].clone()]);
+        }
+
+        let previous = self.search(start);
+        previous[end]?;
+
+        Some(self.path(&previous, end))
+    }
+
+    /// Picks a random ladder made of exactly `length` words
+    ///
+    /// Tries a bounded number of random starting words and, for each,
+    /// collects every word whose shortest ladder has the requested length.
+    /// Using shortest distances guarantees the returned solution is optimal.
+    pub fn random_ladder(&self, length: usize) -> Result<Option<Vec<String>>, AppError> {
+        if self.words.is_empty() || length < MIN_LADDER_LENGTH {
+            return Ok(None);
+        }
+
+        for _ in 0..PUZZLE_ATTEMPTS {
+            let start = random_index(self.words.len())?;
+            if self.neighbours[start].is_empty() {
+                continue;
+            }
+
+            let previous = self.search(start);
+            let candidates: Vec<usize> = (0..self.words.len())
+                .filter(|&end| previous[end].is_some())
+                .filter(|&end| self.distance(&previous, end) + 1 == length)
+                .collect();
+
+            if !candidates.is_empty() {
+                let end = candidates[random_index(candidates.len())?];
+                return Ok(Some(self.path(&previous, end)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Number of steps from the search root to `end`
+    fn distance(&self, previous: &[Option<usize>], end: usize) -> usize {
+        let mut steps = 0;
+        let mut current = end;
+        while let Some(prev) = previous[current] {
+            steps += 1;
+            current = prev;
+        }
+        steps
+    }
+}
+
+/// Picks a random index in `0..len` using the OS random number generator
+fn random_index(len: usize) -> Result<usize, AppError> {
+    let value = getrandom::u64()?;
+    Ok((value % len as u64) as usize)
+}
+
+/// Whether a lemma can take part in a ladder (letters only, no hyphens etc.)
+fn is_ladder_word(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(char::is_alphabetic)
+}
+
+/// Ladder graphs of every language built so far, by word length
+#[derive(Debug, Default)]
+struct LadderCache {
+    graphs: HashMap<String, HashMap<usize, Arc<LadderGraph>>>,
+    /// Bumped on every invalidation, so a build that started before a
+    /// write doesn't cache graphs missing that write
+    generation: u64,
+}
+
+/// Per-language, per-length ladder graphs shared across requests
+///
+/// Graphs are built lazily on first use and dropped by the administrative
+/// handlers after every successful write, so the next ladder request
+/// rebuilds them from the current dictionary.
+#[derive(Debug, Default)]
+pub struct LadderIndex {
+    cache: Mutex<LadderCache>,
+}
+
+impl LadderIndex {
+    /// Creates an empty index, graphs are loaded on demand
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops the ladder graphs of a language, rebuilt on their next use
+    pub fn invalidate(&self, lang: &str) {
+        // a panic while holding the lock can't leave the cache half-written
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.graphs.remove(lang);
+        cache.generation += 1;
+    }
+
+    /// Loads every ladder graph of a language from the database
+    async fn build(
+        dbpool: &SqlitePool,
+        language: &Language,
+    ) -> Result<HashMap<usize, Arc<LadderGraph>>, AppError> {
+        // perform the actual query, checked at compile time, skipping words
+        // in the trash and flagged words
+        let code = language.code();
+        let words = query_scalar!(
+            "SELECT w.word FROM words w WHERE w.language = $1 AND w.deleted_at IS NULL AND NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)",
+            code
+        )
+        .fetch_all(dbpool)
+        .await?;
+
+        let mut by_length: HashMap<usize, Vec<String>> = HashMap::new();
+        for word in words.into_iter().filter(|w| is_ladder_word(w)) {
+            by_length
+                .entry(word.chars().count())
+                .or_default()
+                .push(word);
+        }
+
+        Ok(by_length
+            .into_iter()
+            .map(|(len, words)| (len, Arc::new(LadderGraph::new(words))))
+            .collect())
+    }
+
+    /// Returns all graphs for an enabled language, building them on first use
+    async fn language_graphs(
+        &self,
+        dbpool: &SqlitePool,
+        lang: &str,
+    ) -> Result<HashMap<usize, Arc<LadderGraph>>, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(dbpool, lang).await?;
+
+        let generation = {
+            let cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(graphs) = cache.graphs.get(language.code()) {
+                return Ok(graphs.clone());
+            }
+            cache.generation
+        };
+
+        let graphs = Self::build(dbpool, &language).await?;
+
+        // cached unless the dictionary changed while building
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        if cache.generation == generation {
+            cache
+                .graphs
+                .insert(language.code().to_string(), graphs.clone());
+        }
+
+        Ok(graphs)
+    }
+
+    /// Solves a ladder between two lemmas of the same length
+    pub async fn solve(
+        &self,
+        dbpool: &SqlitePool,
+        lang: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<WordLadder, AppError> {
+        let from = from.to_lowercase();
+        let to = to.to_lowercase();
+
+        if from.chars().count() != to.chars().count() {
+            return Err(LadderError::LengthMismatch(from, to).into());
+        }
+
+        let graphs = self.language_graphs(dbpool, lang).await?;
+        let graph = graphs.get(&from.chars().count());
+
+        for word in [&from, &to] {
+            if !graph.is_some_and(|g| g.contains(word)) {
+                return Err(LadderError::UnknownWord(word.clone()).into());
+            }
+        }
+
+        graph
+            .and_then(|g| g.shortest_ladder(&from, &to))
+            .map(WordLadder::from)
+            .ok_or_else(|| LadderError::NoLadder(from, to).into())
+    }
+
+    /// Generates a random ladder puzzle with the requested number of words
+    pub async fn puzzle(
+        &self,
+        dbpool: &SqlitePool,
+        lang: &str,
+        params: &LadderPuzzleParams,
+    ) -> Result<WordLadder, AppError> {
+        let length = params.length.unwrap_or(4);
+        if !(MIN_LADDER_LENGTH..=MAX_LADDER_LENGTH).contains(&length) {
+            return Err(LadderError::InvalidLength(length).into());
+        }
+
+        let graphs = self.language_graphs(dbpool, lang).await?;
+
+        // either the requested word length or every length we know about
+        let mut candidates: Vec<Arc<LadderGraph>> = match params.letters {
+            Some(letters) => graphs.get(&letters).cloned().into_iter().collect(),
+            None => graphs.values().cloned().collect(),
+        };
+
+        while !candidates.is_empty() {
+            let graph = candidates.swap_remove(random_index(candidates.len())?);
+            if let Some(ladder) = graph.random_ladder(length)? {
+                return Ok(WordLadder::from(ladder));
+            }
+        }
+
+        Err(LadderError::NoPuzzle(length).into())
+    }
+}
+
+/// A solved word ladder returned by the public ladder endpoints
+///
+/// # Fields
+///
+/// - `from`: The starting lemma
+/// - `to`: The target lemma
+/// - `steps`: Number of one-letter changes needed (ladder length minus one)
+/// - `ladder`: Every word on the way, including `from` and `to`
+#[derive(ToSchema, Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WordLadder {
+    pub from: String,
+    pub to: String,
+    pub steps: usize,
+    pub ladder: Vec<String>,
+}
+
+impl From<Vec<String>> for WordLadder {
+    fn from(ladder: Vec<String>) -> Self {
+        Self {
+            from: ladder.first().cloned().unwrap_or_default(),
+            to: ladder.last().cloned().unwrap_or_default(),
+            steps: ladder.len().saturating_sub(1),
+            ladder,
+        }
+    }
+}
+
+/// Query parameters for solving a ladder between two lemmas
+#[derive(Deserialize, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct LadderSolveParams {
+    /// Starting lemma
+    pub from: String,
+    /// Target lemma, must have the same number of letters as `from`
+    pub to: String,
+}
+
+/// Query parameters for generating a random ladder puzzle
+#[derive(Deserialize, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct LadderPuzzleParams {
+    /// Number of words in the ladder, including start and end (2-12, default: 4)
+    pub length: Option<usize>,
+    /// Number of letters per word (default: any)
+    pub letters: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(words: &[&str]) -> LadderGraph {
+        LadderGraph::new(words.iter().map(|w| w.to_string()).collect())
+    }
+
+    #[test]
+    fn test_shortest_ladder() {
+        let g = graph(&["cold", "cord", "card", "ward", "warm", "worm", "word"]);
+
+        let ladder = g.shortest_ladder("cold", "warm").unwrap();
+        assert_eq!(ladder.first().unwrap(), "cold");
+        assert_eq!(ladder.last().unwrap(), "warm");
+        assert_eq!(ladder.len(), 5);
+
+        // every step changes exactly one letter
+        for pair in ladder.windows(2) {
+            let changes = pair[0]
+                .chars()
+                .zip(pair[1].chars())
+                .filter(|(a, b)| a != b)
+                .count();
+            assert_eq!(changes, 1);
+        }
+    }
+
+    #[test]
+    fn test_shortest_ladder_same_word() {
+        let g = graph(&["cold", "cord"]);
+        assert_eq!(g.shortest_ladder("cold", "cold").unwrap(), vec!["cold"]);
+    }
+
+    #[test]
+    fn test_shortest_ladder_unreachable() {
+        let g = graph(&["cold", "cord", "fish"]);
+        assert!(g.shortest_ladder("cold", "fish").is_none());
+        assert!(g.shortest_ladder("cold", "nope").is_none());
+    }
+
+    #[test]
+    fn test_random_ladder_length() {
+        let g = graph(&["cold", "cord", "card", "ward", "warm"]);
+
+        let ladder = g.random_ladder(5).unwrap().unwrap();
+        assert_eq!(ladder.len(), 5);
+
+        let ladder = g.random_ladder(2).unwrap().unwrap();
+        assert_eq!(ladder.len(), 2);
+
+        assert!(g.random_ladder(6).unwrap().is_none());
+        assert!(g.random_ladder(1).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_random_ladder_is_shortest() {
+        // "cat" and "cot" are one step apart even though a longer route exists
+        let g = graph(&["cat", "cot", "cut", "hut", "hot"]);
+
+        for _ in 0..20 {
+            if let Some(ladder) = g.random_ladder(3).unwrap() {
+                let shortest = g
+                    .shortest_ladder(&ladder[0], ladder.last().unwrap())
+                    .unwrap();
+                assert_eq!(shortest.len(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_graph_deduplicates() {
+        let g = graph(&["cold", "cold", "cord"]);
+        assert_eq!(g.len(), 2);
+        assert!(!g.is_empty());
+        assert!(g.contains("cord"));
+    }
+
+    #[test]
+    fn test_is_ladder_word() {
+        assert!(is_ladder_word("cold"));
+        assert!(is_ladder_word("café"));
+        assert!(!is_ladder_word("co-worker"));
+        assert!(!is_ladder_word("don't"));
+        assert!(!is_ladder_word(""));
+    }
+
+    #[test]
+    fn test_invalidate_drops_language() {
+        let index = LadderIndex::new();
+        {
+            let mut cache = index.cache.lock().unwrap();
+            cache.graphs.insert("en".to_string(), HashMap::new());
+            cache.graphs.insert("de".to_string(), HashMap::new());
+        }
+
+        index.invalidate("en");
+
+        let cache = index.cache.lock().unwrap();
+        assert!(!cache.graphs.contains_key("en"));
+        assert!(cache.graphs.contains_key("de"));
+        assert_eq!(cache.generation, 1);
+    }
+
+    #[test]
+    fn test_word_ladder_from_vec() {
+        let ladder = WordLadder::from(vec!["cold".to_string(), "cord".to_string()]);
+        assert_eq!(ladder.from, "cold");
+        assert_eq!(ladder.to, "cord");
+        assert_eq!(ladder.steps, 1);
+    }
+}
@@ -0,0 +1,355 @@
+//! Language registry resolving the `{lang}` path segment
+//!
+//! Languages are stored in the `languages` table with their name, script
+//! and validation profile, so administrators can register new ones at
+//! runtime. Words of every language live in the `words` table, keyed by
+//! language code. Disabled languages are kept with their words but are
+//! not served. Each language can name the grammatical types in its own
+//! words, so that `/de/substantiv` works alongside `/de/noun`.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, SqliteConnection, SqlitePool};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::error::{AppError, LanguageError, PathError};
+use crate::models::profile::{normalize, ValidationProfile};
+use crate::models::word::{is_valid_lemma, GrammaticalType};
+
+/// A language of the registry
+///
+/// # Fields
+///
+/// - `code`: ISO 639 language code used in paths (e.g. "en", "de")
+/// - `name`: Name of the language (e.g. "English")
+/// - `script`: ISO 15924 code of the script the language is written in (e.g. "Latn")
+/// - `validation_profile`: Rules words of the language are validated with (e.g. "latin")
+/// - `enabled`: Whether the language is served, disabled languages keep their words
+/// - `type_names`: Localized names of grammatical types, keyed by type (e.g. "noun": "substantiv")
+/// - `created_at`: Timestamp when the language was registered
+/// - `updated_at`: Timestamp when the language was last modified
+///
+#[derive(ToSchema, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Language {
+    code: String,
+    name: String,
+    script: String,
+    validation_profile: String,
+    enabled: bool,
+    type_names: BTreeMap<String, String>,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+/// A row of the languages table, without the localized type names
+struct LanguageRow {
+    code: String,
+    name: String,
+    script: String,
+    validation_profile: String,
+    enabled: bool,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl From<LanguageRow> for Language {
+    fn from(row: LanguageRow) -> Self {
+        Self {
+            code: row.code,
+            name: row.name,
+            script: row.script,
+            validation_profile: row.validation_profile,
+            enabled: row.enabled,
+            type_names: BTreeMap::new(),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl Language {
+    /// Resolves a `{lang}` path segment to an enabled language
+    ///
+    /// Returns `PathError::InvalidPath` for codes that aren't registered or
+    /// belong to a disabled language.
+    pub async fn resolve(dbpool: &SqlitePool, code: &str) -> Result<Self, AppError> {
+        let language = query_as!(
+            LanguageRow,
+            r#"SELECT code AS "code!", name, script, validation_profile, enabled AS "enabled: bool", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime" FROM languages WHERE code = $1 AND enabled"#,
+            code
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        language
+            .map(Self::from)
+            .ok_or_else(|| PathError::InvalidPath(code.to_string()).into())
+    }
+
+    /// Finds a registered language, enabled or not (admin only)
+    ///
+    /// Lets administrators prepare the words of a language before enabling
+    /// it. Returns `PathError::InvalidPath` for codes that aren't registered.
+    pub async fn find(dbpool: &SqlitePool, code: &str) -> Result<Self, AppError> {
+        let language = query_as!(
+            LanguageRow,
+            r#"SELECT code AS "code!", name, script, validation_profile, enabled AS "enabled: bool", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime" FROM languages WHERE code = $1"#,
+            code
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        language
+            .map(Self::from)
+            .ok_or_else(|| PathError::InvalidPath(code.to_string()).into())
+    }
+
+    /// Language code, as stored in the `language` column of words
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Validation profile the words of the language are checked against
+    pub fn profile(&self) -> ValidationProfile {
+        ValidationProfile::from_str(&self.validation_profile).unwrap_or_default()
+    }
+
+    /// Retrieves all languages, enabled or not, by code (admin only)
+    pub async fn list(dbpool: &SqlitePool) -> Result<Vec<Self>, AppError> {
+        let mut languages: Vec<Self> = query_as!(
+            LanguageRow,
+            r#"SELECT code AS "code!", name, script, validation_profile, enabled AS "enabled: bool", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime" FROM languages ORDER BY code"#
+        )
+        .fetch_all(dbpool)
+        .await?
+        .into_iter()
+        .map(Self::from)
+        .collect();
+
+        let rows = query!("SELECT language, word_type, name FROM type_names")
+            .fetch_all(dbpool)
+            .await?;
+        for row in rows {
+            if let Some(language) = languages.iter_mut().find(|l| l.code == row.language) {
+                language.type_names.insert(row.word_type, row.name);
+            }
+        }
+        Ok(languages)
+    }
+
+    /// Loads the localized type names of the language
+    async fn with_type_names(mut self, dbpool: &SqlitePool) -> Result<Self, AppError> {
+        let rows = query!(
+            "SELECT word_type, name FROM type_names WHERE language = $1",
+            self.code
+        )
+        .fetch_all(dbpool)
+        .await?;
+        self.type_names = rows
+            .into_iter()
+            .map(|row| (row.word_type, row.name))
+            .collect();
+        Ok(self)
+    }
+
+    /// Replaces the localized type names of a language
+    async fn replace_type_names(
+        tx: &mut SqliteConnection,
+        code: &str,
+        type_names: &BTreeMap<String, String>,
+    ) -> Result<(), AppError> {
+        query!("DELETE FROM type_names WHERE language = $1", code)
+            .execute(&mut *tx)
+            .await?;
+
+        for (word_type, name) in type_names {
+            query!(
+                "INSERT INTO type_names (language, word_type, name) VALUES ($1, $2, $3)",
+                code,
+                word_type,
+                name
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Registers a new language (admin only)
+    pub async fn create(
+        dbpool: &SqlitePool,
+        new_language: UpsertLanguage,
+    ) -> Result<Vec<Self>, AppError> {
+        let code = new_language.code()?.to_lowercase();
+        let type_names = new_language.type_names();
+
+        // the language and its type names are stored together or not at all
+        let mut tx = dbpool.begin().await?;
+        let profile = new_language.validation_profile.to_lowercase();
+        let inserted = query_as!(
+            LanguageRow,
+            r#"INSERT INTO languages (code, name, script, validation_profile, enabled) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (code) DO NOTHING RETURNING code AS "code!", name, script, validation_profile, enabled AS "enabled: bool", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime""#,
+            code,
+            new_language.name,
+            new_language.script,
+            profile,
+            new_language.enabled
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(language) = inserted.map(Self::from) else {
+            return Err(LanguageError::AlreadyExists(code).into());
+        };
+        Self::replace_type_names(&mut tx, &code, &type_names).await?;
+        tx.commit().await?;
+
+        Ok(vec![language.with_type_names(dbpool).await?])
+    }
+
+    /// Updates a language, or enables and disables it (admin only)
+    ///
+    /// The code of a language can't change, as its words are keyed by it.
+    pub async fn update(
+        dbpool: &SqlitePool,
+        code: &str,
+        updated_language: UpsertLanguage,
+    ) -> Result<Vec<Self>, AppError> {
+        updated_language.code()?;
+        if !updated_language.code.eq_ignore_ascii_case(code) {
+            return Err(LanguageError::CodeChange(code.to_string()).into());
+        }
+        let type_names = updated_language.type_names();
+
+        let mut tx = dbpool.begin().await?;
+        let profile = updated_language.validation_profile.to_lowercase();
+        let lowercase = code.to_lowercase();
+        let updated = query_as!(
+            LanguageRow,
+            r#"UPDATE languages SET name = $1, script = $2, validation_profile = $3, enabled = $4 WHERE code = $5 RETURNING code AS "code!", name, script, validation_profile, enabled AS "enabled: bool", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime""#,
+            updated_language.name,
+            updated_language.script,
+            profile,
+            updated_language.enabled,
+            lowercase
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(language) = updated.map(Self::from) else {
+            return Err(LanguageError::UnknownLanguage(code.to_string()).into());
+        };
+        Self::replace_type_names(&mut tx, &language.code, &type_names).await?;
+        tx.commit().await?;
+
+        Ok(vec![language.with_type_names(dbpool).await?])
+    }
+}
+
+/// Data transfer object for registering and updating languages
+///
+/// # Validation Rules
+///
+/// - `code`: Two or three letter ISO 639 code (e.g. "de", "ita"), stored lowercased
+/// - `name`: Up to 50 characters
+/// - `script`: Four letter ISO 15924 script code, capitalized (e.g. "Latn", "Cyrl")
+/// - `validation_profile`: Must be one of the validation profiles (latin, greek, cyrillic, cjk)
+/// - `enabled`: Whether the language is served, `true` if absent
+/// - `type_names`: Optional localized names keyed by grammatical type, each following the
+///   lemma rules. A name can't be given to two types, nor be the English name of another
+///   type. Stored lowercased and replaced as a whole on update.
+#[derive(ToSchema, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertLanguage {
+    #[validate(custom(function = "validate_language_code"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+    #[validate(custom(function = "validate_script"))]
+    pub script: String,
+    #[validate(custom(function = "validate_validation_profile"))]
+    pub validation_profile: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    #[validate(custom(function = "validate_type_names"))]
+    pub type_names: BTreeMap<String, String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Validates an ISO 639 language code of two or three letters
+fn validate_language_code(text: &str) -> Result<(), ValidationError> {
+    let valid = (2..=3).contains(&text.len()) && text.chars().all(|c| c.is_ascii_alphabetic());
+    if !valid {
+        return Err(ValidationError::new("invalid_language_code"));
+    }
+    Ok(())
+}
+
+/// Validates an ISO 15924 script code such as "Latn" or "Grek"
+fn validate_script(text: &str) -> Result<(), ValidationError> {
+    let mut chars = text.chars();
+    let valid = text.len() == 4
+        && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_lowercase());
+    if !valid {
+        return Err(ValidationError::new("invalid_script"));
+    }
+    Ok(())
+}
+
+/// Validates a validation_profile field for allowed profiles
+fn validate_validation_profile(text: &str) -> Result<(), ValidationError> {
+    let _ = ValidationProfile::from_str(&text.to_lowercase())
+        .map_err(|_| ValidationError::new("invalid_validation_profile"))?;
+    Ok(())
+}
+
+/// Validates localized type names for known types, valid and unambiguous names
+///
+/// A name is resolved like the English name of a type in the `/{lang}/{type}`
+/// path, so it can't stand for two types or shadow another type.
+fn validate_type_names(names: &BTreeMap<String, String>) -> Result<(), ValidationError> {
+    if names
+        .keys()
+        .any(|t| GrammaticalType::from_str(&t.to_lowercase()).is_err())
+    {
+        return Err(ValidationError::new("invalid_word_type"));
+    }
+    if !names.values().all(|n| is_valid_lemma(n)) {
+        return Err(ValidationError::new("invalid_type_name"));
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for (word_type, name) in names {
+        let name = normalize(&name.to_lowercase());
+        let shadows = GrammaticalType::from_str(&name).is_ok() && name != word_type.to_lowercase();
+        if shadows || !seen.insert(name) {
+            return Err(ValidationError::new("ambiguous_type_name"));
+        }
+    }
+    Ok(())
+}
+
+impl UpsertLanguage {
+    /// Returns the localized type names, lowercased and normalized to NFC
+    fn type_names(&self) -> BTreeMap<String, String> {
+        self.type_names
+            .iter()
+            .map(|(t, n)| (t.to_lowercase(), normalize(&n.to_lowercase())))
+            .collect()
+    }
+
+    /// Returns the code field after validation
+    pub fn code(&self) -> Result<&str, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.code.as_ref()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Paging through the words of a language
+//!
+//! The admin word list is read one page at a time. Pages are sorted by
+//! lemma, creation or last change time and follow each other through an
+//! opaque cursor naming the last word of the previous page, so pages stay
+//! consistent while words are added or deleted, unlike offsets. Words can
+//! be narrowed down by type, by text in the lemma and by creation or change
+//! time ranges.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Deserialize;
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::IntoParams;
+
+use crate::error::QueryError;
+use crate::models::word::Word;
+
+/// Default number of words in a page
+const DEFAULT_PAGE_LIMIT: u32 = 50;
+
+/// Largest number of words that can be requested in a page
+const MAX_PAGE_LIMIT: u32 = 500;
+
+/// Fields the word list can be sorted by
+///
+/// # Supported Fields
+/// - `word` - Alphabetical order of the lemmas (default)
+/// - `created_at` - Time the words were created
+/// - `updated_at` - Time the words were last changed, or created if never changed
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum SortField {
+    #[default]
+    #[strum(serialize = "word")]
+    Word,
+    #[strum(serialize = "created_at")]
+    CreatedAt,
+    #[strum(serialize = "updated_at")]
+    UpdatedAt,
+}
+
+impl SortField {
+    pub fn field_name(&self) -> &str {
+        match self {
+            SortField::Word => "word",
+            SortField::CreatedAt => "created_at",
+            SortField::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+/// Directions the word list can be sorted in
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum SortOrder {
+    #[default]
+    #[strum(serialize = "asc")]
+    Asc,
+    #[strum(serialize = "desc")]
+    Desc,
+}
+
+impl SortOrder {
+    pub fn order_name(&self) -> &str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// Position after the last word of a page
+///
+/// Holds the sort of the list it was made for, so that it can't be used to
+/// page through a list sorted another way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub sort: SortField,
+    pub order: SortOrder,
+    /// Sort key of the last word, as compared by the database
+    pub key: String,
+    /// ID of the last word, breaking ties between equal sort keys
+    pub id: u32,
+}
+
+impl Cursor {
+    /// Encodes the cursor as an opaque and URL-safe token
+    pub fn encode(&self) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.sort.field_name(),
+            self.order.order_name(),
+            self.id,
+            self.key
+        )
+        .bytes()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+    }
+
+    /// Decodes a token made by `encode`, `None` if it was tampered with
+    pub fn decode(token: &str) -> Option<Self> {
+        if !token.len().is_multiple_of(2) {
+            return None;
+        }
+        let bytes = (0..token.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(token.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        let text = String::from_utf8(bytes).ok()?;
+
+        let mut parts = text.splitn(4, ':');
+        Some(Self {
+            sort: SortField::from_str(parts.next()?).ok()?,
+            order: SortOrder::from_str(parts.next()?).ok()?,
+            id: parts.next()?.parse().ok()?,
+            key: parts.next()?.to_string(),
+        })
+    }
+}
+
+/// Query parameters of the admin word list
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ListParams {
+    /// Maximum number of words in the page (1-500, default: 50)
+    pub limit: Option<u32>,
+    /// Cursor of the page, from the `next` link of the previous page
+    pub cursor: Option<String>,
+    /// Field to sort by: 'word' (default), 'created_at' or 'updated_at'
+    pub sort: Option<String>,
+    /// Direction to sort in: 'asc' (default) or 'desc'
+    pub order: Option<String>,
+    /// Only list words of this grammatical type (e.g. 'noun')
+    pub word_type: Option<String>,
+    /// Only list words whose lemma contains this text (e.g. 'ing')
+    pub contains: Option<String>,
+    /// Only list words created after this date or time (e.g. '2025-01-31')
+    pub created_after: Option<String>,
+    /// Only list words created before this date or time (e.g. '2025-01-31T12:00:00')
+    pub created_before: Option<String>,
+    /// Only list words changed after this date or time
+    pub updated_after: Option<String>,
+    /// Only list words changed before this date or time
+    pub updated_before: Option<String>,
+}
+
+impl ListParams {
+    /// Returns the page size, clamped to the allowed range
+    pub fn limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_LIMIT)
+            .clamp(1, MAX_PAGE_LIMIT)
+    }
+
+    /// Returns the sort field, `word` if absent
+    pub fn sort(&self) -> Result<SortField, QueryError> {
+        match &self.sort {
+            Some(sort) => SortField::from_str(&sort.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("sort".to_string(), sort.clone())),
+            None => Ok(SortField::default()),
+        }
+    }
+
+    /// Returns the sort direction, `asc` if absent
+    pub fn order(&self) -> Result<SortOrder, QueryError> {
+        match &self.order {
+            Some(order) => SortOrder::from_str(&order.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("order".to_string(), order.clone())),
+            None => Ok(SortOrder::default()),
+        }
+    }
+
+    /// Returns the decoded cursor after checking it was made for this sort
+    pub fn cursor(&self) -> Result<Option<Cursor>, QueryError> {
+        let Some(token) = &self.cursor else {
+            return Ok(None);
+        };
+
+        match Cursor::decode(token) {
+            Some(cursor) if cursor.sort == self.sort()? && cursor.order == self.order()? => {
+                Ok(Some(cursor))
+            }
+            _ => Err(QueryError::InvalidParameter(
+                "cursor".to_string(),
+                token.clone(),
+            )),
+        }
+    }
+
+    /// Returns the lowercased text lemmas must contain
+    pub fn contains(&self) -> Option<String> {
+        self.contains.as_ref().map(|text| text.to_lowercase())
+    }
+
+    /// Returns the creation and change time bounds, as stored in the database
+    ///
+    /// Bounds are exclusive. Returns `QueryError::InvalidParameter` for
+    /// values that aren't a date (`YYYY-MM-DD`) or a date and time.
+    pub fn time_bounds(&self) -> Result<[Option<String>; 4], QueryError> {
+        Ok([
+            parse_bound("created_after", self.created_after.as_deref())?,
+            parse_bound("created_before", self.created_before.as_deref())?,
+            parse_bound("updated_after", self.updated_after.as_deref())?,
+            parse_bound("updated_before", self.updated_before.as_deref())?,
+        ])
+    }
+}
+
+/// Parses a time bound, a bare date standing for its midnight
+fn parse_bound(parameter: &str, value: Option<&str>) -> Result<Option<String>, QueryError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    let time = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| QueryError::InvalidParameter(parameter.to_string(), value.to_string()))?;
+
+    // the format of DATETIME('NOW', 'subsec')
+    Ok(Some(time.format("%Y-%m-%d %H:%M:%S%.3f").to_string()))
+}
+
+/// One page of the admin word list
+pub struct WordPage {
+    /// Words of the page, in the requested order
+    pub words: Vec<Word>,
+    /// Number of words matching the filters, in every page
+    pub total: i64,
+    /// Cursor of the next page, `None` on the last page
+    pub next: Option<Cursor>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor {
+            sort: SortField::UpdatedAt,
+            order: SortOrder::Desc,
+            key: "2025-01-31 12:00:00.000".to_string(),
+            id: 42,
+        };
+        let token = cursor.encode();
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(Cursor::decode(&token), Some(cursor));
+
+        // lemmas may contain the separator
+        let cursor = Cursor {
+            sort: SortField::Word,
+            order: SortOrder::Asc,
+            key: "a:b".to_string(),
+            id: 1,
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+
+        assert_eq!(Cursor::decode("xyz"), None);
+        assert_eq!(Cursor::decode("6162"), None);
+    }
+
+    #[test]
+    fn test_list_params() {
+        let params = ListParams {
+            limit: Some(10_000),
+            sort: Some("Created_At".to_string()),
+            order: Some("desc".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.limit(), MAX_PAGE_LIMIT);
+        assert_eq!(params.sort().unwrap(), SortField::CreatedAt);
+        assert_eq!(params.order().unwrap(), SortOrder::Desc);
+        assert_eq!(ListParams::default().limit(), DEFAULT_PAGE_LIMIT);
+
+        // a cursor only pages through the sort it was made for
+        let token = Cursor {
+            sort: SortField::Word,
+            order: SortOrder::Asc,
+            key: "cat".to_string(),
+            id: 1,
+        }
+        .encode();
+        let params = |sort: &str| ListParams {
+            cursor: Some(token.clone()),
+            sort: Some(sort.to_string()),
+            ..Default::default()
+        };
+        assert!(params("word").cursor().unwrap().is_some());
+        assert!(params("created_at").cursor().is_err());
+
+        let params = ListParams {
+            sort: Some("id".to_string()),
+            ..Default::default()
+        };
+        assert!(params.sort().is_err());
+    }
+
+    #[test]
+    fn test_time_bounds() {
+        let params = ListParams {
+            created_after: Some("2025-01-31".to_string()),
+            updated_before: Some("2025-01-31T12:30:00".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            params.time_bounds().unwrap(),
+            [
+                Some("2025-01-31 00:00:00.000".to_string()),
+                None,
+                None,
+                Some("2025-01-31 12:30:00.000".to_string()),
+            ]
+        );
+
+        let params = ListParams {
+            created_before: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        assert!(params.time_bounds().is_err());
+    }
+}
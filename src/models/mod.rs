@@ -3,6 +3,46 @@
 //! # Modules
 //! - `word`: Word data structure with validation and database operations
 //! - `user`: User authentication and authorization data structures
+//! - `category`: Grammatical sub-categories (proper noun, transitive verb, ...)
+//! - `challenge`: Daily challenge, submissions and leaderboard
+//! - `export`: Streaming bulk export of words as CSV, NDJSON or JSON
+//! - `flag`: Content safety flags and safe mode
+//! - `homophone`: Words sharing a pronunciation
+//! - `import`: Bulk word import from CSV, JSON or NDJSON with a per-row report
+//! - `inflection`: Inflected forms of words and lookup by inflection
+//! - `language`: Language registry resolving the `{lang}` path segment
+//! - `listing`: Cursor pagination, sorting and filters of the admin word list
+//! - `ladder`: Word ladder adjacency index, solver and puzzle generator
+//! - `profile`: Per-language validation profiles for lemmas, definitions and IPA
+//! - `proposal`: Words and changes proposed by editors, reviewed by admins
+//! - `pronunciation`: IPA conversion to respelling, ARPAbet and X-SAMPA
+//! - `report`: Problem reports of words and their triage by admins
+//! - `revision`: Revision history of words, audit trail and revert
+//! - `relation`: Typed relations between words (synonyms, antonyms, ...)
+//! - `tag`: Thematic tags and tag-filtered random draws
+//! - `translation`: Translation links between words of different languages
+//! - `trash`: Trash of deleted words, restore and purge after the retention
+//! - `version`: Word versions, entity tags and conditional requests
 
+pub mod category;
+pub mod challenge;
+pub mod export;
+pub mod flag;
+pub mod homophone;
+pub mod import;
+pub mod inflection;
+pub mod ladder;
+pub mod language;
+pub mod listing;
+pub mod profile;
+pub mod pronunciation;
+pub mod proposal;
+pub mod relation;
+pub mod report;
+pub mod revision;
+pub mod tag;
+pub mod translation;
+pub mod trash;
 pub mod user;
+pub mod version;
 pub mod word;
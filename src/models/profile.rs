@@ -0,0 +1,243 @@
+//! Per-language validation profiles for lemmas, definitions and IPA
+//!
+//! Every registered language names the profile its words are checked
+//! against: the scripts its lemmas and definitions are written in, and the
+//! phoneme inventory its pronunciations are transcribed with. Text is
+//! normalized to Unicode NFC before being checked and stored, so that a
+//! word typed with precomposed or combining accents is stored only once.
+
+use regex::Regex;
+use std::sync::OnceLock;
+use strum_macros::EnumString;
+use unicode_normalization::UnicodeNormalization;
+
+/// Validation profiles checking the lemmas, definitions and pronunciations of a language
+///
+/// # Supported Profiles
+/// - `latin` - Latin script with diacritics (e.g. English, German, Italian)
+/// - `greek` - Greek script (e.g. Modern Greek)
+/// - `cyrillic` - Cyrillic script (e.g. Russian, Ukrainian, Bulgarian)
+/// - `cjk` - Han, kana and Hangul scripts (e.g. Mandarin, Japanese, Korean)
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum ValidationProfile {
+    #[default]
+    #[strum(serialize = "latin")]
+    Latin,
+    #[strum(serialize = "greek")]
+    Greek,
+    #[strum(serialize = "cyrillic")]
+    Cyrillic,
+    #[strum(serialize = "cjk")]
+    Cjk,
+}
+
+/// Stress, length, syllable and linking marks, tie bars and modifier
+/// letters, allowed in the pronunciations of every profile
+const IPA_MARKS: &str = "ˈˌːˑ.‿\u{0361}\u{035C}ʰʷʲˠˤⁿˡᵊᵛᵚᵏ";
+
+/// Punctuation allowed in the definitions and examples of every profile
+const PUNCTUATION: &str = r#".,;:!?()'"\-‘’“”"#;
+
+/// Characters a profile doesn't allow, as negated character classes
+struct Rules {
+    lemma: Regex,
+    text: Regex,
+    pronunciation: Regex,
+}
+
+impl ValidationProfile {
+    /// Every profile, in the order they are listed above
+    pub const ALL: [Self; 4] = [Self::Latin, Self::Greek, Self::Cyrillic, Self::Cjk];
+
+    pub fn profile_name(&self) -> &str {
+        match self {
+            ValidationProfile::Latin => "latin",
+            ValidationProfile::Greek => "greek",
+            ValidationProfile::Cyrillic => "cyrillic",
+            ValidationProfile::Cjk => "cjk",
+        }
+    }
+
+    /// Letters of the scripts of the profile, as a regex class body
+    fn letters(&self) -> &'static str {
+        match self {
+            ValidationProfile::Latin => r"\p{Latin}",
+            ValidationProfile::Greek => r"\p{Greek}",
+            ValidationProfile::Cyrillic => r"\p{Cyrillic}",
+            ValidationProfile::Cjk => r"\p{Han}\p{Hiragana}\p{Katakana}\p{Hangul}ー",
+        }
+    }
+
+    /// Punctuation of the profile's languages besides the common one
+    fn punctuation(&self) -> &'static str {
+        match self {
+            ValidationProfile::Latin | ValidationProfile::Cyrillic => "«»„",
+            ValidationProfile::Greek => "«»·",
+            ValidationProfile::Cjk => "。、，！？：；「」『』（）・　",
+        }
+    }
+
+    /// Phoneme inventory of the profile's languages, as IPA symbols
+    ///
+    /// Both the IPA `ɡ` and the plain `g` are accepted, the latter being
+    /// common in dictionaries.
+    fn phonemes(&self) -> &'static str {
+        match self {
+            ValidationProfile::Latin => {
+                "abcdefghijklmnopqrstuvwxyzæçðøŋœɐɑɒɔɕɘəɚɛɜɝɞɟɡɣɤɥɨɪɫɬɭɯɰɱɲɳɴɵɸɹɺɻɽɾʀʁʂʃʈʉʊʋʌʎʏʐʑʒʔʝʟβθχʧʤʦʣ"
+            }
+            ValidationProfile::Greek => "abcdefgijklmnoprstuvxzçðŋɟɡɣɲɱʎʝθɾʦʣ",
+            ValidationProfile::Cyrillic => {
+                "abdefgijklmnoprstuvxzæɐɕəɛɡɣɨɪɫɵɾʂʃʉʊʐʑʒʔʲʦʧ"
+            }
+            ValidationProfile::Cjk => {
+                "abcdefghijklmnopqrstuvwxyzæçŋɐɑɔɕəɛɤɥɨɪɯɰɲɴɸɹɻɾʂʃʈʊʌʐʑʒʔʝβʦʨʥ˥˦˧˨˩¹²³⁴⁵"
+            }
+        }
+    }
+
+    /// Compiles the rules of the profile once, on first use
+    fn rules(&self) -> &'static Rules {
+        static RULES: [OnceLock<Rules>; 4] = [const { OnceLock::new() }; 4];
+
+        RULES[*self as usize].get_or_init(|| {
+            let letters = self.letters();
+            let punctuation = self.punctuation();
+            let phonemes = self.phonemes();
+
+            // lemmas: letters, combining marks, digits, hyphens, apostrophes and periods
+            // text: lemma letters, Latin letters, digits, whitespace and punctuation
+            // pronunciations: phonemes, combining diacritics and prosodic marks
+            Rules {
+                lemma: Regex::new(&format!(r"[^{letters}\p{{M}}0-9\-'.]")).unwrap(),
+                text: Regex::new(&format!(
+                    r"[^{letters}\p{{Latin}}\p{{M}}0-9\s{PUNCTUATION}{punctuation}]"
+                ))
+                .unwrap(),
+                pronunciation: Regex::new(&format!(r"[^{phonemes}{IPA_MARKS}\p{{M}}]")).unwrap(),
+            }
+        })
+    }
+
+    /// Returns the first character of a lemma the profile doesn't allow
+    pub fn lemma_error(&self, lemma: &str) -> Option<char> {
+        first_match(&self.rules().lemma, lemma)
+    }
+
+    /// Returns the first character of a definition, example sentence or
+    /// usage note the profile doesn't allow
+    pub fn text_error(&self, text: &str) -> Option<char> {
+        first_match(&self.rules().text, text)
+    }
+
+    /// Returns the first character of an IPA transcription outside of the
+    /// phoneme inventory of the profile, ignoring the enclosing slashes
+    ///
+    /// Uppercase letters are checked as the lowercase ones they are stored as.
+    pub fn pronunciation_error(&self, pronunciation: &str) -> Option<char> {
+        let ipa = pronunciation
+            .strip_prefix('/')
+            .and_then(|p| p.strip_suffix('/'))
+            .unwrap_or(pronunciation);
+        first_match(&self.rules().pronunciation, &ipa.to_lowercase())
+    }
+}
+
+/// Returns the first character matched by a single character class
+fn first_match(regex: &Regex, text: &str) -> Option<char> {
+    regex.find(text).and_then(|m| m.as_str().chars().next())
+}
+
+/// Normalizes text to Unicode Normalization Form C (precomposed characters)
+pub fn normalize(text: &str) -> String {
+    text.nfc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_profile_from_str() {
+        for profile in ValidationProfile::ALL {
+            assert_eq!(
+                ValidationProfile::from_str(profile.profile_name()).unwrap(),
+                profile
+            );
+        }
+        assert!(ValidationProfile::from_str("klingon").is_err());
+    }
+
+    #[test]
+    fn test_lemma_scripts() {
+        assert_eq!(ValidationProfile::Latin.lemma_error("straße"), None);
+        assert_eq!(ValidationProfile::Latin.lemma_error("perché"), None);
+        assert_eq!(ValidationProfile::Greek.lemma_error("λόγος"), None);
+        assert_eq!(ValidationProfile::Cyrillic.lemma_error("слово"), None);
+        assert_eq!(ValidationProfile::Cjk.lemma_error("言葉"), None);
+        assert_eq!(ValidationProfile::Cjk.lemma_error("ことば"), None);
+        assert_eq!(ValidationProfile::Cjk.lemma_error("단어"), None);
+
+        // the failing character is reported, scripts are not mixed
+        assert_eq!(ValidationProfile::Latin.lemma_error("λόγος"), Some('λ'));
+        assert_eq!(ValidationProfile::Greek.lemma_error("logos"), Some('l'));
+        assert_eq!(ValidationProfile::Cyrillic.lemma_error("сл0во!"), Some('!'));
+        assert_eq!(ValidationProfile::Latin.lemma_error("two words"), Some(' '));
+    }
+
+    #[test]
+    fn test_text_scripts() {
+        assert_eq!(
+            ValidationProfile::Greek.text_error("μια λέξη (DNA), «ναι»"),
+            None
+        );
+        assert_eq!(ValidationProfile::Cjk.text_error("言葉の意味。"), None);
+        assert_eq!(ValidationProfile::Latin.text_error("costs $50"), Some('$'));
+        assert_eq!(ValidationProfile::Latin.text_error("a word。"), Some('。'));
+        assert_eq!(ValidationProfile::Cyrillic.text_error("слово и word"), None);
+    }
+
+    #[test]
+    fn test_phoneme_inventories() {
+        assert_eq!(
+            ValidationProfile::Latin.pronunciation_error("/ˈɡʊtn̩/"),
+            None
+        );
+        assert_eq!(
+            ValidationProfile::Latin.pronunciation_error("/ˈhaʊ̯zɐ/"),
+            None
+        );
+        assert_eq!(
+            ValidationProfile::Latin.pronunciation_error("/ˈt͡ʃao/"),
+            None
+        );
+        assert_eq!(ValidationProfile::Latin.pronunciation_error("/TEST/"), None);
+        assert_eq!(
+            ValidationProfile::Cyrillic.pronunciation_error("/ˈslovə/"),
+            None
+        );
+        assert_eq!(ValidationProfile::Cjk.pronunciation_error("/ʨʰi˥˩/"), None);
+
+        assert_eq!(
+            ValidationProfile::Greek.pronunciation_error("/ˈhaʊs/"),
+            Some('h')
+        );
+        assert_eq!(
+            ValidationProfile::Latin.pronunciation_error("/ti˥/"),
+            Some('˥')
+        );
+        assert_eq!(
+            ValidationProfile::Latin.pronunciation_error("/te@st/"),
+            Some('@')
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        // "e" followed by a combining acute accent becomes a single "é"
+        assert_eq!(normalize("cafe\u{0301}"), "café");
+        assert_eq!(normalize("cafe\u{0301}").chars().count(), 4);
+        assert_eq!(normalize("café"), "café");
+    }
+}
@@ -0,0 +1,458 @@
+//! Pronunciation notations derived from IPA transcriptions
+//!
+//! IPA is precise but unreadable for most children and parents. This module
+//! converts the stored IPA `pronunciation` into a kid-friendly respelling
+//! (e.g. `/əˈbeɪt/` → `uh-BAYT`), ARPAbet and X-SAMPA.
+//!
+//! Conversion tables are data-driven: each language has a `<code>.toml` file
+//! in the configured `pronunciation_dir` (`data/pronunciation/` by default)
+//! mapping IPA symbols to their equivalents. The files are loaded once at
+//! startup, so adding a language means dropping its file in the directory
+//! and restarting the server.
+
+use anyhow::Context;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::QueryError;
+
+/// Pronunciation notations that can be requested on word endpoints
+///
+/// # Supported Formats
+/// - `ipa` - International Phonetic Alphabet, always returned as `pronunciation`
+/// - `respelling` - Kid-friendly respelling with the stressed syllable in capitals
+/// - `arpabet` - ARPAbet phonemes with stress digits, as used by speech tools
+/// - `xsampa` - X-SAMPA, an ASCII-only transcription of IPA
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum PronunciationFormat {
+    #[strum(serialize = "ipa")]
+    Ipa,
+    #[strum(serialize = "respelling")]
+    Respelling,
+    #[strum(serialize = "arpabet")]
+    Arpabet,
+    #[strum(serialize = "xsampa")]
+    XSampa,
+}
+
+impl PronunciationFormat {
+    /// Parses a comma-separated list of formats (e.g. `respelling,arpabet`)
+    pub fn parse_list(value: &str) -> Result<Vec<Self>, QueryError> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .map(|f| {
+                Self::from_str(f).map_err(|_| {
+                    QueryError::InvalidParameter("pronunciation_format".to_string(), f.to_string())
+                })
+            })
+            .collect()
+    }
+}
+
+/// Query parameters selecting the pronunciation variant and extra notations
+#[derive(Deserialize, IntoParams, ToSchema, Default)]
+#[into_params(parameter_in = Query)]
+pub struct PronunciationParams {
+    /// Comma-separated extra notations: `respelling`, `arpabet`, `xsampa` (`ipa` is always included)
+    pub pronunciation_format: Option<String>,
+    /// Preferred pronunciation variant (e.g. `us`, `uk`), falls back to the primary pronunciation
+    pub variant: Option<String>,
+}
+
+impl PronunciationParams {
+    /// Returns the requested formats, or none if the parameter is absent
+    pub fn formats(&self) -> Result<Vec<PronunciationFormat>, QueryError> {
+        match &self.pronunciation_format {
+            Some(value) => PronunciationFormat::parse_list(value),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the preferred variant label, lowercased like stored labels
+    pub fn variant(&self) -> Option<String> {
+        self.variant.as_deref().map(str::to_lowercase)
+    }
+}
+
+/// Role of an IPA symbol in the conversion tables
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum SymbolKind {
+    Vowel,
+    Consonant,
+    PrimaryStress,
+    SecondaryStress,
+    Modifier,
+}
+
+/// Equivalents of a single IPA symbol in every supported notation
+#[derive(Debug, Deserialize)]
+struct Symbol {
+    kind: SymbolKind,
+    respelling: Option<String>,
+    arpabet: Option<String>,
+    xsampa: Option<String>,
+}
+
+/// An IPA symbol found in a transcription, with its table entry if known
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    ipa: &'a str,
+    symbol: Option<&'a Symbol>,
+}
+
+impl Token<'_> {
+    fn kind(&self) -> Option<SymbolKind> {
+        self.symbol.map(|s| s.kind)
+    }
+}
+
+/// Stress level of a syllable
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum Stress {
+    #[default]
+    Unstressed,
+    Primary,
+    Secondary,
+}
+
+/// A syllable made of tokens, built from vowel nuclei and stress marks
+#[derive(Debug, Default)]
+struct Syllable<'a> {
+    stress: Stress,
+    tokens: Vec<Token<'a>>,
+    has_vowel: bool,
+}
+
+/// IPA conversion tables for one language
+#[derive(Debug, Deserialize)]
+pub struct PronunciationTables {
+    phonemes: HashMap<String, Symbol>,
+    #[serde(skip)]
+    longest_symbol: usize,
+}
+
+impl PronunciationTables {
+    /// Parses conversion tables from their TOML representation
+    pub fn from_toml(data: &str) -> Result<Self, toml::de::Error> {
+        let mut tables: Self = toml::from_str(data)?;
+        tables.longest_symbol = tables
+            .phonemes
+            .keys()
+            .map(|k| k.chars().count())
+            .max()
+            .unwrap_or(1);
+        Ok(tables)
+    }
+
+    /// Converts an IPA transcription into the requested notation
+    ///
+    /// Returns `None` for `ipa`, which is already part of every response.
+    pub fn convert(&self, ipa: &str, format: PronunciationFormat) -> Option<String> {
+        match format {
+            PronunciationFormat::Ipa => None,
+            PronunciationFormat::Respelling => Some(self.respelling(ipa)),
+            PronunciationFormat::Arpabet => Some(self.arpabet(ipa)),
+            PronunciationFormat::XSampa => Some(self.xsampa(ipa)),
+        }
+    }
+
+    /// Splits a transcription into symbols, longest table match first
+    fn tokenize<'a>(&'a self, ipa: &'a str) -> Vec<Token<'a>> {
+        let text = ipa.trim().trim_matches('/');
+        let boundaries: Vec<usize> = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(text.len()))
+            .collect();
+
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        while start + 1 < boundaries.len() {
+            let longest = self.longest_symbol.min(boundaries.len() - 1 - start);
+            let token = (1..=longest)
+                .rev()
+                .find_map(|len| {
+                    let ipa = &text[boundaries[start]..boundaries[start + len]];
+                    self.phonemes.get_key_value(ipa).map(|(ipa, symbol)| {
+                        (
+                            len,
+                            Token {
+                                ipa: ipa.as_str(),
+                                symbol: Some(symbol),
+                            },
+                        )
+                    })
+                })
+                .unwrap_or((
+                    1,
+                    Token {
+                        ipa: &text[boundaries[start]..boundaries[start + 1]],
+                        symbol: None,
+                    },
+                ));
+
+            tokens.push(token.1);
+            start += token.0;
+        }
+
+        tokens
+    }
+
+    /// Groups tokens into syllables
+    ///
+    /// Stress marks start a new syllable. Between two vowels without a
+    /// stress mark, a single consonant moves to the following syllable.
+    fn syllables<'a>(&'a self, ipa: &'a str) -> Vec<Syllable<'a>> {
+        let mut syllables = Vec::new();
+        let mut current = Syllable::default();
+
+        for token in self.tokenize(ipa) {
+            match token.kind() {
+                Some(SymbolKind::PrimaryStress) | Some(SymbolKind::SecondaryStress) => {
+                    if current.has_vowel {
+                        syllables.push(std::mem::take(&mut current));
+                    }
+                    current.stress = if token.kind() == Some(SymbolKind::PrimaryStress) {
+                        Stress::Primary
+                    } else {
+                        Stress::Secondary
+                    };
+                }
+                Some(SymbolKind::Vowel) => {
+                    if current.has_vowel {
+                        let mut next = Syllable::default();
+                        let ends_with_consonant = current
+                            .tokens
+                            .last()
+                            .is_some_and(|t| t.kind() != Some(SymbolKind::Vowel));
+                        if ends_with_consonant {
+                            next.tokens.extend(current.tokens.pop());
+                        }
+                        syllables.push(std::mem::replace(&mut current, next));
+                    }
+                    current.tokens.push(token);
+                    current.has_vowel = true;
+                }
+                Some(SymbolKind::Modifier) => {}
+                Some(SymbolKind::Consonant) | None => current.tokens.push(token),
+            }
+        }
+
+        if !current.tokens.is_empty() {
+            syllables.push(current);
+        }
+
+        // a lone syllable carries the word stress even when unmarked
+        if syllables.len() == 1 && syllables[0].stress == Stress::Unstressed {
+            syllables[0].stress = Stress::Primary;
+        }
+
+        syllables
+    }
+
+    /// Kid-friendly respelling, syllables joined by hyphens, stress in capitals
+    pub fn respelling(&self, ipa: &str) -> String {
+        let syllables = self.syllables(ipa);
+        let multisyllabic = syllables.len() > 1;
+
+        syllables
+            .iter()
+            .map(|syllable| {
+                let text: String = syllable
+                    .tokens
+                    .iter()
+                    .map(|t| {
+                        t.symbol
+                            .and_then(|s| s.respelling.as_deref())
+                            .unwrap_or(t.ipa)
+                    })
+                    .collect();
+                if multisyllabic && syllable.stress == Stress::Primary {
+                    text.to_uppercase()
+                } else {
+                    text
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// ARPAbet phonemes separated by spaces, vowels carry a stress digit
+    pub fn arpabet(&self, ipa: &str) -> String {
+        self.syllables(ipa)
+            .iter()
+            .flat_map(|syllable| {
+                let digit = match syllable.stress {
+                    Stress::Primary => "1",
+                    Stress::Secondary => "2",
+                    Stress::Unstressed => "0",
+                };
+                syllable.tokens.iter().map(move |t| {
+                    let phone = t
+                        .symbol
+                        .and_then(|s| s.arpabet.clone())
+                        .unwrap_or_else(|| t.ipa.to_uppercase());
+                    if t.kind() == Some(SymbolKind::Vowel) {
+                        format!("{phone}{digit}")
+                    } else {
+                        phone
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// X-SAMPA transcription enclosed in forward slashes, like the IPA input
+    pub fn xsampa(&self, ipa: &str) -> String {
+        let text: String = self
+            .tokenize(ipa)
+            .iter()
+            .map(|t| t.symbol.and_then(|s| s.xsampa.as_deref()).unwrap_or(t.ipa))
+            .collect();
+        format!("/{text}/")
+    }
+}
+
+/// Conversion tables of every language, loaded from the configured directory
+#[derive(Debug, Default)]
+pub struct PronunciationRegistry {
+    tables: HashMap<String, PronunciationTables>,
+}
+
+impl PronunciationRegistry {
+    /// Loads the tables of every `<code>.toml` file in a directory
+    ///
+    /// Fails, naming the file, when the directory or a file can't be read or
+    /// when a file isn't valid conversion tables. Other files are ignored.
+    pub fn load(dir: &Path) -> Result<Self, anyhow::Error> {
+        let entries = std::fs::read_dir(dir)
+            .with_context(|| format!("couldn't read the pronunciation tables in {dir:?}"))?;
+
+        let mut tables = HashMap::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                continue;
+            }
+            let Some(lang) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let data = std::fs::read_to_string(&path)
+                .with_context(|| format!("couldn't read the pronunciation tables {path:?}"))?;
+            let language_tables = PronunciationTables::from_toml(&data)
+                .with_context(|| format!("invalid pronunciation tables {path:?}"))?;
+            tables.insert(lang.to_string(), language_tables);
+        }
+
+        Ok(Self { tables })
+    }
+
+    /// Returns the tables of a language, if it has any
+    pub fn for_language(&self, lang: &str) -> Option<&PronunciationTables> {
+        self.tables.get(lang)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::OnceLock;
+
+    fn english() -> &'static PronunciationTables {
+        static REGISTRY: OnceLock<PronunciationRegistry> = OnceLock::new();
+
+        REGISTRY
+            .get_or_init(|| PronunciationRegistry::load(Path::new("data/pronunciation")).unwrap())
+            .for_language("en")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pronunciation_format_parse_list() {
+        assert_eq!(
+            PronunciationFormat::parse_list("respelling, arpabet").unwrap(),
+            vec![
+                PronunciationFormat::Respelling,
+                PronunciationFormat::Arpabet
+            ]
+        );
+        assert_eq!(
+            PronunciationFormat::parse_list("ipa").unwrap(),
+            vec![PronunciationFormat::Ipa]
+        );
+        assert!(PronunciationFormat::parse_list("").unwrap().is_empty());
+        assert!(PronunciationFormat::parse_list("respelling,klingon").is_err());
+    }
+
+    #[test]
+    fn test_registry_load() {
+        let registry = PronunciationRegistry::load(Path::new("data/pronunciation")).unwrap();
+        assert!(registry.for_language("en").is_some());
+        assert!(registry.for_language("xyz").is_none());
+
+        // invalid tables and missing directories are errors, not panics
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("README.md"), "not tables").unwrap();
+        assert!(PronunciationRegistry::load(dir.path())
+            .unwrap()
+            .for_language("en")
+            .is_none());
+        std::fs::write(dir.path().join("fr.toml"), "phonemes = 3").unwrap();
+        let error = PronunciationRegistry::load(dir.path()).unwrap_err();
+        assert!(format!("{error:#}").contains("fr.toml"));
+        assert!(PronunciationRegistry::load(&dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn test_respelling() {
+        let en = english();
+        assert_eq!(en.respelling("/əˈbeɪt/"), "uh-BAYT");
+        assert_eq!(en.respelling("/ˈhɛloʊ/"), "HE-loh");
+        assert_eq!(en.respelling("/rʌn/"), "run");
+        assert_eq!(en.respelling("/ˈlɪtəl/"), "LI-tuhl");
+        assert_eq!(en.respelling("/kənˈdɪʃən/"), "kuhn-DI-shuhn");
+    }
+
+    #[test]
+    fn test_arpabet() {
+        let en = english();
+        assert_eq!(en.arpabet("/əˈbeɪt/"), "AH0 B EY1 T");
+        assert_eq!(en.arpabet("/rʌn/"), "R AH1 N");
+        assert_eq!(en.arpabet("/ˌæbəˈreɪʃən/"), "AE2 B AH0 R EY1 SH AH0 N");
+    }
+
+    #[test]
+    fn test_xsampa() {
+        let en = english();
+        assert_eq!(en.xsampa("/əˈbeɪt/"), "/@\"beIt/");
+        assert_eq!(en.xsampa("/θɪŋk/"), "/TINk/");
+        assert_eq!(en.xsampa("/ˈtʃɜːtʃ/"), "/\"tS3:tS/");
+    }
+
+    #[test]
+    fn test_unknown_symbols_pass_through() {
+        let en = english();
+        assert_eq!(en.xsampa("/ʔa/"), "/ʔa/");
+        assert_eq!(en.respelling("/ʔa/"), "ʔah");
+    }
+
+    #[test]
+    fn test_convert_ipa_is_noop() {
+        assert!(english()
+            .convert("/əˈbeɪt/", PronunciationFormat::Ipa)
+            .is_none());
+        assert_eq!(
+            english().convert("/əˈbeɪt/", PronunciationFormat::Respelling),
+            Some("uh-BAYT".to_string())
+        );
+    }
+}
@@ -0,0 +1,469 @@
+//! Editorial review of proposed words and changes
+//!
+//! Editors, such as volunteer teachers, don't write to the dictionary
+//! directly. They propose new words and changes to existing ones, which wait
+//! in a pending queue until an admin approves them, rejects them with a
+//! comment, or sends them back asking for changes. A proposal sent back can
+//! be revised and goes back to the queue. Approving applies the proposal
+//! like an admin write, recorded in the history of the word, and a change
+//! only applies to the version of the word it was proposed against.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar, SqlitePool};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::error::{AppError, LookupError, ProposalError, QueryError};
+use crate::models::language::Language;
+use crate::models::revision::RevisionAction;
+use crate::models::user::AuthUser;
+use crate::models::version::IfMatch;
+use crate::models::word::{UpsertWord, Word};
+
+/// Review states of a proposal
+///
+/// # Supported States
+/// - `pending` - Waiting in the queue for an admin (default)
+/// - `approved` - Applied to the dictionary
+/// - `rejected` - Turned down, with a comment
+/// - `changes_requested` - Sent back to the editor, with a comment
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum ProposalStatus {
+    #[default]
+    #[strum(serialize = "pending")]
+    Pending,
+    #[strum(serialize = "approved")]
+    Approved,
+    #[strum(serialize = "rejected")]
+    Rejected,
+    #[strum(serialize = "changes_requested")]
+    ChangesRequested,
+}
+
+impl ProposalStatus {
+    pub fn status_name(&self) -> &str {
+        match self {
+            ProposalStatus::Pending => "pending",
+            ProposalStatus::Approved => "approved",
+            ProposalStatus::Rejected => "rejected",
+            ProposalStatus::ChangesRequested => "changes_requested",
+        }
+    }
+}
+
+/// A new word or a change to a word, proposed by an editor
+///
+/// # Fields
+///
+/// - `id`: Unique identifier of the proposal
+/// - `language`: Code of the language of the word
+/// - `word_id`: ID of the word to change, or of the created word once a new word is approved,
+///   absent again once the word is purged
+/// - `base_version`: Version of the word the change was proposed against, absent for new words
+/// - `word`: The proposed word, with the same fields as a word update
+/// - `status`: Review state (pending, approved, rejected, changes_requested)
+/// - `author`: Name of the editor who proposed it
+/// - `reviewer`: Name of the admin who last reviewed it, if any
+/// - `review_comment`: Comment of the last review, telling the editor why
+/// - `reviewed_at`: Timestamp of the last review
+/// - `created_at`: Timestamp when the proposal was made
+/// - `updated_at`: Timestamp when the proposal was last revised or reviewed
+///
+#[derive(ToSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WordProposal {
+    id: u32,
+    language: String,
+    word_id: Option<u32>,
+    base_version: Option<u32>,
+    word: UpsertWord,
+    status: String,
+    author: String,
+    reviewer: Option<String>,
+    review_comment: Option<String>,
+    reviewed_at: Option<NaiveDateTime>,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+/// A row of the word_proposals table, the proposed word still in JSON
+struct ProposalRow {
+    id: u32,
+    language: String,
+    word_id: Option<u32>,
+    base_version: Option<u32>,
+    proposal: String,
+    status: String,
+    author: String,
+    reviewer: Option<String>,
+    review_comment: Option<String>,
+    reviewed_at: Option<NaiveDateTime>,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<ProposalRow> for WordProposal {
+    type Error = AppError;
+
+    fn try_from(row: ProposalRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            language: row.language,
+            word_id: row.word_id,
+            base_version: row.base_version,
+            word: serde_json::from_str(&row.proposal)?,
+            status: row.status,
+            author: row.author,
+            reviewer: row.reviewer,
+            review_comment: row.review_comment,
+            reviewed_at: row.reviewed_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Query parameters of the review queue
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ProposalParams {
+    /// Only list proposals in this state: 'pending' (default), 'approved', 'rejected' or 'changes_requested'
+    pub status: Option<String>,
+}
+
+impl ProposalParams {
+    /// Returns the review state to list, `pending` if absent
+    pub fn status(&self) -> Result<ProposalStatus, QueryError> {
+        match &self.status {
+            Some(status) => ProposalStatus::from_str(&status.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("status".to_string(), status.clone())),
+            None => Ok(ProposalStatus::default()),
+        }
+    }
+}
+
+/// Comment of an admin rejecting a proposal or requesting changes
+///
+/// # Fields
+///
+/// - `comment`: Why the proposal is turned down or what must change, required
+///
+#[derive(ToSchema, Deserialize, Validate)]
+pub struct ProposalReview {
+    #[validate(length(max = 2000))]
+    pub comment: Option<String>,
+}
+
+impl WordProposal {
+    /// Proposes a new word, or a change to the word `word_id` (editor only)
+    ///
+    /// The proposed word is validated like an admin write, so admins only
+    /// review its content. A change records the current version of the
+    /// word, and returns `LookupError::UnknownWord` when the word isn't in
+    /// the language.
+    pub async fn propose(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: Option<u32>,
+        word: UpsertWord,
+        author: &AuthUser,
+    ) -> Result<Self, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // normalized, written in the scripts of the language and valid
+        let word = word.conform(language.profile())?;
+        word.validate()?;
+        let base_version = match word_id {
+            Some(id) => Some(current_version(dbpool, language.code(), id).await?),
+            None => None,
+        };
+
+        // perform the actual query
+        let code = language.code();
+        let proposal = serde_json::to_string(&word)?;
+        let id = query_scalar!(
+            r#"INSERT INTO word_proposals (language, word_id, base_version, proposal, author_id, author) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id AS "id: u32""#,
+            code,
+            word_id,
+            base_version,
+            proposal,
+            author.id,
+            author.username
+        )
+        .fetch_one(dbpool)
+        .await?;
+
+        Self::read(dbpool, lang, id, Some(author)).await
+    }
+
+    /// Revises a proposal of the editor, sending it back to the queue (editor only)
+    ///
+    /// Pending proposals and proposals sent back for changes can be revised.
+    /// A change is rebased on the current version of the word. Returns
+    /// `ProposalError::Closed` once the proposal is approved or rejected,
+    /// and `LookupError::UnknownWord` for a change to a purged word.
+    pub async fn revise(
+        dbpool: &SqlitePool,
+        lang: &str,
+        id: u32,
+        word: UpsertWord,
+        author: &AuthUser,
+    ) -> Result<Self, AppError> {
+        // if the proposal is one of the editor's
+        let language = Language::find(dbpool, lang).await?;
+        let proposal = Self::read(dbpool, lang, id, Some(author)).await?;
+        if !matches!(
+            ProposalStatus::from_str(&proposal.status),
+            Ok(ProposalStatus::Pending | ProposalStatus::ChangesRequested)
+        ) {
+            return Err(ProposalError::Closed(id).into());
+        }
+
+        // normalized, written in the scripts of the language and valid
+        let word = word.conform(language.profile())?;
+        word.validate()?;
+        let base_version = match (proposal.word_id, proposal.base_version) {
+            (Some(word_id), _) => Some(current_version(dbpool, language.code(), word_id).await?),
+            // a change to a word purged since then
+            (None, Some(_)) => return Err(LookupError::UnknownWord(word.word).into()),
+            (None, None) => None,
+        };
+
+        // perform the actual query, unless reviewed in the meantime
+        let proposal = serde_json::to_string(&word)?;
+        let revised = query!(
+            "UPDATE word_proposals SET proposal = $1, base_version = $2, status = 'pending' WHERE id = $3 AND status IN ('pending', 'changes_requested')",
+            proposal,
+            base_version,
+            id
+        )
+        .execute(dbpool)
+        .await?
+        .rows_affected();
+        if revised == 0 {
+            return Err(ProposalError::Closed(id).into());
+        }
+
+        Self::read(dbpool, lang, id, Some(author)).await
+    }
+
+    /// Retrieves the proposals of a language in a review state, oldest first
+    ///
+    /// Lists every proposal for the review queue, or only those of the
+    /// given editor.
+    pub async fn list(
+        dbpool: &SqlitePool,
+        lang: &str,
+        status: Option<ProposalStatus>,
+        author: Option<&AuthUser>,
+    ) -> Result<Vec<Self>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let code = language.code();
+        let status = status.as_ref().map(ProposalStatus::status_name);
+        let author_id = author.map(|user| user.id);
+        let rows = query_as!(
+            ProposalRow,
+            r#"SELECT id AS "id!: u32", language, word_id AS "word_id: u32", base_version AS "base_version: u32", proposal, status, author, reviewer, review_comment, reviewed_at AS "reviewed_at: NaiveDateTime", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime" FROM word_proposals WHERE language = $1 AND ($2 IS NULL OR status = $2) AND ($3 IS NULL OR author_id = $3) ORDER BY id"#,
+            code,
+            status,
+            author_id
+        )
+        .fetch_all(dbpool)
+        .await?;
+
+        rows.into_iter().map(Self::try_from).collect()
+    }
+
+    /// Retrieves a proposal of a language, if it is one of the given editor's
+    ///
+    /// Returns `ProposalError::UnknownProposal` otherwise.
+    pub async fn read(
+        dbpool: &SqlitePool,
+        lang: &str,
+        id: u32,
+        author: Option<&AuthUser>,
+    ) -> Result<Self, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let code = language.code();
+        let author_id = author.map(|user| user.id);
+        let row = query_as!(
+            ProposalRow,
+            r#"SELECT id AS "id!: u32", language, word_id AS "word_id: u32", base_version AS "base_version: u32", proposal, status, author, reviewer, review_comment, reviewed_at AS "reviewed_at: NaiveDateTime", created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime" FROM word_proposals WHERE id = $1 AND language = $2 AND ($3 IS NULL OR author_id = $3)"#,
+            id,
+            code,
+            author_id
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        row.ok_or(ProposalError::UnknownProposal(id))?.try_into()
+    }
+
+    /// Approves a pending proposal, applying it to the dictionary (admin only)
+    ///
+    /// The word is written and the proposal closed together or not at all,
+    /// and the write is recorded in the history of the word as made by the
+    /// reviewer. A change fails with `PreconditionError::VersionMismatch`
+    /// when the word changed since it was proposed, so it doesn't silently
+    /// overwrite the other change; the editor can then be asked to revise it.
+    /// A change to a word purged since then fails with `LookupError::UnknownWord`.
+    pub async fn approve(
+        dbpool: &SqlitePool,
+        lang: &str,
+        id: u32,
+        reviewer: Option<&AuthUser>,
+    ) -> Result<Self, AppError> {
+        // if the proposal is pending review
+        let language = Language::find(dbpool, lang).await?;
+        let proposal = Self::pending(dbpool, lang, id).await?;
+        let code = language.code();
+
+        // conformed again, the profile of the language may have changed
+        let word = proposal.word.conform(language.profile())?;
+
+        // the word and the review are stored together or not at all
+        let mut tx = dbpool.begin().await?;
+        let stored = match (proposal.word_id, proposal.base_version) {
+            (Some(word_id), _) => {
+                let base = IfMatch::Versions(proposal.base_version.into_iter().collect());
+                Word::check_version(&mut tx, code, word_id, Some(&base)).await?;
+                Word::store_revision(
+                    &mut tx,
+                    code,
+                    Some(word_id),
+                    &word,
+                    RevisionAction::Update,
+                    reviewer,
+                )
+                .await?
+                .ok_or_else(|| LookupError::UnknownWord(format!("#{word_id}")))?
+            }
+            // a change to a word purged since then
+            (None, Some(_)) => return Err(LookupError::UnknownWord(word.word).into()),
+            (None, None) => {
+                Word::store_revision(&mut tx, code, None, &word, RevisionAction::Create, reviewer)
+                    .await?
+                    .ok_or_else(|| LookupError::UnknownWord(word.word.clone()))?
+            }
+        };
+        let reviewer_id = reviewer.map(|user| user.id);
+        let reviewer_name = reviewer.map(|user| user.username.as_str());
+        let approved = query!(
+            "UPDATE word_proposals SET status = 'approved', word_id = $1, reviewer_id = $2, reviewer = $3, review_comment = NULL, reviewed_at = DATETIME('NOW', 'subsec') WHERE id = $4 AND status = 'pending'",
+            stored,
+            reviewer_id,
+            reviewer_name,
+            id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if approved == 0 {
+            return Err(ProposalError::NotPending(id).into());
+        }
+        tx.commit().await?;
+
+        Self::read(dbpool, lang, id, None).await
+    }
+
+    /// Closes a pending proposal without applying it (admin only)
+    ///
+    /// Rejects the proposal for good, or sends it back to the editor for
+    /// changes. Either way the comment is required, and returns
+    /// `ProposalError::MissingComment` when it is absent or blank.
+    pub async fn review(
+        dbpool: &SqlitePool,
+        lang: &str,
+        id: u32,
+        status: ProposalStatus,
+        review: &ProposalReview,
+        reviewer: Option<&AuthUser>,
+    ) -> Result<Self, AppError> {
+        review.validate()?;
+        let Some(comment) = review
+            .comment
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        else {
+            return Err(ProposalError::MissingComment.into());
+        };
+
+        // if the proposal is pending review
+        Self::pending(dbpool, lang, id).await?;
+
+        // perform the actual query, unless reviewed in the meantime
+        let status = status.status_name();
+        let reviewer_id = reviewer.map(|user| user.id);
+        let reviewer_name = reviewer.map(|user| user.username.as_str());
+        let reviewed = query!(
+            "UPDATE word_proposals SET status = $1, reviewer_id = $2, reviewer = $3, review_comment = $4, reviewed_at = DATETIME('NOW', 'subsec') WHERE id = $5 AND status = 'pending'",
+            status,
+            reviewer_id,
+            reviewer_name,
+            comment,
+            id
+        )
+        .execute(dbpool)
+        .await?
+        .rows_affected();
+        if reviewed == 0 {
+            return Err(ProposalError::NotPending(id).into());
+        }
+
+        Self::read(dbpool, lang, id, None).await
+    }
+
+    /// Retrieves a proposal of a language, or `ProposalError::NotPending`
+    async fn pending(dbpool: &SqlitePool, lang: &str, id: u32) -> Result<Self, AppError> {
+        let proposal = Self::read(dbpool, lang, id, None).await?;
+        if proposal.status != ProposalStatus::Pending.status_name() {
+            return Err(ProposalError::NotPending(id).into());
+        }
+        Ok(proposal)
+    }
+}
+
+/// Returns the version of a word, or `LookupError::UnknownWord`
+async fn current_version(dbpool: &SqlitePool, code: &str, id: u32) -> Result<u32, AppError> {
+    let version = query_scalar!(
+        r#"SELECT version AS "version: u32" FROM words WHERE id = $1 AND language = $2 AND deleted_at IS NULL"#,
+        id,
+        code
+    )
+    .fetch_optional(dbpool)
+    .await?;
+
+    version.ok_or_else(|| LookupError::UnknownWord(format!("#{id}")).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proposal_params_status() {
+        let params = |status: &str| ProposalParams {
+            status: Some(status.to_string()),
+        };
+        assert_eq!(
+            params("Changes_Requested").status().unwrap(),
+            ProposalStatus::ChangesRequested
+        );
+        assert_eq!(
+            ProposalParams::default().status().unwrap(),
+            ProposalStatus::Pending
+        );
+        assert!(params("draft").status().is_err());
+    }
+}
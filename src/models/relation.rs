@@ -0,0 +1,300 @@
+//! Typed relations between words for vocabulary lessons
+//!
+//! Relations link two words of the same language by ID. Synonyms and
+//! antonyms are symmetric: one stored relation is found from either word.
+//! Hypernyms and derived forms are directional, from the word to the
+//! related one (e.g. "dog" has the hypernym "animal", "happy" has the
+//! derived form "happiness"). Relations are removed together with either
+//! of their words by `ON DELETE CASCADE` foreign keys.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar, SqlitePool};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::error::{AppError, LookupError, QueryError, RelationError};
+use crate::models::language::Language;
+use crate::models::word::{GetWord, RandomWordRow, Word};
+
+/// Kinds of relation between two words
+///
+/// # Supported Kinds
+/// - `synonym` - Words with the same meaning (symmetric)
+/// - `antonym` - Words with opposite meanings (symmetric)
+/// - `hypernym` - The related word is a broader term (e.g. "dog" → "animal")
+/// - `derived` - The related word is derived from the word (e.g. "happy" → "happiness")
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum RelationKind {
+    #[strum(serialize = "synonym")]
+    Synonym,
+    #[strum(serialize = "antonym")]
+    Antonym,
+    #[strum(serialize = "hypernym")]
+    Hypernym,
+    #[strum(serialize = "derived")]
+    Derived,
+}
+
+impl RelationKind {
+    pub fn kind_name(&self) -> &str {
+        match self {
+            RelationKind::Synonym => "synonym",
+            RelationKind::Antonym => "antonym",
+            RelationKind::Hypernym => "hypernym",
+            RelationKind::Derived => "derived",
+        }
+    }
+
+    /// Whether the relation holds in both directions
+    pub fn is_symmetric(&self) -> bool {
+        matches!(self, RelationKind::Synonym | RelationKind::Antonym)
+    }
+}
+
+/// A relation from a word to another one, as listed to administrators
+///
+/// # Fields
+///
+/// - `id`: Unique identifier for the relation in the database
+/// - `kind`: Kind of relation (synonym, antonym, hypernym, derived)
+/// - `related_id`: Database ID of the related word
+/// - `related_word`: Lemma of the related word
+/// - `created_at`: Timestamp when the relation was added to the database
+///
+#[derive(ToSchema, Deserialize, Serialize, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct WordRelation {
+    id: u32,
+    kind: String,
+    related_id: u32,
+    related_word: String,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl WordRelation {
+    /// Retrieves the relations of a word, symmetric ones from either side (admin only)
+    pub async fn list(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+    ) -> Result<Vec<Self>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        ensure_word(dbpool, &language, word_id).await?;
+
+        // perform the actual query
+        query_as!(
+            Self,
+            r#"SELECT r.id AS "id!: u32", r.kind, w.id AS "related_id!: u32", w.word AS related_word, r.created_at AS "created_at: NaiveDateTime" FROM word_relations r JOIN words w ON w.id = CASE WHEN r.word_id = $1 THEN r.related_id ELSE r.word_id END WHERE (r.word_id = $1 OR (r.related_id = $1 AND r.kind IN ('synonym', 'antonym'))) AND w.deleted_at IS NULL ORDER BY r.kind, w.word"#,
+            word_id
+        )
+        .fetch_all(dbpool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Relates a word to another one (admin only)
+    ///
+    /// Symmetric relations can only be stored once, whichever word they start from.
+    pub async fn create(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        new_relation: UpsertRelation,
+    ) -> Result<Vec<Self>, AppError> {
+        let kind = new_relation.kind()?;
+        let related_id = new_relation.related_id;
+
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        if word_id == related_id {
+            return Err(RelationError::SelfRelation.into());
+        }
+        ensure_word(dbpool, &language, word_id).await?;
+        ensure_word(dbpool, &language, related_id).await?;
+
+        let kind_name = kind.kind_name();
+        let symmetric = kind.is_symmetric();
+        let existing = query_scalar!(
+            "SELECT COUNT(*) FROM word_relations WHERE kind = $1 AND ((word_id = $2 AND related_id = $3) OR ($4 AND word_id = $3 AND related_id = $2))",
+            kind_name,
+            word_id,
+            related_id,
+            symmetric
+        )
+        .fetch_one(dbpool)
+        .await?;
+        if existing > 0 {
+            return Err(RelationError::AlreadyRelated(kind_name.to_string()).into());
+        }
+
+        let id = query_scalar!(
+            r#"INSERT INTO word_relations (word_id, related_id, kind) VALUES ($1, $2, $3) RETURNING id AS "id: u32""#,
+            word_id,
+            related_id,
+            kind_name
+        )
+        .fetch_one(dbpool)
+        .await?;
+
+        let relations = Self::list(dbpool, lang, word_id).await?;
+        Ok(relations.into_iter().filter(|r| r.id == id).collect())
+    }
+
+    /// Deletes a relation of a word (admin only)
+    ///
+    /// Symmetric relations can be deleted from either of their words.
+    pub async fn delete(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        relation_id: u32,
+    ) -> Result<(), AppError> {
+        let relations = Self::list(dbpool, lang, word_id).await?;
+        if !relations.iter().any(|r| r.id == relation_id) {
+            return Err(RelationError::UnknownRelation(relation_id).into());
+        }
+
+        query!("DELETE FROM word_relations WHERE id = $1", relation_id)
+            .execute(dbpool)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Returns `LookupError::UnknownWord` when no word of the language has the given ID
+pub(crate) async fn ensure_word(
+    dbpool: &SqlitePool,
+    language: &Language,
+    word_id: u32,
+) -> Result<(), AppError> {
+    let code = language.code();
+    let count = query_scalar!(
+        "SELECT COUNT(*) FROM words WHERE id = $1 AND language = $2 AND deleted_at IS NULL",
+        word_id,
+        code
+    )
+    .fetch_one(dbpool)
+    .await?;
+    if count == 0 {
+        return Err(LookupError::UnknownWord(format!("#{word_id}")).into());
+    }
+    Ok(())
+}
+
+/// Data transfer object for relating a word to another one
+///
+/// # Validation Rules
+///
+/// - `kind`: Must be one of the relation kinds (synonym, antonym, hypernym, derived)
+/// - `related_id`: Database ID of another word of the same language
+#[derive(ToSchema, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertRelation {
+    #[validate(length(min = 1), custom(function = "validate_relation_kind"))]
+    pub kind: String,
+    pub related_id: u32,
+}
+
+/// Validates a kind field for allowed relation kinds
+fn validate_relation_kind(text: &str) -> Result<(), ValidationError> {
+    let _ = RelationKind::from_str(&text.to_lowercase())
+        .map_err(|_| ValidationError::new("invalid_relation_kind"))?;
+    Ok(())
+}
+
+impl UpsertRelation {
+    /// Returns the relation kind after validation
+    pub fn kind(&self) -> Result<RelationKind, AppError> {
+        match self.validate() {
+            Ok(_) => RelationKind::from_str(&self.kind.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("kind".to_string(), self.kind.clone()))
+                .map_err(Into::into),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Query parameters filtering the related words by kind
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct RelatedParams {
+    /// Relation kind: `synonym`, `antonym`, `hypernym` or `derived`, all kinds if absent
+    pub kind: Option<String>,
+}
+
+impl RelatedParams {
+    /// Returns the requested kind, `None` for all kinds
+    pub fn kind(&self) -> Result<Option<RelationKind>, QueryError> {
+        self.kind
+            .as_ref()
+            .map(|kind| {
+                RelationKind::from_str(&kind.to_lowercase())
+                    .map_err(|_| QueryError::InvalidParameter("kind".to_string(), kind.clone()))
+            })
+            .transpose()
+    }
+}
+
+/// Retrieves the words related to a lemma, grouped by kind in alphabetical order
+///
+/// Symmetric relations are found from either of their words, directional
+/// ones only from the word they start from. Flagged words are left out in
+/// safe mode.
+pub async fn find_related(
+    dbpool: &SqlitePool,
+    lang: &str,
+    lemma: &str,
+    kind: Option<RelationKind>,
+    safe: bool,
+) -> Result<Vec<RelatedWord>, AppError> {
+    // if the language is registered and enabled
+    let language = Language::resolve(dbpool, lang).await?;
+
+    let word_id = Word::find_id(dbpool, &language, lemma).await?;
+
+    // perform the actual query, checked at compile time, each related word
+    // with its primary sense and pronunciation, unflagged in safe mode
+    let kind_name = kind.as_ref().map(RelationKind::kind_name);
+    let related = query!(
+        "SELECT r.kind, w.word, s.definition, p.pronunciation, p.variant FROM word_relations r JOIN words w ON w.id = CASE WHEN r.word_id = $1 THEN r.related_id ELSE r.word_id END JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY position LIMIT 1) WHERE (r.word_id = $1 OR (r.related_id = $1 AND r.kind IN ('synonym', 'antonym'))) AND ($2 IS NULL OR r.kind = $2) AND w.deleted_at IS NULL AND ($3 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) ORDER BY r.kind, w.word",
+        word_id,
+        kind_name,
+        safe
+    )
+    .fetch_all(dbpool)
+    .await?
+    .into_iter()
+    .map(|row| RelatedWord {
+        kind: row.kind,
+        word: RandomWordRow {
+            word: row.word,
+            definition: row.definition,
+            pronunciation: row.pronunciation,
+            variant: row.variant,
+        }
+        .into(),
+    })
+    .collect();
+
+    Ok(related)
+}
+
+/// A related word in public API responses
+///
+/// # Fields
+///
+/// - `kind`: Kind of relation to the looked up lemma
+/// - the fields of [`GetWord`] for the related word
+#[derive(ToSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedWord {
+    pub kind: String,
+    #[serde(flatten)]
+    pub word: GetWord,
+}
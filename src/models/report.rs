@@ -0,0 +1,374 @@
+//! Problem reports of words and their triage
+//!
+//! Anyone can report a problem with a word, such as a wrong definition, a
+//! bad pronunciation or inappropriate content, with an optional note. The
+//! reports stay open in a triage queue, where admins see them one by one or
+//! grouped by word, and close them as resolved once the word is fixed or as
+//! dismissed when there was nothing to fix. Reports go away with their word
+//! once it is purged from the trash.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar, SqlitePool};
+use std::str::FromStr;
+use strum_macros::EnumString;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::error::{AppError, QueryError, ReportError};
+use crate::models::language::Language;
+use crate::models::user::AuthUser;
+use crate::models::word::Word;
+
+/// Kinds of problems a word can be reported for
+///
+/// # Supported Reasons
+/// - `wrong_definition` - A definition is wrong or misleading
+/// - `bad_pronunciation` - The pronunciation is wrong
+/// - `inappropriate` - The word or its content isn't fit for the audience
+/// - `other` - Anything else, explained in the note
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum ReportReason {
+    #[strum(serialize = "wrong_definition")]
+    WrongDefinition,
+    #[strum(serialize = "bad_pronunciation")]
+    BadPronunciation,
+    #[strum(serialize = "inappropriate")]
+    Inappropriate,
+    #[strum(serialize = "other")]
+    Other,
+}
+
+impl ReportReason {
+    pub fn reason_name(&self) -> &str {
+        match self {
+            ReportReason::WrongDefinition => "wrong_definition",
+            ReportReason::BadPronunciation => "bad_pronunciation",
+            ReportReason::Inappropriate => "inappropriate",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+/// Triage states of a report
+///
+/// # Supported States
+/// - `open` - Waiting in the queue for an admin (default)
+/// - `resolved` - The problem was fixed
+/// - `dismissed` - There was nothing to fix
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum ReportStatus {
+    #[default]
+    #[strum(serialize = "open")]
+    Open,
+    #[strum(serialize = "resolved")]
+    Resolved,
+    #[strum(serialize = "dismissed")]
+    Dismissed,
+}
+
+impl ReportStatus {
+    pub fn status_name(&self) -> &str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Resolved => "resolved",
+            ReportStatus::Dismissed => "dismissed",
+        }
+    }
+}
+
+/// A problem reported with a word
+///
+/// # Fields
+///
+/// - `id`: Unique identifier of the report
+/// - `word_id`: ID of the reported word
+/// - `word`: The reported lemma
+/// - `reason`: Kind of problem (wrong_definition, bad_pronunciation, inappropriate, other)
+/// - `note`: Details given by the reporter, if any
+/// - `status`: Triage state (open, resolved, dismissed)
+/// - `resolver`: Name of the admin who closed it, if any
+/// - `resolution_note`: Note of the admin who closed it, if any
+/// - `resolved_at`: Timestamp when the report was closed
+/// - `created_at`: Timestamp when the problem was reported
+///
+#[derive(ToSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WordReport {
+    id: u32,
+    word_id: u32,
+    word: String,
+    reason: String,
+    note: Option<String>,
+    status: String,
+    resolver: Option<String>,
+    resolution_note: Option<String>,
+    resolved_at: Option<NaiveDateTime>,
+    created_at: Option<NaiveDateTime>,
+}
+
+/// The open reports of a word, or the reports in another state, together
+///
+/// # Fields
+///
+/// - `word_id`: ID of the reported word
+/// - `word`: The reported lemma
+/// - `reports`: Number of reports of the word
+/// - `reasons`: Distinct reasons the word was reported for, alphabetically
+/// - `first_reported_at`: Timestamp of the oldest report
+/// - `last_reported_at`: Timestamp of the latest report
+///
+#[derive(ToSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportedWord {
+    word_id: u32,
+    word: String,
+    reports: u32,
+    reasons: Vec<String>,
+    first_reported_at: Option<NaiveDateTime>,
+    last_reported_at: Option<NaiveDateTime>,
+}
+
+/// A problem reported by a client
+///
+/// # Fields
+///
+/// - `reason`: Kind of problem: 'wrong_definition', 'bad_pronunciation', 'inappropriate' or 'other'
+/// - `note`: Optional details, up to 1000 characters
+///
+#[derive(ToSchema, Deserialize, Validate)]
+pub struct NewReport {
+    pub reason: String,
+    #[validate(length(max = 1000))]
+    pub note: Option<String>,
+}
+
+impl NewReport {
+    /// Returns the kind of problem reported
+    pub fn reason(&self) -> Result<ReportReason, QueryError> {
+        ReportReason::from_str(&self.reason.to_lowercase())
+            .map_err(|_| QueryError::InvalidParameter("reason".to_string(), self.reason.clone()))
+    }
+}
+
+/// Query parameters of the triage queue
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ReportParams {
+    /// Only list reports in this state: 'open' (default), 'resolved' or 'dismissed'
+    pub status: Option<String>,
+}
+
+impl ReportParams {
+    /// Returns the triage state to list, `open` if absent
+    pub fn status(&self) -> Result<ReportStatus, QueryError> {
+        match &self.status {
+            Some(status) => ReportStatus::from_str(&status.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter("status".to_string(), status.clone())),
+            None => Ok(ReportStatus::default()),
+        }
+    }
+}
+
+/// Note of an admin closing a report
+///
+/// # Fields
+///
+/// - `note`: Optional note on what was fixed or why there was nothing to fix
+///
+#[derive(ToSchema, Deserialize, Validate)]
+pub struct ReportResolution {
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+impl WordReport {
+    /// Reports a problem with a lemma of a language
+    ///
+    /// Words in the trash can't be reported. Returns
+    /// `LookupError::UnknownWord` when the lemma isn't in the dictionary.
+    pub async fn submit(
+        dbpool: &SqlitePool,
+        lang: &str,
+        lemma: &str,
+        report: &NewReport,
+    ) -> Result<Self, AppError> {
+        report.validate()?;
+        let reason = report.reason()?;
+        let note = report
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty());
+
+        // if the lemma is in the language, registered and enabled
+        let language = Language::resolve(dbpool, lang).await?;
+        let word_id = Word::find_id(dbpool, &language, lemma).await?;
+
+        // perform the actual query
+        let reason = reason.reason_name();
+        let id = query_scalar!(
+            r#"INSERT INTO word_reports (word_id, reason, note) VALUES ($1, $2, $3) RETURNING id AS "id!: u32""#,
+            word_id,
+            reason,
+            note
+        )
+        .fetch_one(dbpool)
+        .await?;
+
+        Self::read(dbpool, lang, id).await
+    }
+
+    /// Retrieves the reports of a language in a triage state, oldest first (admin only)
+    pub async fn list(
+        dbpool: &SqlitePool,
+        lang: &str,
+        status: ReportStatus,
+    ) -> Result<Vec<Self>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let code = language.code();
+        let status = status.status_name();
+        let reports = query_as!(
+            Self,
+            r#"SELECT r.id AS "id!: u32", r.word_id AS "word_id!: u32", w.word AS "word!", r.reason AS "reason!", r.note, r.status AS "status!", r.resolver, r.resolution_note, r.resolved_at AS "resolved_at: NaiveDateTime", r.created_at AS "created_at: NaiveDateTime" FROM word_reports r JOIN words w ON w.id = r.word_id WHERE w.language = $1 AND r.status = $2 ORDER BY r.id"#,
+            code,
+            status
+        )
+        .fetch_all(dbpool)
+        .await?;
+
+        Ok(reports)
+    }
+
+    /// Retrieves the reports of a language in a triage state, grouped by word (admin only)
+    ///
+    /// The most reported words come first, then the most recently reported.
+    pub async fn grouped(
+        dbpool: &SqlitePool,
+        lang: &str,
+        status: ReportStatus,
+    ) -> Result<Vec<ReportedWord>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let code = language.code();
+        let status = status.status_name();
+        let rows = query!(
+            r#"SELECT w.id AS "word_id!: u32", w.word AS "word!", COUNT(*) AS "reports!: u32", GROUP_CONCAT(DISTINCT r.reason) AS "reasons!: String", MIN(r.created_at) AS "first_reported_at: NaiveDateTime", MAX(r.created_at) AS "last_reported_at: NaiveDateTime" FROM word_reports r JOIN words w ON w.id = r.word_id WHERE w.language = $1 AND r.status = $2 GROUP BY w.id ORDER BY COUNT(*) DESC, MAX(r.id) DESC"#,
+            code,
+            status
+        )
+        .fetch_all(dbpool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let mut reasons: Vec<String> = row.reasons.split(',').map(str::to_string).collect();
+                reasons.sort();
+                ReportedWord {
+                    word_id: row.word_id,
+                    word: row.word,
+                    reports: row.reports,
+                    reasons,
+                    first_reported_at: row.first_reported_at,
+                    last_reported_at: row.last_reported_at,
+                }
+            })
+            .collect())
+    }
+
+    /// Retrieves a report of a language (admin only)
+    ///
+    /// Returns `ReportError::UnknownReport` when the report doesn't exist or
+    /// is about a word of another language.
+    pub async fn read(dbpool: &SqlitePool, lang: &str, id: u32) -> Result<Self, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let code = language.code();
+        let report = query_as!(
+            Self,
+            r#"SELECT r.id AS "id!: u32", r.word_id AS "word_id!: u32", w.word AS "word!", r.reason AS "reason!", r.note, r.status AS "status!", r.resolver, r.resolution_note, r.resolved_at AS "resolved_at: NaiveDateTime", r.created_at AS "created_at: NaiveDateTime" FROM word_reports r JOIN words w ON w.id = r.word_id WHERE r.id = $1 AND w.language = $2"#,
+            id,
+            code
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        report.ok_or_else(|| ReportError::UnknownReport(id).into())
+    }
+
+    /// Closes an open report as resolved or dismissed (admin only)
+    ///
+    /// Returns `ReportError::NotOpen` when the report was already closed.
+    pub async fn close(
+        dbpool: &SqlitePool,
+        lang: &str,
+        id: u32,
+        status: ReportStatus,
+        resolution: &ReportResolution,
+        resolver: Option<&AuthUser>,
+    ) -> Result<Self, AppError> {
+        resolution.validate()?;
+        let note = resolution
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty());
+
+        // if the report is open
+        let report = Self::read(dbpool, lang, id).await?;
+        if report.status != ReportStatus::Open.status_name() {
+            return Err(ReportError::NotOpen(id).into());
+        }
+
+        // perform the actual query, unless closed in the meantime
+        let status = status.status_name();
+        let resolver_id = resolver.map(|user| user.id);
+        let resolver_name = resolver.map(|user| user.username.as_str());
+        let closed = query!(
+            "UPDATE word_reports SET status = $1, resolver_id = $2, resolver = $3, resolution_note = $4, resolved_at = DATETIME('NOW', 'subsec') WHERE id = $5 AND status = 'open'",
+            status,
+            resolver_id,
+            resolver_name,
+            note,
+            id
+        )
+        .execute(dbpool)
+        .await?
+        .rows_affected();
+        if closed == 0 {
+            return Err(ReportError::NotOpen(id).into());
+        }
+
+        Self::read(dbpool, lang, id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_report_reason() {
+        let report = |reason: &str| NewReport {
+            reason: reason.to_string(),
+            note: None,
+        };
+        assert_eq!(
+            report("Bad_Pronunciation").reason().unwrap(),
+            ReportReason::BadPronunciation
+        );
+        assert!(report("typo").reason().is_err());
+        assert_eq!(
+            ReportParams::default().status().unwrap(),
+            ReportStatus::Open
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Revision history of words
+//!
+//! Every creation, change, deletion, restore and revert of a word is recorded
+//! as a revision in the same transaction as the write, with the admin who made
+//! it, when, and snapshots of the word before and after. Admins can read the
+//! history of a word and revert it to the state left by any of its revisions.
+//! Revisions outlive their word: the purge of a word from the trash is
+//! recorded too, and the history of a purged word can still be read.
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::{query, query_scalar, SqliteConnection, SqlitePool};
+use strum_macros::EnumString;
+use utoipa::ToSchema;
+
+use crate::error::{AppError, LookupError, RevisionError};
+use crate::models::language::Language;
+use crate::models::user::AuthUser;
+use crate::models::version::IfMatch;
+use crate::models::word::{UpsertWord, Word};
+
+/// Changes recorded in the history of a word
+///
+/// # Supported Actions
+/// - `create` - The word was added
+/// - `update` - The word was changed, fully or by a patch or an import
+/// - `delete` - The word was moved to the trash
+/// - `restore` - The word was taken out of the trash
+/// - `revert` - The word was reverted to an earlier revision
+/// - `purge` - The word was purged from the trash for good
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+pub enum RevisionAction {
+    #[strum(serialize = "create")]
+    Create,
+    #[strum(serialize = "update")]
+    Update,
+    #[strum(serialize = "delete")]
+    Delete,
+    #[strum(serialize = "restore")]
+    Restore,
+    #[strum(serialize = "revert")]
+    Revert,
+    #[strum(serialize = "purge")]
+    Purge,
+}
+
+impl RevisionAction {
+    pub fn action_name(&self) -> &str {
+        match self {
+            RevisionAction::Create => "create",
+            RevisionAction::Update => "update",
+            RevisionAction::Delete => "delete",
+            RevisionAction::Restore => "restore",
+            RevisionAction::Revert => "revert",
+            RevisionAction::Purge => "purge",
+        }
+    }
+}
+
+/// A recorded change to a word
+///
+/// # Fields
+///
+/// - `id`: Unique identifier of the revision, increasing with time
+/// - `word_id`: ID of the changed word
+/// - `action`: Kind of change (create, update, delete, restore, revert, purge)
+/// - `user_id`: ID of the admin who made the change, if known
+/// - `username`: Name of the admin at the time of the change, if known
+/// - `before`: The word before the change, absent for a creation, a restore or
+///   a purge, the deletion before a purge holding the purged word
+/// - `after`: The word after the change, absent for a deletion or a purge
+/// - `created_at`: Timestamp of the change
+///
+#[derive(ToSchema, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WordRevision {
+    id: u32,
+    word_id: u32,
+    action: String,
+    user_id: Option<i64>,
+    username: Option<String>,
+    before: Option<Word>,
+    after: Option<Word>,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl WordRevision {
+    /// Records a change to a word of a language, in the transaction that made it
+    pub(crate) async fn record(
+        tx: &mut SqliteConnection,
+        code: &str,
+        word_id: u32,
+        action: RevisionAction,
+        actor: Option<&AuthUser>,
+        before: Option<&Word>,
+        after: Option<&Word>,
+    ) -> Result<(), AppError> {
+        let before = before.map(serde_json::to_string).transpose()?;
+        let after = after.map(serde_json::to_string).transpose()?;
+
+        let action = action.action_name();
+        let user_id = actor.map(|user| user.id);
+        let username = actor.map(|user| user.username.as_str());
+        query!(
+            "INSERT INTO word_revisions (word_id, language, action, user_id, username, before_snapshot, after_snapshot) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            word_id,
+            code,
+            action,
+            user_id,
+            username,
+            before,
+            after
+        )
+        .execute(&mut *tx)
+        .await?;
+        Ok(())
+    }
+
+    /// Retrieves the history of a word, most recent change first (admin only)
+    ///
+    /// The history of a word in the trash, or purged from it, can be read
+    /// too. Returns `LookupError::UnknownWord` when the word isn't in the
+    /// language and has no recorded history.
+    pub async fn history(dbpool: &SqlitePool, lang: &str, id: u32) -> Result<Vec<Self>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query, the ID of a purged word can be taken by a
+        // new word, whose history starts after the purge
+        let code = language.code();
+        let rows = query!(
+            r#"SELECT id AS "id!: u32", word_id AS "word_id!: u32", action, user_id, username, before_snapshot, after_snapshot, created_at AS "created_at: NaiveDateTime" FROM word_revisions WHERE word_id = $1 AND language = $2 AND id > COALESCE((SELECT MAX(r.id) FROM word_revisions r WHERE r.word_id = $1 AND r.language = $2 AND r.action = 'purge' AND EXISTS (SELECT 1 FROM words w WHERE w.id = $1 AND w.language = $2)), 0) ORDER BY id DESC"#,
+            id,
+            code
+        )
+        .fetch_all(dbpool)
+        .await?;
+
+        // a word without history is still known while it is stored
+        if rows.is_empty() {
+            let exists = query_scalar!(
+                r#"SELECT id AS "id: u32" FROM words WHERE id = $1 AND language = $2"#,
+                id,
+                code
+            )
+            .fetch_optional(dbpool)
+            .await?;
+            if exists.is_none() {
+                return Err(LookupError::UnknownWord(format!("#{id}")).into());
+            }
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(Self {
+                    id: row.id,
+                    word_id: row.word_id,
+                    action: row.action,
+                    user_id: row.user_id,
+                    username: row.username,
+                    before: row
+                        .before_snapshot
+                        .map(|json| serde_json::from_str(&json))
+                        .transpose()?,
+                    after: row
+                        .after_snapshot
+                        .map(|json| serde_json::from_str(&json))
+                        .transpose()?,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Reverts a word to the state left by one of its revisions (admin only)
+    ///
+    /// A deletion is reverted to the word as it was deleted. The revert is a
+    /// change like any other, bumping the version and recorded as a revision.
+    /// Tags aren't part of the word's own fields and are kept as they are.
+    /// Returns `RevisionError::UnknownRevision` when the revision isn't one
+    /// of the word, and `LookupError::UnknownWord` when the word isn't in the
+    /// language or is in the trash.
+    pub async fn revert(
+        dbpool: SqlitePool,
+        lang: &str,
+        id: u32,
+        revision_id: u32,
+        actor: Option<&AuthUser>,
+        if_match: Option<&IfMatch>,
+    ) -> Result<Word, AppError> {
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
+        let code = language.code();
+
+        // the snapshot of the revision, within the history of the word since
+        // its ID was last taken by a purged word
+        let snapshots = query!(
+            "SELECT before_snapshot, after_snapshot FROM word_revisions WHERE id = $1 AND word_id = $2 AND language = $3 AND id > COALESCE((SELECT MAX(r.id) FROM word_revisions r WHERE r.word_id = $2 AND r.language = $3 AND r.action = 'purge'), 0)",
+            revision_id,
+            id,
+            code
+        )
+        .fetch_optional(&dbpool)
+        .await?;
+        let Some(snapshot) = snapshots.and_then(|row| row.after_snapshot.or(row.before_snapshot))
+        else {
+            return Err(RevisionError::UnknownRevision(revision_id).into());
+        };
+        let word: Word = serde_json::from_str(&snapshot)?;
+
+        // normalized and written in the scripts and phoneme inventory of the language
+        let reverted = UpsertWord::from(word).conform(language.profile())?;
+
+        // the word and all of its child rows are stored together or not at all
+        let mut tx = dbpool.begin().await?;
+        Word::check_version(&mut tx, code, id, if_match).await?;
+        let stored = Word::store_revision(
+            &mut tx,
+            code,
+            Some(id),
+            &reverted,
+            RevisionAction::Revert,
+            actor,
+        )
+        .await?;
+        if stored.is_none() {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        }
+        tx.commit().await?;
+
+        Word::read(dbpool, lang, id)
+            .await?
+            .pop()
+            .ok_or_else(|| LookupError::UnknownWord(format!("#{id}")).into())
+    }
+}
@@ -0,0 +1,258 @@
+//! Thematic tags for words and tag-filtered random draws
+//!
+//! Tags (e.g. "animals", "food", "school") group words by theme across
+//! grammatical types. Tags are shared by all languages; words are tagged
+//! and untagged individually. Public random endpoints can require one of
+//! a set of tags and exclude others.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar, SqliteConnection, SqlitePool};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::error::{AppError, LookupError, QueryError, TagError};
+use crate::models::language::Language;
+
+/// A tag as listed to administrators
+///
+/// # Fields
+///
+/// - `id`: Unique identifier for the tag in the database
+/// - `name`: Tag name (e.g. "animals")
+/// - `word_count`: Number of words with the tag, in any language
+/// - `created_at`: Timestamp when the tag was added to the database
+///
+#[derive(ToSchema, Deserialize, Serialize, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    id: u32,
+    name: String,
+    word_count: u32,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl Tag {
+    /// Retrieves all tags in alphabetical order (admin only)
+    pub async fn list(dbpool: &SqlitePool) -> Result<Vec<Self>, AppError> {
+        query_as!(
+            Self,
+            r#"SELECT t.id AS "id!: u32", t.name, COUNT(wt.word_id) AS "word_count!: u32", t.created_at AS "created_at: NaiveDateTime" FROM tags t LEFT JOIN word_tags wt ON wt.tag_id = t.id GROUP BY t.id ORDER BY t.name"#
+        )
+        .fetch_all(dbpool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Creates a new tag (admin only)
+    pub async fn create(dbpool: &SqlitePool, new_tag: UpsertTag) -> Result<Vec<Self>, AppError> {
+        let name = new_tag.name()?.to_lowercase();
+
+        let inserted = query_scalar!(
+            r#"INSERT INTO tags (name) VALUES ($1) ON CONFLICT (name) DO NOTHING RETURNING id AS "id: u32""#,
+            name
+        )
+        .fetch_optional(dbpool)
+        .await?;
+        let Some(id) = inserted else {
+            return Err(TagError::AlreadyExists(name).into());
+        };
+
+        let tags = Self::list(dbpool).await?;
+        Ok(tags.into_iter().filter(|t| t.id == id).collect())
+    }
+
+    /// Deletes a tag and untags its words (admin only)
+    pub async fn delete(dbpool: &SqlitePool, id: u32) -> Result<(), AppError> {
+        let deleted = query!("DELETE FROM tags WHERE id = $1", id)
+            .execute(dbpool)
+            .await?;
+        if deleted.rows_affected() == 0 {
+            return Err(TagError::UnknownTag(id.to_string()).into());
+        }
+        Ok(())
+    }
+
+    /// Adds a tag to a word, tagging twice has no effect (admin only)
+    pub async fn attach(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        name: &str,
+    ) -> Result<(), AppError> {
+        let tag_id = Self::find_for_word(dbpool, lang, word_id, name).await?;
+
+        query!(
+            "INSERT INTO word_tags (word_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            word_id,
+            tag_id
+        )
+        .execute(dbpool)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes a tag from a word, untagging twice has no effect (admin only)
+    pub async fn detach(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        name: &str,
+    ) -> Result<(), AppError> {
+        let tag_id = Self::find_for_word(dbpool, lang, word_id, name).await?;
+
+        query!(
+            "DELETE FROM word_tags WHERE word_id = $1 AND tag_id = $2",
+            word_id,
+            tag_id
+        )
+        .execute(dbpool)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the ID of a tag after checking that the word exists
+    async fn find_for_word(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        name: &str,
+    ) -> Result<u32, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let code = language.code();
+        let count = query_scalar!(
+            "SELECT COUNT(*) FROM words WHERE id = $1 AND language = $2 AND deleted_at IS NULL",
+            word_id,
+            code
+        )
+        .fetch_one(dbpool)
+        .await?;
+        if count == 0 {
+            return Err(LookupError::UnknownWord(format!("#{word_id}")).into());
+        }
+
+        let lowercase = name.to_lowercase();
+        let tag_id = query_scalar!(
+            r#"SELECT id AS "id!: u32" FROM tags WHERE name = $1"#,
+            lowercase
+        )
+        .fetch_optional(dbpool)
+        .await?;
+        tag_id.ok_or_else(|| TagError::UnknownTag(name.to_string()).into())
+    }
+
+    /// Loads the tag names of a single word, in alphabetical order
+    pub(crate) async fn for_word(
+        conn: &mut SqliteConnection,
+        word_id: u32,
+    ) -> Result<Vec<String>, AppError> {
+        query_scalar!(
+            "SELECT t.name FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = $1 ORDER BY t.name",
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+}
+
+/// Data transfer object for creating tags
+///
+/// # Validation Rules
+///
+/// - `name`: Up to 32 letters, digits and hyphens (e.g. "farm-animals"), stored lowercased
+#[derive(ToSchema, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertTag {
+    #[validate(length(min = 1, max = 32), custom(function = "validate_tag_name"))]
+    pub name: String,
+}
+
+/// Validates a tag name
+///
+/// Accepts names such as "animals" or "farm-animals" made of letters,
+/// digits and hyphens, compared case-insensitively.
+fn validate_tag_name(text: &str) -> Result<(), ValidationError> {
+    let valid = text.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !valid || text.starts_with('-') || text.ends_with('-') {
+        return Err(ValidationError::new("invalid_tag_name"));
+    }
+    Ok(())
+}
+
+impl UpsertTag {
+    /// Returns the name field after validation
+    pub fn name(&self) -> Result<&str, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.name.as_ref()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Query parameters filtering random words by tag
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct TagParams {
+    /// Comma-separated tags, words must have at least one of them (e.g. `animals,food`)
+    pub tags: Option<String>,
+    /// Comma-separated tags, words must have none of them
+    pub exclude_tags: Option<String>,
+}
+
+impl TagParams {
+    /// Returns the requested filter, which lets every word through if both parameters are absent
+    pub fn filter(&self) -> Result<TagFilter, QueryError> {
+        Ok(TagFilter {
+            include: parse_tag_list("tags", self.tags.as_deref())?,
+            exclude: parse_tag_list("exclude_tags", self.exclude_tags.as_deref())?,
+        })
+    }
+}
+
+/// Parses a comma-separated list of tag names, lowercased like stored names
+fn parse_tag_list(parameter: &str, value: Option<&str>) -> Result<Vec<String>, QueryError> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            validate_tag_name(t)
+                .map(|_| t.to_lowercase())
+                .map_err(|_| QueryError::InvalidParameter(parameter.to_string(), t.to_string()))
+        })
+        .collect()
+}
+
+/// Tags a random word must have at least one of, and tags it must not have
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TagFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl TagFilter {
+    /// Included tags as a JSON array to bind, `None` when any tag is fine
+    pub(crate) fn include_json(&self) -> Option<String> {
+        Self::to_json(&self.include)
+    }
+
+    /// Excluded tags as a JSON array to bind, `None` when no tag is excluded
+    pub(crate) fn exclude_json(&self) -> Option<String> {
+        Self::to_json(&self.exclude)
+    }
+
+    fn to_json(names: &[String]) -> Option<String> {
+        if names.is_empty() {
+            return None;
+        }
+        serde_json::to_string(names).ok()
+    }
+}
@@ -0,0 +1,336 @@
+//! Translation links between words of different languages
+//!
+//! A link pairs a word with its translation in another language by ID,
+//! optionally narrowed to one sense of either word (e.g. the river sense of
+//! "bank" translates to the German "ufer"). Links hold in both directions:
+//! one stored link is found from either word. Links are removed together
+//! with either of their words by `ON DELETE CASCADE` foreign keys.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, query_scalar, SqlitePool};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{AppError, LookupError, TranslationError};
+use crate::models::language::Language;
+use crate::models::relation::ensure_word;
+use crate::models::tag::TagFilter;
+use crate::models::word::{GetWord, GrammaticalType, RandomWordRow, Word};
+
+// The queries below see the translation links `t` from both of their words,
+// from `t.word_id` and its `t.sense` to `t.other_id` and its `t.other_sense`,
+// through the same `UNION ALL` of `word_translations` with its columns swapped.
+
+/// A translation of a word, as listed to administrators
+///
+/// # Fields
+///
+/// - `id`: Unique identifier for the translation link in the database
+/// - `sense`: Position of the sense of the word the link is for, all senses if absent
+/// - `translation_id`: Database ID of the translation
+/// - `language`: Language code of the translation
+/// - `translation`: Lemma of the translation
+/// - `translation_sense`: Position of the sense of the translation the link is for
+/// - `created_at`: Timestamp when the link was added to the database
+///
+#[derive(ToSchema, Deserialize, Serialize, Clone, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct WordTranslation {
+    id: u32,
+    sense: Option<u32>,
+    translation_id: u32,
+    language: String,
+    translation: String,
+    translation_sense: Option<u32>,
+    created_at: Option<NaiveDateTime>,
+}
+
+impl WordTranslation {
+    /// Retrieves the translations of a word, in any language (admin only)
+    pub async fn list(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+    ) -> Result<Vec<Self>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        ensure_word(dbpool, &language, word_id).await?;
+
+        // perform the actual query
+        query_as!(
+            Self,
+            r#"SELECT t.id AS "id!: u32", t.sense AS "sense: u32", w.id AS "translation_id!: u32", w.language, w.word AS translation, t.other_sense AS "translation_sense: u32", t.created_at AS "created_at: NaiveDateTime" FROM (SELECT id, word_id, sense, translation_id AS other_id, translation_sense AS other_sense, created_at FROM word_translations UNION ALL SELECT id, translation_id, translation_sense, word_id, sense, created_at FROM word_translations) t JOIN words w ON w.id = t.other_id WHERE t.word_id = $1 AND w.deleted_at IS NULL ORDER BY w.language, w.word"#,
+            word_id
+        )
+        .fetch_all(dbpool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Links a word to its translation in another language (admin only)
+    ///
+    /// A link can only be stored once, whichever word it starts from.
+    pub async fn create(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        new_translation: UpsertTranslation,
+    ) -> Result<Vec<Self>, AppError> {
+        let translation_id = new_translation.translation_id;
+
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        ensure_word(dbpool, &language, word_id).await?;
+        let translation_language = query_scalar!(
+            "SELECT language FROM words WHERE id = $1 AND deleted_at IS NULL",
+            translation_id
+        )
+        .fetch_optional(dbpool)
+        .await?;
+        match translation_language {
+            None => return Err(LookupError::UnknownWord(format!("#{translation_id}")).into()),
+            Some(code) if code == language.code() => {
+                return Err(TranslationError::SameLanguage.into())
+            }
+            Some(_) => {}
+        }
+        ensure_sense(dbpool, word_id, new_translation.sense).await?;
+        ensure_sense(dbpool, translation_id, new_translation.translation_sense).await?;
+
+        let existing = query_scalar!(
+            "SELECT COUNT(*) FROM word_translations WHERE (word_id = $1 AND translation_id = $2) OR (word_id = $2 AND translation_id = $1)",
+            word_id,
+            translation_id
+        )
+        .fetch_one(dbpool)
+        .await?;
+        if existing > 0 {
+            return Err(TranslationError::AlreadyTranslated.into());
+        }
+
+        let id = query_scalar!(
+            r#"INSERT INTO word_translations (word_id, translation_id, sense, translation_sense) VALUES ($1, $2, $3, $4) RETURNING id AS "id: u32""#,
+            word_id,
+            translation_id,
+            new_translation.sense,
+            new_translation.translation_sense
+        )
+        .fetch_one(dbpool)
+        .await?;
+
+        let translations = Self::list(dbpool, lang, word_id).await?;
+        Ok(translations.into_iter().filter(|t| t.id == id).collect())
+    }
+
+    /// Deletes a translation link of a word (admin only)
+    ///
+    /// Links can be deleted from either of their words.
+    pub async fn delete(
+        dbpool: &SqlitePool,
+        lang: &str,
+        word_id: u32,
+        link_id: u32,
+    ) -> Result<(), AppError> {
+        let translations = Self::list(dbpool, lang, word_id).await?;
+        if !translations.iter().any(|t| t.id == link_id) {
+            return Err(TranslationError::UnknownTranslation(link_id).into());
+        }
+
+        query!("DELETE FROM word_translations WHERE id = $1", link_id)
+            .execute(dbpool)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Returns `TranslationError::UnknownSense` when the word has no sense at the given position
+async fn ensure_sense(
+    dbpool: &SqlitePool,
+    word_id: u32,
+    sense: Option<u32>,
+) -> Result<(), AppError> {
+    let Some(position) = sense else {
+        return Ok(());
+    };
+
+    let count = query_scalar!(
+        "SELECT COUNT(*) FROM word_senses WHERE word_id = $1 AND position = $2",
+        word_id,
+        position
+    )
+    .fetch_one(dbpool)
+    .await?;
+    if count == 0 {
+        return Err(TranslationError::UnknownSense(position).into());
+    }
+    Ok(())
+}
+
+/// Data transfer object for linking a word to its translation
+///
+/// # Validation Rules
+///
+/// - `translation_id`: Database ID of a word of another language
+/// - `sense`: Optional position of the sense of the word the link is for,
+///   starting from 0 for the primary sense
+/// - `translation_sense`: Optional position of the sense of the translation
+///   the link is for, starting from 0 for the primary sense
+#[derive(ToSchema, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertTranslation {
+    pub translation_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sense: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation_sense: Option<u32>,
+}
+
+/// Query parameters selecting the language of the translations
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct TranslationParams {
+    /// Code of the language to translate to, every enabled language if absent
+    pub to: Option<String>,
+}
+
+/// Retrieves the translations of a lemma, by language in alphabetical order
+///
+/// Only translations into enabled languages are returned, and in safe mode
+/// only those without content flags. Each translation comes with the
+/// definition of the sense it is for, or its primary one.
+pub async fn find_translations(
+    dbpool: &SqlitePool,
+    lang: &str,
+    lemma: &str,
+    to: Option<&str>,
+    safe: bool,
+) -> Result<Vec<TranslatedWord>, AppError> {
+    // if the language is registered and enabled
+    let language = Language::resolve(dbpool, lang).await?;
+
+    // if the language to translate to is registered and enabled
+    if let Some(code) = to {
+        Language::resolve(dbpool, code).await?;
+    }
+
+    let word_id = Word::find_id(dbpool, &language, lemma).await?;
+
+    // perform the actual query, checked at compile time, every translation
+    // with its primary sense and pronunciation, unflagged in safe mode
+    let translations = query!(
+        r#"SELECT w.language, ls.definition AS "sense?", w.word, COALESCE(ts.definition, s.definition) AS "definition!: String", p.pronunciation, p.variant FROM (SELECT id, word_id, sense, translation_id AS other_id, translation_sense AS other_sense, created_at FROM word_translations UNION ALL SELECT id, translation_id, translation_sense, word_id, sense, created_at FROM word_translations) t JOIN words w ON w.id = t.other_id JOIN languages l ON l.code = w.language AND l.enabled JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY position LIMIT 1) LEFT JOIN word_senses ls ON ls.word_id = t.word_id AND ls.position = t.sense LEFT JOIN word_senses ts ON ts.word_id = w.id AND ts.position = t.other_sense WHERE t.word_id = $1 AND w.deleted_at IS NULL AND ($2 IS NULL OR w.language = $2) AND ($3 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) ORDER BY w.language, w.word"#,
+        word_id,
+        to,
+        safe
+    )
+    .fetch_all(dbpool)
+    .await?
+    .into_iter()
+    .map(|row| TranslatedWord {
+        language: row.language,
+        sense: row.sense,
+        word: RandomWordRow {
+            word: row.word,
+            definition: row.definition,
+            pronunciation: row.pronunciation,
+            variant: row.variant,
+        }
+        .into(),
+    })
+    .collect();
+
+    Ok(translations)
+}
+
+/// Retrieves a random word and its translation into another language
+///
+/// With a `word_type`, the word has that type. The tag filter applies to
+/// the word, safe mode to both of them. Each word comes with the definition
+/// of the sense the link is for, or its primary one. Returns `None` when no
+/// word of the language has a translation into the other one.
+pub async fn random_translation(
+    dbpool: &SqlitePool,
+    lang: &str,
+    to: &str,
+    word_type: Option<&str>,
+    tags: &TagFilter,
+    safe: bool,
+) -> Result<Option<(GetWord, GetWord)>, AppError> {
+    // if both languages are registered and enabled
+    let language = Language::resolve(dbpool, lang).await?;
+    let to = Language::resolve(dbpool, to).await?;
+
+    // if the grammatical type is in the allowed ones
+    let grammatical_type = match word_type {
+        Some(t) => Some(GrammaticalType::resolve(dbpool, &language, t).await?),
+        None => None,
+    };
+
+    // perform the actual query, checked at compile time, the tag filters
+    // bound as JSON arrays, both the word and its translation `o` unflagged
+    // in safe mode
+    let type_name = grammatical_type.as_ref().map(GrammaticalType::type_name);
+    let include = tags.include_json();
+    let exclude = tags.exclude_json();
+    let code = language.code();
+    let to_code = to.code();
+    let link = query!(
+        r#"SELECT t.word_id AS "word_id!: u32", t.sense AS "sense: u32", t.other_id AS "other_id!: u32", t.other_sense AS "other_sense: u32" FROM (SELECT id, word_id, sense, translation_id AS other_id, translation_sense AS other_sense, created_at FROM word_translations UNION ALL SELECT id, translation_id, translation_sense, word_id, sense, created_at FROM word_translations) t JOIN words w ON w.id = t.word_id JOIN words o ON o.id = t.other_id WHERE w.language = $5 AND o.language = $6 AND w.deleted_at IS NULL AND o.deleted_at IS NULL AND ($1 IS NULL OR w.word_type = $1) AND ($2 IS NULL OR EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($2)))) AND ($3 IS NULL OR NOT EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($3)))) AND ($4 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) AND ($4 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = o.id)) ORDER BY random() LIMIT 1"#,
+        type_name,
+        include,
+        exclude,
+        safe,
+        code,
+        to_code
+    )
+    .fetch_optional(dbpool)
+    .await?;
+
+    let Some(link) = link else {
+        return Ok(None);
+    };
+    Ok(Some((
+        linked_word(dbpool, link.word_id, link.sense).await?,
+        linked_word(dbpool, link.other_id, link.other_sense).await?,
+    )))
+}
+
+/// Retrieves a word with the definition of one of its senses, or of its primary one
+async fn linked_word(
+    dbpool: &SqlitePool,
+    word_id: u32,
+    sense: Option<u32>,
+) -> Result<GetWord, AppError> {
+    // perform the actual query, the linked word with its primary sense and
+    // pronunciation, checked at compile time
+    let word = query_as!(
+        RandomWordRow,
+        r#"SELECT w.word, COALESCE(ls.definition, s.definition) AS "definition!: String", p.pronunciation, p.variant FROM words w JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY position LIMIT 1) LEFT JOIN word_senses ls ON ls.word_id = w.id AND ls.position = $2 WHERE w.id = $1"#,
+        word_id,
+        sense
+    )
+    .fetch_one(dbpool)
+    .await?;
+
+    Ok(word.into())
+}
+
+/// A translation in public API responses
+///
+/// # Fields
+///
+/// - `language`: Language code of the translation
+/// - `sense`: Definition of the sense of the looked up lemma the translation
+///   is for, absent when it translates the lemma as a whole
+/// - the fields of [`GetWord`] for the translation
+#[derive(ToSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslatedWord {
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sense: Option<String>,
+    #[serde(flatten)]
+    pub word: GetWord,
+}
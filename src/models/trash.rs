@@ -0,0 +1,238 @@
+//! Trash of deleted words
+//!
+//! Deleting a word moves it to the trash instead of removing it. The word
+//! keeps its child rows, relations and translations, but every public and
+//! admin query leaves it out, and it still holds its lemma. Admins can list
+//! the trash of a language, restore a word as it was or purge it for good.
+//! Words in the trash for longer than the configured retention are purged
+//! by the server in the background, or on demand.
+
+use chrono::{Duration, NaiveDateTime};
+use serde::Serialize;
+use sqlx::{query, SqlitePool};
+use utoipa::ToSchema;
+
+use crate::error::{AppError, LookupError};
+use crate::models::language::Language;
+use crate::models::revision::{RevisionAction, WordRevision};
+use crate::models::user::AuthUser;
+use crate::models::word::Word;
+
+/// Time between two background purges of the trash
+const PURGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// A deleted word waiting in the trash
+///
+/// # Fields
+///
+/// - `id`: ID of the word, which it keeps when restored
+/// - `word`: The lemma, which can't be used by another word until purged
+/// - `word_type`: Grammatical type of the word
+/// - `deleted_at`: Timestamp when the word was deleted
+/// - `purge_at`: Timestamp after which the word is purged for good
+///
+#[derive(ToSchema, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedWord {
+    id: u32,
+    word: String,
+    word_type: String,
+    deleted_at: NaiveDateTime,
+    purge_at: NaiveDateTime,
+}
+
+/// Result of purging the trash
+///
+/// # Fields
+///
+/// - `purged`: Number of words removed for good
+///
+#[derive(ToSchema, Serialize, Clone)]
+pub struct PurgeReport {
+    purged: u64,
+}
+
+impl TrashedWord {
+    /// Retrieves the trash of a language, most recently deleted first (admin only)
+    pub async fn list(
+        dbpool: &SqlitePool,
+        lang: &str,
+        retention_days: u32,
+    ) -> Result<Vec<Self>, AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query, checked at compile time
+        let code = language.code();
+        let rows = query!(
+            r#"SELECT id AS "id!: u32", word, word_type, deleted_at AS "deleted_at!: NaiveDateTime" FROM words WHERE language = $1 AND deleted_at IS NOT NULL ORDER BY deleted_at DESC, id DESC"#,
+            code
+        )
+        .fetch_all(dbpool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Self {
+                id: row.id,
+                word: row.word,
+                word_type: row.word_type,
+                deleted_at: row.deleted_at,
+                purge_at: row.deleted_at + Duration::days(retention_days.into()),
+            })
+            .collect())
+    }
+
+    /// Takes a word out of the trash, as it was when deleted (admin only)
+    ///
+    /// The restore is recorded as a revision made by the acting admin.
+    /// Returns `LookupError::UnknownWord` when the word isn't in the trash
+    /// of the language.
+    pub async fn restore(
+        dbpool: SqlitePool,
+        lang: &str,
+        id: u32,
+        actor: Option<&AuthUser>,
+    ) -> Result<Word, AppError> {
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
+
+        // perform the actual query
+        let mut tx = dbpool.begin().await?;
+        let code = language.code();
+        let restored = query!(
+            "UPDATE words SET deleted_at = NULL, version = version + 1 WHERE id = $1 AND language = $2 AND deleted_at IS NOT NULL",
+            id,
+            code
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if restored == 0 {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        }
+        let Some(after) = Word::load(&mut tx, language.code(), id).await? else {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        };
+        WordRevision::record(
+            &mut tx,
+            language.code(),
+            id,
+            RevisionAction::Restore,
+            actor,
+            None,
+            Some(&after),
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(after)
+    }
+
+    /// Purges a word of the trash for good, whatever its retention (admin only)
+    ///
+    /// Its senses, examples, inflections, tags, pronunciations, relations and
+    /// translations are removed by `ON DELETE CASCADE` foreign keys, while
+    /// its history is kept and the purge recorded as a revision made by the
+    /// acting admin. Returns `LookupError::UnknownWord` when the word isn't
+    /// in the trash of the language.
+    pub async fn purge(
+        dbpool: &SqlitePool,
+        lang: &str,
+        id: u32,
+        actor: Option<&AuthUser>,
+    ) -> Result<(), AppError> {
+        // if the language is registered
+        let language = Language::find(dbpool, lang).await?;
+
+        // perform the actual query
+        let mut tx = dbpool.begin().await?;
+        let code = language.code();
+        let purged = query!(
+            "DELETE FROM words WHERE id = $1 AND language = $2 AND deleted_at IS NOT NULL",
+            id,
+            code
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if purged == 0 {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        }
+        WordRevision::record(
+            &mut tx,
+            language.code(),
+            id,
+            RevisionAction::Purge,
+            actor,
+            None,
+            None,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Purges the words in the trash for longer than the retention
+    ///
+    /// Only purges the trash of the given language, or of every language
+    /// when `None`. Each purge is recorded as a revision made by the acting
+    /// admin, if any.
+    pub async fn purge_expired(
+        dbpool: &SqlitePool,
+        lang: Option<&str>,
+        retention_days: u32,
+        actor: Option<&AuthUser>,
+    ) -> Result<PurgeReport, AppError> {
+        // if the language is registered
+        let code = match lang {
+            Some(lang) => Some(Language::find(dbpool, lang).await?.code().to_string()),
+            None => None,
+        };
+
+        // the purges are recorded along with the deletion
+        let mut tx = dbpool.begin().await?;
+        let retention = format!("-{retention_days} days");
+        let user_id = actor.map(|user| user.id);
+        let username = actor.map(|user| user.username.as_str());
+        query!(
+            "INSERT INTO word_revisions (word_id, language, action, user_id, username) SELECT id, language, 'purge', $3, $4 FROM words WHERE deleted_at IS NOT NULL AND deleted_at <= DATETIME('NOW', 'subsec', $1) AND ($2 IS NULL OR language = $2) ORDER BY id",
+            retention,
+            code,
+            user_id,
+            username
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // perform the actual query, checked at compile time like the one above
+        let purged = query!(
+            "DELETE FROM words WHERE deleted_at IS NOT NULL AND deleted_at <= DATETIME('NOW', 'subsec', $1) AND ($2 IS NULL OR language = $2)",
+            retention,
+            code
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        tx.commit().await?;
+        Ok(PurgeReport { purged })
+    }
+}
+
+/// Purges the words in the trash for longer than the retention, every hour
+///
+/// Runs for as long as the server does. A failed purge is logged and
+/// retried at the next interval.
+pub async fn purge_periodically(dbpool: SqlitePool, retention_days: u32) {
+    let mut interval = tokio::time::interval(PURGE_INTERVAL);
+    loop {
+        interval.tick().await;
+        match TrashedWord::purge_expired(&dbpool, None, retention_days, None).await {
+            Ok(report) if report.purged > 0 => {
+                tracing::info!("purged {} words from the trash", report.purged)
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("couldn't purge the trash: {e:?}"),
+        }
+    }
+}
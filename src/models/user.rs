@@ -10,7 +10,7 @@ use sqlx::SqlitePool;
 use utoipa::ToSchema;
 use validator::Validate;
 
-use crate::error::AuthError;
+use crate::error::{AppError, AuthError, UserError};
 
 /// Database representation of a user
 #[derive(Debug, Clone, FromRow, Serialize, ToSchema)]
@@ -20,6 +20,8 @@ pub struct User {
     #[serde(skip_serializing)]
     pub password_hash: String,
     pub is_admin: bool,
+    /// Editors propose words and changes, which admins review
+    pub is_editor: bool,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -64,6 +66,34 @@ impl User {
             .await
             .map_err(AuthError::DatabaseError)
     }
+
+    /// Grants or revokes the editor role of a user (admin only)
+    ///
+    /// The role is carried by the tokens of the user, so it applies from the
+    /// next login on. Returns `UserError::UnknownUser` when no user has the ID.
+    pub async fn set_editor(pool: &SqlitePool, id: i64, is_editor: bool) -> Result<Self, AppError> {
+        let updated = sqlx::query!(
+            "UPDATE users SET is_editor = $1 WHERE id = $2",
+            is_editor,
+            id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Err(UserError::UnknownUser(id).into());
+        }
+
+        // the user as stamped by the update trigger
+        let user = sqlx::query_as!(
+            User,
+            r#"SELECT id, username, password_hash, is_admin AS "is_admin: bool", is_editor AS "is_editor: bool", created_at AS "created_at: DateTime<Utc>", updated_at AS "updated_at: DateTime<Utc>" FROM users WHERE id = $1"#,
+            id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(user)
+    }
 }
 
 /// User login request data
@@ -142,6 +172,11 @@ pub struct Claims {
     /// Administrative privileges flag
     pub is_admin: bool,
 
+    /// Editorial privileges flag, to propose words for review
+    /// Defaults to false for tokens issued before the editor role
+    #[serde(default)]
+    pub is_editor: bool,
+
     /// Session identifier for token revocation capabilities
     /// Allows proper logout functionality and session management
     pub session_id: String,
@@ -157,6 +192,9 @@ pub struct AuthUser {
     pub id: i64,
     pub username: String,
     pub is_admin: bool,
+    pub is_editor: bool,
+    /// JWT ID of the token used for this request, for replay protection
+    pub jti: String,
 }
 
 impl From<Claims> for AuthUser {
@@ -165,6 +203,8 @@ impl From<Claims> for AuthUser {
             id: claims.sub.parse().unwrap_or(0),
             username: claims.username,
             is_admin: claims.is_admin,
+            is_editor: claims.is_editor,
+            jti: claims.jti,
         }
     }
 }
@@ -0,0 +1,128 @@
+//! Word versions and conditional requests
+//!
+//! Every write to a word bumps its version, which is its entity tag. Admins
+//! send the tag back in `If-Match` when writing, and a write to a word
+//! changed in the meantime fails instead of silently overwriting the other
+//! change. Reads honour `If-None-Match` and `If-Modified-Since`, so clients
+//! can revalidate cached words without downloading them again.
+
+use chrono::NaiveDateTime;
+
+/// Format of HTTP dates, always in GMT (e.g. "Sun, 06 Nov 1994 08:49:37 GMT")
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Returns the entity tag of a version of a word
+pub fn etag(version: u32) -> String {
+    format!("\"{version}\"")
+}
+
+/// Formats a time as an HTTP date, for `Last-Modified`
+pub fn http_date(time: NaiveDateTime) -> String {
+    time.format(HTTP_DATE_FORMAT).to_string()
+}
+
+/// Versions of a word a write may apply to, from an `If-Match` header
+#[derive(Debug, Clone, PartialEq)]
+pub enum IfMatch {
+    /// `*`, any version of an existing word
+    Any,
+    /// The listed versions, weak or malformed tags never matching
+    Versions(Vec<u32>),
+}
+
+impl IfMatch {
+    /// Parses the value of an `If-Match` header
+    pub fn parse(value: &str) -> Self {
+        if value.trim() == "*" {
+            return IfMatch::Any;
+        }
+        IfMatch::Versions(
+            value
+                .split(',')
+                .filter_map(|tag| tag.trim().strip_prefix('"')?.strip_suffix('"'))
+                .filter_map(|version| version.parse().ok())
+                .collect(),
+        )
+    }
+
+    /// Whether a stored version is one of the expected ones
+    pub fn matches(&self, version: u32) -> bool {
+        match self {
+            IfMatch::Any => true,
+            IfMatch::Versions(versions) => versions.contains(&version),
+        }
+    }
+}
+
+/// Whether the copy of a word a client holds is still current
+///
+/// `If-None-Match` takes precedence, comparing tags weakly, and
+/// `If-Modified-Since` is only used without it, at the precision of a
+/// second. Malformed headers are ignored.
+pub fn not_modified(
+    if_none_match: Option<&str>,
+    if_modified_since: Option<&str>,
+    version: u32,
+    last_modified: Option<NaiveDateTime>,
+) -> bool {
+    if let Some(tags) = if_none_match {
+        let current = etag(version);
+        return tags
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == current);
+    }
+
+    match (if_modified_since, last_modified) {
+        (Some(since), Some(last_modified)) => {
+            NaiveDateTime::parse_from_str(since, HTTP_DATE_FORMAT).is_ok_and(|since| {
+                last_modified.and_utc().timestamp() <= since.and_utc().timestamp()
+            })
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_if_match() {
+        assert_eq!(IfMatch::parse("*"), IfMatch::Any);
+        assert_eq!(etag(3), "\"3\"");
+        assert_eq!(
+            IfMatch::parse("\"3\", \"4\""),
+            IfMatch::Versions(vec![3, 4])
+        );
+
+        // If-Match compares tags strongly
+        assert!(IfMatch::parse("\"3\"").matches(3));
+        assert!(!IfMatch::parse("W/\"3\"").matches(3));
+        assert!(!IfMatch::parse("3").matches(3));
+        assert!(IfMatch::Any.matches(7));
+    }
+
+    #[test]
+    fn test_not_modified() {
+        let time = NaiveDateTime::parse_from_str("2025-01-31 12:00:00.250", "%Y-%m-%d %H:%M:%S%.f")
+            .unwrap();
+        assert_eq!(http_date(time), "Fri, 31 Jan 2025 12:00:00 GMT");
+
+        assert!(not_modified(Some("\"2\", W/\"3\""), None, 3, Some(time)));
+        assert!(not_modified(Some("*"), None, 3, None));
+        assert!(!not_modified(Some("\"2\""), None, 3, Some(time)));
+
+        // If-None-Match takes precedence over If-Modified-Since
+        let since = Some("Fri, 31 Jan 2025 12:00:00 GMT");
+        assert!(not_modified(None, since, 3, Some(time)));
+        assert!(!not_modified(Some("\"2\""), since, 3, Some(time)));
+        assert!(!not_modified(
+            None,
+            Some("Fri, 31 Jan 2025 11:59:59 GMT"),
+            3,
+            Some(time)
+        ));
+        assert!(!not_modified(None, Some("yesterday"), 3, Some(time)));
+    }
+}
@@ -1,18 +1,37 @@
 //! Word data structure with CRUD operations and validation
 //!
 //! Provides database operations for managing dictionary words with lemmas,
-//! definitions, and IPA pronunciations. Supports random word retrieval
-//! with optional filtering by grammatical type.
+//! senses (definitions), example sentences, usage notes, inflections, and IPA
+//! pronunciations.
+//! Supports random word retrieval with optional filtering by grammatical type.
+//! Words of every language live in the `words` table, keyed by language
+//! code, and their queries are checked at compile time.
 
 use chrono::NaiveDateTime;
+use futures::TryStreamExt;
 use serde::{Deserialize, Serialize};
-use sqlx::{query, query_as, SqlitePool};
+use sqlx::{query, query_as, query_scalar, SqliteConnection, SqlitePool};
 use std::str::FromStr;
 use strum_macros::EnumString;
-use utoipa::ToSchema;
-use validator::{Validate, ValidationError};
-
-use crate::error::{AppError, PathError};
+use tokio::sync::mpsc;
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::error::{
+    AppError, LookupError, PatchError, PathError, PreconditionError, ProfileError, QueryError,
+    TrashError,
+};
+use crate::models::category::{validate_categories, CategoryParams, TypeCategory};
+use crate::models::flag::{validate_flags, ContentFlag};
+use crate::models::inflection::{InflectionKind, WordInflection};
+use crate::models::language::Language;
+use crate::models::listing::{Cursor, ListParams, SortOrder, WordPage};
+use crate::models::profile::{normalize, ValidationProfile};
+use crate::models::pronunciation::{PronunciationFormat, PronunciationTables};
+use crate::models::revision::{RevisionAction, WordRevision};
+use crate::models::tag::{Tag, TagFilter};
+use crate::models::user::AuthUser;
+use crate::models::version::{etag, not_modified, IfMatch};
 
 /// Grammatical word types supported by the API
 ///
@@ -24,10 +43,16 @@ use crate::error::{AppError, PathError};
 /// - `Verb` - Actions, states, occurrences (e.g., "run", "think", "exist")
 /// - `Adjective` - Descriptive words, qualities (e.g., "red", "beautiful", "quick")
 /// - `Adverb` - Modifiers for verbs, adjectives, other adverbs (e.g., "quickly", "very")
+/// - `Pronoun` - Words standing in for nouns (e.g., "she", "something")
+/// - `Preposition` - Words relating a noun to the rest of the sentence (e.g., "under", "of")
+/// - `Conjunction` - Words joining clauses or phrases (e.g., "and", "because")
+/// - `Interjection` - Exclamations (e.g., "ouch", "hello")
+/// - `Article` - Definite and indefinite articles (e.g., "the", "a")
 ///
 /// # Usage
 /// Used for filtering random word requests by grammatical category and
-/// validating word type parameters in API endpoints.
+/// validating word type parameters in API endpoints. In paths, a type can
+/// also be named in the language of the words (see [`GrammaticalType::resolve`]).
 #[derive(Debug, PartialEq, EnumString)]
 pub enum GrammaticalType {
     #[strum(serialize = "noun")]
@@ -38,16 +63,16 @@ pub enum GrammaticalType {
     Adjective,
     #[strum(serialize = "adverb")]
     Adverb,
-    // #[strum(serialize = "pronoun")]
-    // Pronoun,
-    // #[strum(serialize = "preposition")]
-    // Preposition,
-    // #[strum(serialize = "conjunction")]
-    // Conjunction,
-    // #[strum(serialize = "interjection")]
-    // Interjection,
-    // #[strum(serialize = "article")]
-    // Article,
+    #[strum(serialize = "pronoun")]
+    Pronoun,
+    #[strum(serialize = "preposition")]
+    Preposition,
+    #[strum(serialize = "conjunction")]
+    Conjunction,
+    #[strum(serialize = "interjection")]
+    Interjection,
+    #[strum(serialize = "article")]
+    Article,
 }
 
 impl GrammaticalType {
@@ -57,13 +82,42 @@ impl GrammaticalType {
             GrammaticalType::Verb => "verb",
             GrammaticalType::Adjective => "adjective",
             GrammaticalType::Adverb => "adverb",
-            // GrammaticalType::Pronoun => "pronoun",
-            // GrammaticalType::Preposition => "preposition",
-            // GrammaticalType::Conjunction => "conjunction",
-            // GrammaticalType::Interjection => "interjection",
-            // GrammaticalType::Article => "article",
+            GrammaticalType::Pronoun => "pronoun",
+            GrammaticalType::Preposition => "preposition",
+            GrammaticalType::Conjunction => "conjunction",
+            GrammaticalType::Interjection => "interjection",
+            GrammaticalType::Article => "article",
         }
     }
+
+    /// Resolves the `{type}` path segment in a language
+    ///
+    /// Accepts the English name of the type (e.g. "noun") in every language,
+    /// or the name the language gives it (e.g. "substantiv" in German), in
+    /// any case. Returns `PathError::InvalidWordType` for other names.
+    pub async fn resolve(
+        dbpool: &SqlitePool,
+        language: &Language,
+        name: &str,
+    ) -> Result<Self, AppError> {
+        if let Ok(grammatical_type) = Self::from_str(name) {
+            return Ok(grammatical_type);
+        }
+
+        let code = language.code();
+        let localized = normalize(&name.to_lowercase());
+        let word_type = query_scalar!(
+            "SELECT word_type FROM type_names WHERE language = $1 AND name = $2",
+            code,
+            localized
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        word_type
+            .and_then(|t| Self::from_str(&t).ok())
+            .ok_or_else(|| PathError::InvalidWordType(name.to_string()).into())
+    }
 }
 
 impl std::fmt::Display for GrammaticalType {
@@ -73,202 +127,1051 @@ impl std::fmt::Display for GrammaticalType {
             GrammaticalType::Verb => write!(f, "verb"),
             GrammaticalType::Adjective => write!(f, "adjective"),
             GrammaticalType::Adverb => write!(f, "adverb"),
-            // GrammaticalType::Pronoun => write!(f, "pronoun"),
-            // GrammaticalType::Preposition => write!(f, "preposition"),
-            // GrammaticalType::Conjunction => write!(f, "conjunction"),
-            // GrammaticalType::Interjection => write!(f, "interjection"),
-            // GrammaticalType::Article => write!(f, "article"),
+            GrammaticalType::Pronoun => write!(f, "pronoun"),
+            GrammaticalType::Preposition => write!(f, "preposition"),
+            GrammaticalType::Conjunction => write!(f, "conjunction"),
+            GrammaticalType::Interjection => write!(f, "interjection"),
+            GrammaticalType::Article => write!(f, "article"),
         }
     }
 }
 
-/// Languages support. Currently only supports American English.
+/// Label given to pronunciations that don't belong to a specific region
+pub const DEFAULT_VARIANT: &str = "general";
+
+/// A pronunciation of a word with its regional or variant label
 ///
-/// Methods include language parameter validation to ensure:
-/// - Only supported languages are processed
-/// - Proper error handling for unsupported language codes
-/// - Future extensibility for multi-language support
+/// # Fields
 ///
-/// To add new longuages see the commented out examples.
+/// - `variant`: Region or variant label (e.g. "us", "uk", "general")
+/// - `pronunciation`: IPA phonetic notation enclosed in forward slashes
 ///
-/// NOTE: the database need to have the tables and data ready to
-/// accommodate any additiomal language
-#[derive(Debug, PartialEq, EnumString)]
-pub enum LanguageCode {
-    #[strum(serialize = "en")]
-    English,
-    // #[strum(serialize = "de")]
-    // German,
-    // #[strum(serialize = "fr")]
-    // French,
-    // #[strum(serialize = "es")]
-    // Spanish,
-    // #[strum(serialize = "it")]
-    // Italian,
-    // #[strum(serialize = "nl")]
-    // Dutch,
-}
-
-impl LanguageCode {
-    pub fn table_name(&self) -> &str {
-        match self {
-            LanguageCode::English => "words",
-            // LanguageCode::German => "words_de",
-            // LanguageCode::French => "words_fr",
-            // LanguageCode::Spanish => "words_es",
-            // LanguageCode::Italian => "words_it",
-            // LanguageCode::Dutch => "words_nl",
+#[derive(Debug, PartialEq, ToSchema, Deserialize, Serialize, Clone, Validate, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct PronunciationVariant {
+    #[serde(default = "default_variant")]
+    #[validate(length(min = 1, max = 16), custom(function = "validate_variant"))]
+    pub variant: String,
+    #[validate(length(min = 1), custom(function = "validate_pronunciation"))]
+    pub pronunciation: String,
+}
+
+fn default_variant() -> String {
+    DEFAULT_VARIANT.to_string()
+}
+
+impl PronunciationVariant {
+    /// Loads the pronunciations of a single word, in preference order
+    async fn for_word(conn: &mut SqliteConnection, word_id: u32) -> Result<Vec<Self>, AppError> {
+        query_as!(
+            Self,
+            "SELECT variant, pronunciation FROM word_pronunciations WHERE word_id = $1 ORDER BY position",
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the pronunciations of a word, the first one being the primary
+    async fn replace_all(
+        tx: &mut SqliteConnection,
+        word_id: u32,
+        pronunciations: &[Self],
+    ) -> Result<(), AppError> {
+        query!(
+            "DELETE FROM word_pronunciations WHERE word_id = $1",
+            word_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (position, p) in pronunciations.iter().enumerate() {
+            let position = position as i64;
+            query!(
+                "INSERT INTO word_pronunciations (word_id, variant, pronunciation, position) VALUES ($1, $2, $3, $4)",
+                word_id,
+                p.variant,
+                p.pronunciation,
+                position
+            )
+            .execute(&mut *tx)
+            .await?;
         }
+        Ok(())
     }
 }
 
-impl std::fmt::Display for LanguageCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LanguageCode::English => write!(f, "en"),
-            // LanguageCode::German => write!(f, "de"),
-            // LanguageCode::French => write!(f, "fr"),
-            // LanguageCode::Spanish => write!(f, "es"),
-            // LanguageCode::Italian => write!(f, "it"),
-            // LanguageCode::Dutch => write!(f, "nl"),
+/// A meaning of a word with its part of speech and an optional example
+///
+/// # Fields
+///
+/// - `part_of_speech`: Grammatical type of the word in this sense
+/// - `definition`: Human-readable definition of this sense
+/// - `example`: Optional example sentence using the word in this sense
+///
+#[derive(Debug, PartialEq, ToSchema, Deserialize, Serialize, Clone, Validate, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct WordSense {
+    #[validate(length(min = 1), custom(function = "validate_word_type"))]
+    pub part_of_speech: String,
+    #[validate(length(min = 1), custom(function = "validate_definition"))]
+    pub definition: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1), custom(function = "validate_definition"))]
+    pub example: Option<String>,
+}
+
+impl WordSense {
+    /// Loads the senses of a single word, in display order
+    async fn for_word(conn: &mut SqliteConnection, word_id: u32) -> Result<Vec<Self>, AppError> {
+        query_as!(
+            Self,
+            "SELECT part_of_speech, definition, example FROM word_senses WHERE word_id = $1 ORDER BY position",
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Loads the senses of a lemma, in display order
+    async fn for_lemma(
+        dbpool: &SqlitePool,
+        language: &Language,
+        lemma: &str,
+    ) -> Result<Vec<Self>, AppError> {
+        let code = language.code();
+        query_as!(
+            Self,
+            "SELECT s.part_of_speech, s.definition, s.example FROM word_senses s JOIN words w ON w.id = s.word_id WHERE w.language = $1 AND w.word = $2 AND w.deleted_at IS NULL ORDER BY s.position",
+            code,
+            lemma
+        )
+        .fetch_all(dbpool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the senses of a word, the first one being the primary
+    async fn replace_all(
+        tx: &mut SqliteConnection,
+        word_id: u32,
+        senses: &[Self],
+    ) -> Result<(), AppError> {
+        query!("DELETE FROM word_senses WHERE word_id = $1", word_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for (position, s) in senses.iter().enumerate() {
+            let position = position as i64;
+            query!(
+                "INSERT INTO word_senses (word_id, part_of_speech, definition, example, position) VALUES ($1, $2, $3, $4, $5)",
+                word_id,
+                s.part_of_speech,
+                s.definition,
+                s.example,
+                position
+            )
+            .execute(&mut *tx)
+            .await?;
         }
+        Ok(())
+    }
+}
+
+/// An example sentence using a word, with the word's position marked
+///
+/// Offsets count characters (Unicode scalar values), not bytes, so that
+/// clients can highlight the word the sentence is about.
+///
+/// # Fields
+///
+/// - `sentence`: Example sentence, kept as written
+/// - `target_start`: Offset of the first character of the word in the sentence
+/// - `target_end`: Offset right after the last character of the word in the sentence
+///
+#[derive(Debug, PartialEq, ToSchema, Deserialize, Serialize, Clone, Validate, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_example_target"))]
+pub struct WordExample {
+    #[validate(length(min = 1, max = 500), custom(function = "validate_definition"))]
+    pub sentence: String,
+    pub target_start: u32,
+    pub target_end: u32,
+}
+
+impl WordExample {
+    /// Returns the marked part of the sentence, if the offsets fit in it
+    pub fn target(&self) -> Option<String> {
+        if self.target_start >= self.target_end
+            || self.target_end as usize > self.sentence.chars().count()
+        {
+            return None;
+        }
+
+        Some(
+            self.sentence
+                .chars()
+                .skip(self.target_start as usize)
+                .take((self.target_end - self.target_start) as usize)
+                .collect(),
+        )
+    }
+
+    /// Normalizes the sentence to Unicode NFC, moving the offsets along
+    ///
+    /// The parts before, inside and after the marked word are normalized
+    /// on their own, so the offsets keep marking the same word.
+    fn normalized(&self) -> Self {
+        let chars: Vec<char> = self.sentence.chars().collect();
+        let start = (self.target_start as usize).min(chars.len());
+        let end = (self.target_end as usize).clamp(start, chars.len());
+        let [before, target, after] = [&chars[..start], &chars[start..end], &chars[end..]]
+            .map(|part| normalize(&part.iter().collect::<String>()));
+
+        let target_start = before.chars().count() as u32;
+        Self {
+            target_start,
+            target_end: target_start + target.chars().count() as u32,
+            sentence: before + &target + &after,
+        }
+    }
+
+    /// Loads the examples of a single word, in display order
+    async fn for_word(conn: &mut SqliteConnection, word_id: u32) -> Result<Vec<Self>, AppError> {
+        query_as!(
+            Self,
+            r#"SELECT sentence, target_start AS "target_start: u32", target_end AS "target_end: u32" FROM word_examples WHERE word_id = $1 ORDER BY position"#,
+            word_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the examples of a word, in the given display order
+    async fn replace_all(
+        tx: &mut SqliteConnection,
+        word_id: u32,
+        examples: &[Self],
+    ) -> Result<(), AppError> {
+        query!("DELETE FROM word_examples WHERE word_id = $1", word_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for (position, e) in examples.iter().enumerate() {
+            let position = position as i64;
+            query!(
+                "INSERT INTO word_examples (word_id, sentence, target_start, target_end, position) VALUES ($1, $2, $3, $4, $5)",
+                word_id,
+                e.sentence,
+                e.target_start,
+                e.target_end,
+                position
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        Ok(())
     }
 }
 
 /// Represents a word in the database and in API responses.
 ///
 /// This struct contains all the information about a dictionary word including
-/// its definition, pronunciation in IPA notation, and timestamp metadata.
+/// its senses, pronunciations in IPA notation, and timestamp metadata.
 ///
 /// # Fields
 ///
 /// - `id`: Unique identifier for the word in the database
 /// - `word_type`: Grammatical type of the word (noun, verb, adjective, adverb)
 /// - `word`: The actual word/lemma following Merriam-Webster standards
-/// - `definition`: Human-readable definition of the word
-/// - `pronunciation`: IPA phonetic notation enclosed in forward slashes
+/// - `senses`: Definitions with part of speech and example, the first one is the primary
+/// - `examples`: Example sentences with the word's position marked
+/// - `usage_note`: Optional note on register or usage (e.g. "informal")
+/// - `inflections`: Inflected forms of the word (e.g. plural, past tense)
+/// - `categories`: Grammatical sub-categories of the word (e.g. proper, transitive), in alphabetical order
+/// - `tags`: Names of the thematic tags of the word, in alphabetical order
+/// - `flags`: Content flags of the word (offensive, mature, archaic, slang), in alphabetical order
+/// - `pronunciations`: Labelled IPA pronunciations, the first one is the primary
 /// - `created_at`: Timestamp when the word was added to the database
 /// - `updated_at`: Timestamp when the word was last modified
+/// - `version`: Version of the word, bumped by every change, which is its `ETag`
 ///
-#[derive(ToSchema, Deserialize, Serialize, Clone, sqlx::FromRow)]
+#[derive(ToSchema, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Word {
     id: u32,
     word_type: String,
     word: String,
-    definition: String,
-    pronunciation: String,
+    senses: Vec<WordSense>,
+    examples: Vec<WordExample>,
+    usage_note: Option<String>,
+    inflections: Vec<WordInflection>,
+    categories: Vec<String>,
+    tags: Vec<String>,
+    flags: Vec<String>,
+    pronunciations: Vec<PronunciationVariant>,
+    created_at: Option<NaiveDateTime>,
+    updated_at: Option<NaiveDateTime>,
+    version: u32,
+}
+
+/// A row of the words table, other word data lives in child tables
+struct WordRow {
+    id: u32,
+    word_type: String,
+    word: String,
+    usage_note: Option<String>,
     created_at: Option<NaiveDateTime>,
     updated_at: Option<NaiveDateTime>,
+    version: u32,
+}
+
+impl From<WordRow> for Word {
+    fn from(row: WordRow) -> Self {
+        Self {
+            id: row.id,
+            word_type: row.word_type,
+            word: row.word,
+            senses: Vec::new(),
+            examples: Vec::new(),
+            usage_note: row.usage_note,
+            inflections: Vec::new(),
+            categories: Vec::new(),
+            tags: Vec::new(),
+            flags: Vec::new(),
+            pronunciations: Vec::new(),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            version: row.version,
+        }
+    }
+}
+
+/// The fields of a stored word that can be written back, as exported
+impl From<Word> for UpsertWord {
+    fn from(word: Word) -> Self {
+        Self {
+            word: word.word,
+            senses: word.senses,
+            examples: word.examples,
+            usage_note: word.usage_note,
+            inflections: word.inflections,
+            categories: word.categories,
+            flags: word.flags,
+            pronunciations: word.pronunciations,
+            word_type: word.word_type,
+        }
+    }
+}
+
+/// Applies a JSON Merge Patch (RFC 7396) to a JSON document
+///
+/// Objects are merged member by member, `null` members are removed, and
+/// every other value, arrays included, replaces the one of the document.
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(members) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(Default::default());
+    }
+    if let serde_json::Value::Object(target) = target {
+        for (name, value) in members {
+            if value.is_null() {
+                target.remove(name);
+            } else {
+                merge_patch(
+                    target
+                        .entry(name.clone())
+                        .or_insert(serde_json::Value::Null),
+                    value,
+                );
+            }
+        }
+    }
 }
 
 impl Word {
-    /// Retrieves all words from the database (admin only)
-    pub async fn list(dbpool: SqlitePool, lang: &str) -> Result<Vec<Self>, AppError> {
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
+    /// Fields of a word a merge patch can change, named as in JSON
+    pub const PATCHABLE_FIELDS: [&str; 9] = [
+        "word",
+        "wordType",
+        "senses",
+        "examples",
+        "usageNote",
+        "inflections",
+        "categories",
+        "flags",
+        "pronunciations",
+    ];
+
+    /// Returns the entity tag of the stored version of the word
+    pub fn etag(&self) -> String {
+        etag(self.version)
+    }
+
+    /// Returns the time of the last change of the word, or of its creation
+    pub fn last_modified(&self) -> Option<NaiveDateTime> {
+        self.updated_at.or(self.created_at)
+    }
 
-        // form the query with the right table
-        let my_query = format!("SELECT * FROM {}", language_code.table_name());
+    /// Whether the copy of the word a client holds is still current
+    pub fn not_modified(
+        &self,
+        if_none_match: Option<&str>,
+        if_modified_since: Option<&str>,
+    ) -> bool {
+        not_modified(
+            if_none_match,
+            if_modified_since,
+            self.version,
+            self.last_modified(),
+        )
+    }
 
-        // perform the actual query
-        query_as(&my_query)
-            .fetch_all(&dbpool)
-            .await
-            .map_err(Into::into)
+    /// Retrieves one page of the words of a language (admin only)
+    ///
+    /// Words are sorted by the requested field, ties broken by ID, and start
+    /// after the cursor when given. The total counts the words matching the
+    /// filters in every page.
+    pub async fn page(
+        dbpool: SqlitePool,
+        lang: &str,
+        params: &ListParams,
+    ) -> Result<WordPage, AppError> {
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
+
+        // if the sort, cursor and filters are in the allowed ones
+        let sort = params.sort()?;
+        let order = params.order()?;
+        let cursor = params.cursor()?;
+        let word_type = match &params.word_type {
+            Some(t) => Some(
+                GrammaticalType::resolve(&dbpool, &language, t)
+                    .await?
+                    .type_name()
+                    .to_string(),
+            ),
+            None => None,
+        };
+        let contains = params.contains();
+        let [created_after, created_before, updated_after, updated_before] =
+            params.time_bounds()?;
+
+        let code = language.code();
+        let sort_name = sort.field_name();
+        let descending = order == SortOrder::Desc;
+        let (after_key, after_id) = match &cursor {
+            Some(cursor) => (Some(cursor.key.as_str()), Some(cursor.id)),
+            None => (None, None),
+        };
+        // one more word than the page tells whether a next page exists
+        let page_limit = params.limit() as usize;
+        let limit = params.limit() + 1;
+
+        let total = query_scalar!(
+            r#"SELECT COUNT(*) AS "count!: i64" FROM words WHERE language = $1 AND deleted_at IS NULL AND ($2 IS NULL OR word_type = $2) AND ($3 IS NULL OR instr(word, $3) > 0) AND ($4 IS NULL OR created_at > $4) AND ($5 IS NULL OR created_at < $5) AND ($6 IS NULL OR updated_at > $6) AND ($7 IS NULL OR updated_at < $7)"#,
+            code,
+            word_type,
+            contains,
+            created_after,
+            created_before,
+            updated_after,
+            updated_before
+        )
+        .fetch_one(&dbpool)
+        .await?;
+
+        let mut rows = query!(
+            r#"SELECT id AS "id!: u32", word_type AS "word_type!", word AS "word!", usage_note, created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime", version AS "version!: u32", sort_key AS "sort_key!: String" FROM (SELECT *, CASE $8 WHEN 'created_at' THEN COALESCE(created_at, '') WHEN 'updated_at' THEN COALESCE(updated_at, created_at, '') ELSE word END AS sort_key FROM words WHERE language = $1 AND deleted_at IS NULL AND ($2 IS NULL OR word_type = $2) AND ($3 IS NULL OR instr(word, $3) > 0) AND ($4 IS NULL OR created_at > $4) AND ($5 IS NULL OR created_at < $5) AND ($6 IS NULL OR updated_at > $6) AND ($7 IS NULL OR updated_at < $7)) WHERE $10 IS NULL OR CASE WHEN $9 THEN sort_key < $10 OR (sort_key = $10 AND id < $11) ELSE sort_key > $10 OR (sort_key = $10 AND id > $11) END ORDER BY CASE WHEN $9 THEN sort_key END DESC, CASE WHEN $9 THEN id END DESC, sort_key, id LIMIT $12"#,
+            code,
+            word_type,
+            contains,
+            created_after,
+            created_before,
+            updated_after,
+            updated_before,
+            sort_name,
+            descending,
+            after_key,
+            after_id,
+            limit
+        )
+        .fetch_all(&dbpool)
+        .await?;
+
+        let next = match rows.len() > page_limit {
+            true => {
+                rows.truncate(page_limit);
+                rows.last().map(|row| Cursor {
+                    sort,
+                    order,
+                    key: row.sort_key.clone(),
+                    id: row.id,
+                })
+            }
+            false => None,
+        };
+
+        let mut conn = dbpool.acquire().await?;
+        let mut words = Vec::with_capacity(rows.len());
+        for row in rows {
+            let word = Self::from(WordRow {
+                id: row.id,
+                word_type: row.word_type,
+                word: row.word,
+                usage_note: row.usage_note,
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+                version: row.version,
+            });
+            words.push(word.with_children(&mut conn).await?);
+        }
+
+        Ok(WordPage { words, total, next })
     }
 
     /// Creates a new word in the database with validation
+    ///
+    /// The creation is recorded as a revision made by the acting admin.
     pub async fn create(
         dbpool: SqlitePool,
         lang: &str,
         new_word: UpsertWord,
+        actor: Option<&AuthUser>,
     ) -> Result<Vec<Self>, AppError> {
-        let word = new_word.word()?.to_lowercase();
-        let definition = new_word.definition()?.to_lowercase();
-        let pronunciation = new_word.pronunciation()?.to_lowercase();
-        let word_type = new_word.word_type()?.to_lowercase();
-
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
-
-        // form the query with the right table
-        let my_query = format!("INSERT INTO {} (word, definition, pronunciation, word_type) VALUES ($1, $2, $3, $4) RETURNING *", language_code.table_name());
-
-        // perform the actual query
-        query_as(&my_query)
-            .bind(word)
-            .bind(definition)
-            .bind(pronunciation)
-            .bind(word_type)
-            .fetch_all(&dbpool)
-            .await
-            .map_err(Into::into)
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
+
+        // normalized and written in the scripts and phoneme inventory of the language
+        let new_word = new_word.conform(language.profile())?;
+
+        // the word and all of its child rows are stored together or not at all
+        let mut tx = dbpool.begin().await?;
+        let id = Self::store_revision(
+            &mut tx,
+            language.code(),
+            None,
+            &new_word,
+            RevisionAction::Create,
+            actor,
+        )
+        .await?;
+        tx.commit().await?;
+
+        match id {
+            Some(id) => Self::read(dbpool, lang, id).await,
+            None => Ok(Vec::new()),
+        }
     }
 
     /// Retrieves a specific word by ID
+    ///
+    /// Returns `LookupError::UnknownWord` when no word of the language has
+    /// the ID, or the word is in the trash.
     pub async fn read(dbpool: SqlitePool, lang: &str, id: u32) -> Result<Vec<Self>, AppError> {
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
 
-        // form the query with the right table
-        let my_query = format!("SELECT * FROM {} WHERE id = $1", language_code.table_name());
+        let mut conn = dbpool.acquire().await?;
+        match Self::load(&mut conn, language.code(), id).await? {
+            Some(word) => Ok(vec![word]),
+            None => Err(LookupError::UnknownWord(format!("#{id}")).into()),
+        }
+    }
 
-        // perform the actual query
-        query_as(&my_query)
-            .bind(id)
-            .fetch_all(&dbpool)
-            .await
-            .map_err(Into::into)
+    /// Loads a word with its child rows, `None` when it doesn't exist or is in the trash
+    ///
+    /// Takes a connection so that a transaction sees its own changes.
+    pub(crate) async fn load(
+        conn: &mut SqliteConnection,
+        code: &str,
+        id: u32,
+    ) -> Result<Option<Self>, AppError> {
+        let row = query_as!(
+            WordRow,
+            r#"SELECT id AS "id: u32", word_type, word, usage_note, created_at AS "created_at: NaiveDateTime", updated_at AS "updated_at: NaiveDateTime", version AS "version: u32" FROM words WHERE language = $1 AND id = $2 AND deleted_at IS NULL"#,
+            code,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(Self::from(row).with_children(conn).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Loads the child rows of a single word
+    async fn with_children(mut self, conn: &mut SqliteConnection) -> Result<Self, AppError> {
+        self.senses = WordSense::for_word(conn, self.id).await?;
+        self.examples = WordExample::for_word(conn, self.id).await?;
+        self.inflections = WordInflection::for_word(conn, self.id).await?;
+        self.categories = TypeCategory::for_word(conn, self.id).await?;
+        self.tags = Tag::for_word(conn, self.id).await?;
+        self.flags = ContentFlag::for_word(conn, self.id).await?;
+        self.pronunciations = PronunciationVariant::for_word(conn, self.id).await?;
+        Ok(self)
+    }
+
+    /// Sends the words of a language one by one, in lemma order (admin only)
+    ///
+    /// Words are read from a cursor and loaded with their child rows one at a
+    /// time, so the whole table is never held in memory. The type, category
+    /// and tag filters narrow the words down when given. Stops early when the
+    /// receiving end is dropped.
+    pub(crate) async fn send_all(
+        dbpool: &SqlitePool,
+        code: &str,
+        word_type: Option<&str>,
+        category: Option<&str>,
+        tags: &TagFilter,
+        sender: &mpsc::Sender<Result<Self, AppError>>,
+    ) -> Result<(), AppError> {
+        let include = tags.include_json();
+        let exclude = tags.exclude_json();
+        let mut rows = query_as!(
+            WordRow,
+            r#"SELECT w.id AS "id: u32", w.word_type, w.word, w.usage_note, w.created_at AS "created_at: NaiveDateTime", w.updated_at AS "updated_at: NaiveDateTime", w.version AS "version: u32" FROM words w WHERE w.language = $1 AND w.deleted_at IS NULL AND ($2 IS NULL OR w.word_type = $2) AND ($3 IS NULL OR EXISTS (SELECT 1 FROM word_categories c WHERE c.word_id = w.id AND c.category = $3)) AND ($4 IS NULL OR EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($4)))) AND ($5 IS NULL OR NOT EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($5)))) ORDER BY w.word, w.id"#,
+            code,
+            word_type,
+            category,
+            include,
+            exclude
+        )
+        .fetch(dbpool);
+
+        // child rows are read on another connection than the cursor
+        let mut conn = dbpool.acquire().await?;
+        while let Some(row) = rows.try_next().await? {
+            let word = Self::from(row).with_children(&mut conn).await;
+            if sender.send(word).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
     }
 
     /// Updates an existing word in the database
+    ///
+    /// The update is recorded as a revision made by the acting admin.
+    /// Returns `LookupError::UnknownWord` when no word of the language has
+    /// the ID, or the word is in the trash.
     pub async fn update(
         dbpool: SqlitePool,
         lang: &str,
         id: u32,
         updated_word: UpsertWord,
+        actor: Option<&AuthUser>,
+        if_match: Option<&IfMatch>,
     ) -> Result<Vec<Self>, AppError> {
-        let word = updated_word.word()?.to_lowercase();
-        let definition = updated_word.definition()?.to_lowercase();
-        let pronunciation = updated_word.pronunciation()?.to_lowercase();
-        let word_type = updated_word.word_type()?.to_lowercase();
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
+
+        // normalized and written in the scripts and phoneme inventory of the language
+        let updated_word = updated_word.conform(language.profile())?;
+
+        // the word and all of its child rows are stored together or not at all
+        let mut tx = dbpool.begin().await?;
+        Self::check_version(&mut tx, language.code(), id, if_match).await?;
+        let stored = Self::store_revision(
+            &mut tx,
+            language.code(),
+            Some(id),
+            &updated_word,
+            RevisionAction::Update,
+            actor,
+        )
+        .await?;
+        if stored.is_none() {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        }
+        tx.commit().await?;
 
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
+        Self::read(dbpool, lang, id).await
+    }
 
-        // form the query with the right table
-        let my_query = format!("UPDATE {} SET word = $1, definition = $2, pronunciation = $3, word_type = $4 WHERE id = $5 RETURNING *", language_code.table_name());
+    /// Checks the stored version of a word against an `If-Match` precondition
+    ///
+    /// Returns `PreconditionError::VersionMismatch` when the word changed
+    /// since the client read it, or doesn't exist anymore.
+    pub(crate) async fn check_version(
+        tx: &mut SqliteConnection,
+        code: &str,
+        id: u32,
+        if_match: Option<&IfMatch>,
+    ) -> Result<(), AppError> {
+        let Some(if_match) = if_match else {
+            return Ok(());
+        };
 
-        // perform the actual query
-        query_as(&my_query)
-            .bind(word)
-            .bind(definition)
-            .bind(pronunciation)
-            .bind(word_type)
-            .bind(id)
-            .fetch_all(&dbpool)
-            .await
-            .map_err(Into::into)
-    }
-
-    /// Deletes a word from the database
-    pub async fn delete(dbpool: SqlitePool, lang: &str, id: u32) -> Result<(), AppError> {
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
-
-        // form the query with the right table
-        let my_query = format!("DELETE FROM {} WHERE id = $1", language_code.table_name());
+        let version = query_scalar!(
+            r#"SELECT version AS "version: u32" FROM words WHERE id = $1 AND language = $2 AND deleted_at IS NULL"#,
+            id,
+            code
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        match version {
+            Some(version) if if_match.matches(version) => Ok(()),
+            _ => Err(PreconditionError::VersionMismatch.into()),
+        }
+    }
+
+    /// Inserts a word with its child rows, or updates the word with the given ID
+    ///
+    /// The word must already be validated and conform to the validation
+    /// profile of the language. Returns the ID of the stored word, or `None` when no word
+    /// of the language has the given ID.
+    pub(crate) async fn store(
+        tx: &mut SqliteConnection,
+        code: &str,
+        id: Option<u32>,
+        upsert: &UpsertWord,
+    ) -> Result<Option<u32>, AppError> {
+        let UpsertWord {
+            word,
+            senses,
+            examples,
+            usage_note,
+            inflections,
+            categories,
+            flags,
+            pronunciations,
+            word_type,
+        } = upsert.lowercased();
+
+        // a deleted word holds its lemma until it is restored or purged
+        let trashed = query_scalar!(
+            r#"SELECT id AS "id: u32" FROM words WHERE language = $1 AND word = $2 AND deleted_at IS NOT NULL AND id IS NOT $3"#,
+            code,
+            word,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        if trashed.is_some() {
+            return Err(TrashError::InTrash(word).into());
+        }
+
+        let stored = match id {
+            None => Some(
+                query_scalar!(
+                    r#"INSERT INTO words (language, word, word_type, usage_note) VALUES ($1, $2, $3, $4) RETURNING id AS "id!: u32""#,
+                    code,
+                    word,
+                    word_type,
+                    usage_note
+                )
+                .fetch_one(&mut *tx)
+                .await?,
+            ),
+            Some(id) => query_scalar!(
+                r#"UPDATE words SET word = $1, word_type = $2, usage_note = $3, version = version + 1 WHERE id = $4 AND language = $5 AND deleted_at IS NULL RETURNING id AS "id!: u32""#,
+                word,
+                word_type,
+                usage_note,
+                id,
+                code
+            )
+            .fetch_optional(&mut *tx)
+            .await?,
+        };
+
+        if let Some(id) = stored {
+            WordSense::replace_all(tx, id, &senses).await?;
+            WordExample::replace_all(tx, id, &examples).await?;
+            WordInflection::replace_all(tx, id, &inflections).await?;
+            TypeCategory::replace_all(tx, id, &categories).await?;
+            ContentFlag::replace_all(tx, id, &flags).await?;
+            PronunciationVariant::replace_all(tx, id, &pronunciations).await?;
+        }
+        Ok(stored)
+    }
+
+    /// Validates and stores a word like `store`, recording the change as a revision
+    ///
+    /// The word is snapshotted before and after the change in the same
+    /// transaction, so the revision is exactly what was written.
+    pub(crate) async fn store_revision(
+        tx: &mut SqliteConnection,
+        code: &str,
+        id: Option<u32>,
+        upsert: &UpsertWord,
+        action: RevisionAction,
+        actor: Option<&AuthUser>,
+    ) -> Result<Option<u32>, AppError> {
+        upsert.validate()?;
+        Self::revise(tx, code, id, upsert, action, actor).await
+    }
+
+    /// Stores a word and records the change, leaving its validation to the caller
+    async fn revise(
+        tx: &mut SqliteConnection,
+        code: &str,
+        id: Option<u32>,
+        upsert: &UpsertWord,
+        action: RevisionAction,
+        actor: Option<&AuthUser>,
+    ) -> Result<Option<u32>, AppError> {
+        let before = match id {
+            Some(id) => Self::load(tx, code, id).await?,
+            None => None,
+        };
+        let Some(stored) = Self::store(tx, code, id, upsert).await? else {
+            return Ok(None);
+        };
+        let after = Self::load(tx, code, stored).await?;
+
+        WordRevision::record(
+            tx,
+            code,
+            stored,
+            action,
+            actor,
+            before.as_ref(),
+            after.as_ref(),
+        )
+        .await?;
+        Ok(Some(stored))
+    }
+
+    /// Partially updates a word with a JSON Merge Patch (admin only)
+    ///
+    /// Only the fields of the patch change, the others are kept as stored.
+    /// Only the fields of the patch are validated and checked against the
+    /// profile of the language, so a stored word breaking newer rules can
+    /// still be patched. Cross-field rules such as categories applying to
+    /// the type are checked when the patch changes one of their fields.
+    pub async fn patch(
+        dbpool: SqlitePool,
+        lang: &str,
+        id: u32,
+        patch: &serde_json::Value,
+        actor: Option<&AuthUser>,
+        if_match: Option<&IfMatch>,
+    ) -> Result<Self, AppError> {
+        let serde_json::Value::Object(members) = patch else {
+            return Err(PatchError::NotAnObject.into());
+        };
+        if let Some(name) = members
+            .keys()
+            .find(|name| !Self::PATCHABLE_FIELDS.contains(&name.as_str()))
+        {
+            return Err(PatchError::UnknownField(name.clone()).into());
+        }
+        let fields: Vec<&str> = members.keys().map(String::as_str).collect();
+
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
+
+        let Some(stored) = Self::read(dbpool.clone(), lang, id).await?.pop() else {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        };
+        if if_match.is_some_and(|if_match| !if_match.matches(stored.version)) {
+            return Err(PreconditionError::VersionMismatch.into());
+        }
+        // the patch only applies to the version it was merged into
+        let merged_into = IfMatch::Versions(vec![stored.version]);
+        let mut document = serde_json::to_value(UpsertWord::from(stored))?;
+        merge_patch(&mut document, patch);
+        let patched: UpsertWord = serde_json::from_value(document)
+            .map_err(|e| PatchError::MalformedWord(e.to_string()))?;
+        let patched = patched.conform_fields(language.profile(), &fields)?;
+        patched.validate_fields(&fields)?;
+
+        let mut tx = dbpool.begin().await?;
+        Self::check_version(&mut tx, language.code(), id, Some(&merged_into)).await?;
+        let stored = Self::revise(
+            &mut tx,
+            language.code(),
+            Some(id),
+            &patched,
+            RevisionAction::Update,
+            actor,
+        )
+        .await?;
+        if stored.is_none() {
+            return Err(LookupError::UnknownWord(format!("#{id}")).into());
+        }
+        tx.commit().await?;
+
+        Self::read(dbpool, lang, id)
+            .await?
+            .pop()
+            .ok_or_else(|| LookupError::UnknownWord(format!("#{id}")).into())
+    }
+
+    /// Returns the database ID of a lemma, or `LookupError::UnknownWord`
+    pub async fn find_id(
+        dbpool: &SqlitePool,
+        language: &Language,
+        lemma: &str,
+    ) -> Result<u32, AppError> {
+        let code = language.code();
+        let lemma_lowercase = lemma.to_lowercase();
+        let id = query_scalar!(
+            r#"SELECT id AS "id: u32" FROM words WHERE language = $1 AND word = $2 AND deleted_at IS NULL"#,
+            code,
+            lemma_lowercase
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        id.ok_or_else(|| LookupError::UnknownWord(lemma.to_string()).into())
+    }
+
+    /// Moves a word to the trash
+    ///
+    /// The word and its child rows are kept, but it is left out of every
+    /// query until it is restored. It is purged for good, along with its
+    /// senses, examples, inflections, tags, pronunciations and relations to
+    /// other words, once the trash retention is over. The deletion is
+    /// recorded as a revision made by the acting admin.
+    pub async fn delete(
+        dbpool: SqlitePool,
+        lang: &str,
+        id: u32,
+        actor: Option<&AuthUser>,
+        if_match: Option<&IfMatch>,
+    ) -> Result<(), AppError> {
+        // if the language is registered
+        let language = Language::find(&dbpool, lang).await?;
 
         // perform the actual query
-        query(&my_query).bind(id).execute(&dbpool).await?;
+        let code = language.code();
+        let mut tx = dbpool.begin().await?;
+        Self::check_version(&mut tx, code, id, if_match).await?;
+        Self::trash(&mut tx, code, id, actor).await?;
+        tx.commit().await?;
         Ok(())
     }
+
+    /// Moves a word to the trash in a transaction, recording the deletion
+    ///
+    /// Returns whether the word was moved, false when no word of the
+    /// language has the ID or it is already in the trash.
+    pub(crate) async fn trash(
+        tx: &mut SqliteConnection,
+        code: &str,
+        id: u32,
+        actor: Option<&AuthUser>,
+    ) -> Result<bool, AppError> {
+        let Some(before) = Self::load(tx, code, id).await? else {
+            return Ok(false);
+        };
+        query!(
+            "UPDATE words SET deleted_at = DATETIME('NOW', 'subsec'), version = version + 1 WHERE id = $1 AND language = $2 AND deleted_at IS NULL",
+            id,
+            code
+        )
+        .execute(&mut *tx)
+        .await?;
+        WordRevision::record(
+            tx,
+            code,
+            id,
+            RevisionAction::Delete,
+            actor,
+            Some(&before),
+            None,
+        )
+        .await?;
+        Ok(true)
+    }
+}
+
+/// Result shapes of the random word endpoints
+///
+/// # Supported Modes
+/// - `word` - A single random word (default)
+/// - `homophones` - A random group of words sharing a pronunciation
+/// - `bilingual` - A random word followed by its translation into another language
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum RandomMode {
+    #[default]
+    #[strum(serialize = "word")]
+    Word,
+    #[strum(serialize = "homophones")]
+    Homophones,
+    #[strum(serialize = "bilingual")]
+    Bilingual,
+}
+
+/// Query parameters selecting the random word mode
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct RandomParams {
+    /// Random mode: `word` (default), `homophones` or `bilingual`
+    pub mode: Option<String>,
+    /// Code of the language to translate to, required in `bilingual` mode
+    pub to: Option<String>,
+}
+
+impl RandomParams {
+    /// Returns the requested mode, `word` if the parameter is absent
+    pub fn mode(&self) -> Result<RandomMode, QueryError> {
+        match &self.mode {
+            Some(mode) => RandomMode::from_str(mode)
+                .map_err(|_| QueryError::InvalidParameter("mode".to_string(), mode.clone())),
+            None => Ok(RandomMode::default()),
+        }
+    }
+
+    /// Returns the language to translate to in `bilingual` mode
+    pub fn to(&self) -> Result<&str, QueryError> {
+        self.to
+            .as_deref()
+            .ok_or_else(|| QueryError::MissingParameter("to".to_string()))
+    }
+}
+
+/// Senses returned with each word by the public endpoints
+///
+/// # Supported Selections
+/// - `all` - Every sense in display order, `definition` is the primary one (default)
+/// - `random` - A single random sense, also used as `definition`
+#[derive(Debug, Default, Clone, Copy, PartialEq, EnumString)]
+pub enum SenseSelection {
+    #[default]
+    #[strum(serialize = "all")]
+    All,
+    #[strum(serialize = "random")]
+    Random,
+}
+
+/// Query parameters selecting the senses returned with each word
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct SenseParams {
+    /// Senses to return: `all` (default) or `random` for a single random sense
+    pub sense: Option<String>,
+}
+
+impl SenseParams {
+    /// Returns the requested selection, `all` if the parameter is absent
+    pub fn selection(&self) -> Result<SenseSelection, QueryError> {
+        match &self.sense {
+            Some(sense) => SenseSelection::from_str(sense)
+                .map_err(|_| QueryError::InvalidParameter("sense".to_string(), sense.clone())),
+            None => Ok(SenseSelection::default()),
+        }
+    }
+}
+
+/// Query parameters asking for an example sentence with each word
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
+pub struct ExampleParams {
+    /// Adds a random example sentence and the usage note of each word
+    #[serde(default)]
+    pub example: bool,
 }
 
 /// Public word response structure for API endpoints.
@@ -281,8 +1184,15 @@ impl Word {
 /// # Fields
 ///
 /// - `word`: The actual word/lemma following dictionary standards
-/// - `definition`: Human-readable definition of the word
-/// - `pronunciation`: IPA phonetic notation enclosed in forward slashes
+/// - `definition`: Human-readable definition of the primary (or randomly picked) sense
+/// - `senses`: Senses of the word with part of speech and example, when loaded
+/// - `pronunciation`: IPA phonetic notation enclosed in forward slashes, in the
+///   preferred variant when available and the primary pronunciation otherwise
+/// - `variant`: Region or variant label of the returned pronunciation
+/// - `example`: A random example sentence, only present when requested with `example`
+/// - `usage_note`: Note on register or usage, only present when requested with `example`
+/// - `respelling`, `arpabet`, `xsampa`: Optional notations derived from the IPA,
+///   only present when requested with `pronunciation_format`
 ///
 /// # Language Support
 ///
@@ -299,57 +1209,214 @@ impl Word {
 /// - Adjectives for descriptive word requests
 /// - Adverbs for modifier-based word requests
 ///
-#[derive(ToSchema, Deserialize, Serialize, Clone, sqlx::FromRow)]
+#[derive(ToSchema, Deserialize, Serialize, Clone, Default, sqlx::FromRow)]
 #[serde(rename_all = "camelCase")]
 pub struct GetWord {
     word: String,
     definition: String,
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    senses: Vec<WordSense>,
     pronunciation: String,
+    #[sqlx(default)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    variant: Option<String>,
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    example: Option<WordExample>,
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    usage_note: Option<String>,
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    respelling: Option<String>,
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    arpabet: Option<String>,
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    xsampa: Option<String>,
+}
+
+/// A word selected by the public endpoints, before its optional data is added
+pub(crate) struct RandomWordRow {
+    pub(crate) word: String,
+    pub(crate) definition: String,
+    pub(crate) pronunciation: String,
+    pub(crate) variant: String,
+}
+
+impl From<RandomWordRow> for GetWord {
+    fn from(row: RandomWordRow) -> Self {
+        Self {
+            word: row.word,
+            definition: row.definition,
+            pronunciation: row.pronunciation,
+            variant: Some(row.variant),
+            ..Self::default()
+        }
+    }
 }
 
 impl GetWord {
-    /// Retrieves a random word from the database
-    pub async fn random_word(dbpool: SqlitePool, lang: &str) -> Result<Vec<Self>, AppError> {
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
-
-        // form the query with the right table
-        let my_query = format!(
-            "SELECT word, definition, pronunciation FROM {} ORDER BY random() LIMIT 1",
-            language_code.table_name()
-        );
+    /// Adds the requested notations derived from the IPA pronunciation
+    ///
+    /// Words of languages without conversion tables are returned unchanged.
+    pub fn with_pronunciation_formats(
+        mut self,
+        tables: Option<&PronunciationTables>,
+        formats: &[PronunciationFormat],
+    ) -> Self {
+        let Some(tables) = tables else {
+            return self;
+        };
 
-        // perform the actual query
-        query_as(&my_query)
-            .fetch_all(&dbpool)
-            .await
-            .map_err(Into::into)
+        for format in formats {
+            let converted = tables.convert(&self.pronunciation, *format);
+            match format {
+                PronunciationFormat::Ipa => {}
+                PronunciationFormat::Respelling => self.respelling = converted,
+                PronunciationFormat::Arpabet => self.arpabet = converted,
+                PronunciationFormat::XSampa => self.xsampa = converted,
+            }
+        }
+
+        self
+    }
+
+    /// Adds the senses of the word, all of them or a single random one
+    ///
+    /// With a random selection, `definition` follows the picked sense.
+    pub async fn with_senses(
+        mut self,
+        dbpool: &SqlitePool,
+        lang: &str,
+        selection: SenseSelection,
+    ) -> Result<Self, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(dbpool, lang).await?;
+
+        let mut senses = WordSense::for_lemma(dbpool, &language, &self.word).await?;
+        if selection == SenseSelection::Random && !senses.is_empty() {
+            let index = (getrandom::u64()? % senses.len() as u64) as usize;
+            let sense = senses.swap_remove(index);
+            self.definition = sense.definition.clone();
+            senses = vec![sense];
+        }
+        self.senses = senses;
+
+        Ok(self)
+    }
+
+    /// Adds a random example sentence and the usage note of the word
+    ///
+    /// Words without examples or usage note are returned unchanged.
+    pub async fn with_example(mut self, dbpool: &SqlitePool, lang: &str) -> Result<Self, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(dbpool, lang).await?;
+
+        let code = language.code();
+        self.example = query_as!(
+            WordExample,
+            r#"SELECT e.sentence, e.target_start AS "target_start: u32", e.target_end AS "target_end: u32" FROM word_examples e JOIN words w ON w.id = e.word_id WHERE w.language = $1 AND w.word = $2 AND w.deleted_at IS NULL ORDER BY random() LIMIT 1"#,
+            code,
+            self.word
+        )
+        .fetch_optional(dbpool)
+        .await?;
+
+        self.usage_note = query_scalar!(
+            "SELECT usage_note FROM words WHERE language = $1 AND word = $2 AND deleted_at IS NULL",
+            code,
+            self.word
+        )
+        .fetch_optional(dbpool)
+        .await?
+        .flatten();
+
+        Ok(self)
+    }
+
+    /// Retrieves a random word from the database
+    ///
+    /// The pronunciation is taken from the preferred `variant` when the word
+    /// has one, and falls back to the primary pronunciation otherwise. Only
+    /// words passing the tag filter are drawn, and in safe mode only words
+    /// without content flags.
+    pub async fn random_word(
+        dbpool: SqlitePool,
+        lang: &str,
+        variant: Option<&str>,
+        tags: &TagFilter,
+        safe: bool,
+    ) -> Result<Vec<Self>, AppError> {
+        // if the language is registered and enabled
+        let language = Language::resolve(&dbpool, lang).await?;
+
+        // perform the actual query, with the primary sense join, the preferred
+        // pronunciation join, the tag filters and the safe mode filter spelled
+        // out so that the statement is checked at compile time
+        let code = language.code();
+        let include = tags.include_json();
+        let exclude = tags.exclude_json();
+        let words = query_as!(
+            RandomWordRow,
+            r#"SELECT w.word, s.definition, p.pronunciation, p.variant FROM words w JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY variant = $1 DESC, position LIMIT 1) WHERE w.language = $5 AND w.deleted_at IS NULL AND ($2 IS NULL OR EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($2)))) AND ($3 IS NULL OR NOT EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($3)))) AND ($4 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) ORDER BY random() LIMIT 1"#,
+            variant,
+            include,
+            exclude,
+            safe,
+            code
+        )
+        .fetch_all(&dbpool)
+        .await?;
+
+        Ok(words.into_iter().map(Self::from).collect())
     }
 
     /// Retrieves a random word of a specific grammatical type
+    ///
+    /// The type can be named in the language of the words. With a category,
+    /// the word belongs to that sub-category of the type. The pronunciation,
+    /// tag filter and safe mode apply like in [`GetWord::random_word`].
     pub async fn random_type(
         dbpool: SqlitePool,
         lang: &str,
         word_type: &str,
+        category: &CategoryParams,
+        variant: Option<&str>,
+        tags: &TagFilter,
+        safe: bool,
     ) -> Result<Vec<Self>, AppError> {
-        // if the language code is in the allowed ones
-        let language_code =
-            LanguageCode::from_str(lang).map_err(|_| PathError::InvalidPath(lang.to_string()))?;
-
-        // if the grammatical type is in the allowed ones
-        let grammatical_type = GrammaticalType::from_str(word_type)
-            .map_err(|_| PathError::InvalidWordType(word_type.to_string()))?;
-
-        // form the query with the right table
-        let my_query = format!("SELECT word, definition, pronunciation FROM {} WHERE word_type = $1 ORDER BY random() LIMIT 1", language_code.table_name());
-
-        // perform the actual query
-        query_as(&my_query)
-            .bind(grammatical_type.type_name())
-            .fetch_all(&dbpool)
-            .await
-            .map_err(Into::into)
+        // if the language is registered and enabled
+        let language = Language::resolve(&dbpool, lang).await?;
+
+        // if the grammatical type is in the allowed ones, and the category one of its own
+        let grammatical_type = GrammaticalType::resolve(&dbpool, &language, word_type).await?;
+        let category = category
+            .category(&grammatical_type)?
+            .map(|c| c.category_name().to_string());
+
+        // perform the actual query, spelled out like in `random_word`
+        let code = language.code();
+        let type_name = grammatical_type.type_name();
+        let include = tags.include_json();
+        let exclude = tags.exclude_json();
+        let words = query_as!(
+            RandomWordRow,
+            r#"SELECT w.word, s.definition, p.pronunciation, p.variant FROM words w JOIN word_senses s ON s.id = (SELECT id FROM word_senses WHERE word_id = w.id ORDER BY position LIMIT 1) JOIN word_pronunciations p ON p.id = (SELECT id FROM word_pronunciations WHERE word_id = w.id ORDER BY variant = $1 DESC, position LIMIT 1) WHERE w.language = $6 AND w.word_type = $2 AND w.deleted_at IS NULL AND ($3 IS NULL OR EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($3)))) AND ($4 IS NULL OR NOT EXISTS (SELECT 1 FROM word_tags wt JOIN tags t ON t.id = wt.tag_id WHERE wt.word_id = w.id AND t.name IN (SELECT value FROM json_each($4)))) AND ($5 = 0 OR NOT EXISTS (SELECT 1 FROM word_flags f WHERE f.word_id = w.id)) AND ($7 IS NULL OR EXISTS (SELECT 1 FROM word_categories c WHERE c.word_id = w.id AND c.category = $7)) ORDER BY random() LIMIT 1"#,
+            variant,
+            type_name,
+            include,
+            exclude,
+            safe,
+            code,
+            category
+        )
+        .fetch_all(&dbpool)
+        .await?;
+
+        Ok(words.into_iter().map(Self::from).collect())
     }
 }
 
@@ -363,17 +1430,56 @@ impl GetWord {
 ///
 /// - `word_type`: Must be one of the allowed grammatical types (noun, verb, adjective, adverb)
 /// - `word`: Must be a valid lemma (no whitespace, follows Merriam-Webster format)
-/// - `definition`: Must contain only alphabetic characters, punctuation, and whitespace
-/// - `pronunciation`: Must be valid IPA notation enclosed in forward slashes
+/// - `senses`: At least one entry, each with a definition and optional example made of
+///   alphabetic characters, punctuation, and whitespace, and an allowed part of speech.
+///   The same definition can't be listed twice. The first entry is the primary.
+/// - `examples`: Optional sentences kept as written, made of the same characters as
+///   definitions, with the offsets of the word marking a single token of the sentence.
+///   The same sentence can't be listed twice.
+/// - `usage_note`: Optional note kept as written, made of the same characters as definitions
+/// - `inflections`: Optional inflected forms following the lemma rules, each of a kind
+///   that applies to the word type or to the part of speech of one of its senses
+///   (plural for nouns, past tense and past participle for verbs, comparative and
+///   superlative for adjectives and adverbs). The same form can't be listed twice for a kind.
+/// - `categories`: Optional grammatical sub-categories, each applying to the word type or
+///   to the part of speech of one of its senses (proper, countable and uncountable for
+///   nouns, transitive and intransitive for verbs). The same category can't be listed twice.
+/// - `flags`: Optional content flags, each one of offensive, mature, archaic or slang.
+///   The same flag can't be listed twice.
+/// - `pronunciations`: At least one entry, each with valid IPA notation enclosed in
+///   forward slashes and a variant label of lowercase letters, digits and hyphens.
+///   The same pronunciation can't be listed twice. The first entry is the primary.
+///
+/// On create and update, all text is normalized to Unicode NFC and checked
+/// against the validation profile of the language: lemmas, inflections and
+/// definitions must be written in its scripts (definitions may also quote
+/// Latin words), and pronunciations in its phoneme inventory.
 #[derive(ToSchema, Deserialize, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_inflection_kinds"))]
+#[validate(schema(function = "validate_type_categories"))]
 pub struct UpsertWord {
     #[validate(length(min = 1), custom(function = "validate_word"))]
     pub word: String,
-    #[validate(length(min = 1), custom(function = "validate_definition"))]
-    pub definition: String,
-    #[validate(length(min = 1), custom(function = "validate_pronunciation"))]
-    pub pronunciation: String,
+    #[validate(length(min = 1), nested, custom(function = "validate_senses"))]
+    pub senses: Vec<WordSense>,
+    #[serde(default)]
+    #[validate(nested, custom(function = "validate_examples"))]
+    pub examples: Vec<WordExample>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 500), custom(function = "validate_definition"))]
+    pub usage_note: Option<String>,
+    #[serde(default)]
+    #[validate(nested, custom(function = "validate_inflections"))]
+    pub inflections: Vec<WordInflection>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_categories"))]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_flags"))]
+    pub flags: Vec<String>,
+    #[validate(length(min = 1), nested, custom(function = "validate_pronunciations"))]
+    pub pronunciations: Vec<PronunciationVariant>,
     #[validate(length(min = 1), custom(function = "validate_word_type"))]
     pub word_type: String,
 }
@@ -400,6 +1506,91 @@ fn validate_definition(text: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Validates a list of senses for duplicates
+///
+/// The same definition can't be stored twice for one word.
+fn validate_senses(list: &[WordSense]) -> Result<(), ValidationError> {
+    let mut seen = std::collections::HashSet::new();
+    if !list
+        .iter()
+        .all(|s| seen.insert(s.definition.to_lowercase()))
+    {
+        return Err(ValidationError::new("duplicate_definition"));
+    }
+    Ok(())
+}
+
+/// Validates the marked position of the word in an example sentence
+///
+/// The offsets must fall inside the sentence and mark a single token,
+/// such as "ran" in "she ran home", which may be an inflected form.
+fn validate_example_target(example: &WordExample) -> Result<(), ValidationError> {
+    match example.target() {
+        Some(target) if is_valid_lemma(&target) => Ok(()),
+        _ => Err(ValidationError::new("invalid_example_target")),
+    }
+}
+
+/// Validates a list of examples for duplicates
+///
+/// The same sentence can't be stored twice for one word.
+fn validate_examples(list: &[WordExample]) -> Result<(), ValidationError> {
+    let mut seen = std::collections::HashSet::new();
+    if !list.iter().all(|e| seen.insert(e.sentence.to_lowercase())) {
+        return Err(ValidationError::new("duplicate_example"));
+    }
+    Ok(())
+}
+
+/// Validates a list of inflections for duplicates
+///
+/// The same form can't be stored twice for one kind of inflection.
+fn validate_inflections(list: &[WordInflection]) -> Result<(), ValidationError> {
+    let mut seen = std::collections::HashSet::new();
+    if !list
+        .iter()
+        .all(|i| seen.insert((i.kind.to_lowercase(), i.form.to_lowercase())))
+    {
+        return Err(ValidationError::new("duplicate_inflection"));
+    }
+    Ok(())
+}
+
+/// Validates that each inflection applies to the word
+///
+/// A kind of inflection is allowed when it applies to the word type or to
+/// the part of speech of one of the senses (e.g. a plural for "bank", a
+/// noun which is also a verb).
+fn validate_inflection_kinds(word: &UpsertWord) -> Result<(), ValidationError> {
+    let types = word.grammatical_types();
+    let applies = word.inflections.iter().all(|i| {
+        InflectionKind::from_str(&i.kind.to_lowercase())
+            .map(|kind| types.iter().any(|t| kind.applies_to(t)))
+            .unwrap_or(true)
+    });
+    if !applies {
+        return Err(ValidationError::new("inflection_not_applicable"));
+    }
+    Ok(())
+}
+
+/// Validates that each category applies to the word
+///
+/// Like inflections, a category is allowed when it applies to the word
+/// type or to the part of speech of one of the senses.
+fn validate_type_categories(word: &UpsertWord) -> Result<(), ValidationError> {
+    let types = word.grammatical_types();
+    let applies = word.categories.iter().all(|c| {
+        TypeCategory::from_str(&c.to_lowercase())
+            .map(|category| types.iter().any(|t| category.applies_to(t)))
+            .unwrap_or(true)
+    });
+    if !applies {
+        return Err(ValidationError::new("category_not_applicable"));
+    }
+    Ok(())
+}
+
 /// Validates a pronunciation field for IPA phonetic notation
 ///
 /// Ensures the pronunciation follows International Phonetic Alphabet
@@ -411,6 +1602,35 @@ fn validate_pronunciation(text: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Validates a pronunciation variant label
+///
+/// Accepts short labels such as "us", "uk" or "en-au" made of
+/// lowercase letters, digits and hyphens.
+fn validate_variant(text: &str) -> Result<(), ValidationError> {
+    let valid = text
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid || text.starts_with('-') || text.ends_with('-') {
+        return Err(ValidationError::new("invalid_variant"));
+    }
+    Ok(())
+}
+
+/// Validates a list of pronunciations for duplicates
+///
+/// The same IPA notation can't be stored twice for one word, whatever
+/// its variant label.
+fn validate_pronunciations(list: &[PronunciationVariant]) -> Result<(), ValidationError> {
+    let mut seen = std::collections::HashSet::new();
+    if !list
+        .iter()
+        .all(|p| seen.insert(p.pronunciation.to_lowercase()))
+    {
+        return Err(ValidationError::new("duplicate_pronunciation"));
+    }
+    Ok(())
+}
+
 /// Validates a word_type field for allowed grammatical types
 ///
 /// Checks that the word type is one of the supported grammatical categories:
@@ -422,6 +1642,172 @@ pub fn validate_word_type(text: &str) -> Result<(), ValidationError> {
 }
 
 impl UpsertWord {
+    /// Grammatical types of the word type and of the parts of speech of its senses
+    fn grammatical_types(&self) -> Vec<GrammaticalType> {
+        std::iter::once(&self.word_type)
+            .chain(self.senses.iter().map(|s| &s.part_of_speech))
+            .filter_map(|t| GrammaticalType::from_str(&t.to_lowercase()).ok())
+            .collect()
+    }
+
+    /// Normalizes the text of the word to Unicode NFC and checks it against
+    /// the validation profile of its language
+    ///
+    /// Returns `ProfileError::InvalidCharacter` naming the first field and
+    /// character outside of the scripts or phoneme inventory of the profile.
+    pub fn conform(self, profile: ValidationProfile) -> Result<Self, ProfileError> {
+        self.conform_fields(profile, &Word::PATCHABLE_FIELDS)
+    }
+
+    /// Like `conform`, for the given fields only, named as in JSON
+    pub fn conform_fields(
+        mut self,
+        profile: ValidationProfile,
+        fields: &[&str],
+    ) -> Result<Self, ProfileError> {
+        let given = |field: &str| fields.contains(&field);
+        if given("word") {
+            self.word = normalize(&self.word);
+        }
+        if given("senses") {
+            for sense in &mut self.senses {
+                sense.definition = normalize(&sense.definition);
+                sense.example = sense.example.as_deref().map(normalize);
+            }
+        }
+        if given("examples") {
+            for example in &mut self.examples {
+                *example = example.normalized();
+            }
+        }
+        if given("usageNote") {
+            self.usage_note = self.usage_note.as_deref().map(normalize);
+        }
+        if given("inflections") {
+            for inflection in &mut self.inflections {
+                inflection.form = normalize(&inflection.form);
+            }
+        }
+        if given("pronunciations") {
+            for pronunciation in &mut self.pronunciations {
+                pronunciation.pronunciation = normalize(&pronunciation.pronunciation);
+            }
+        }
+
+        let check = |field: &dyn Fn() -> String, found: Option<char>| match found {
+            Some(character) => Err(ProfileError::InvalidCharacter {
+                field: field(),
+                character,
+                profile: profile.profile_name().to_string(),
+            }),
+            None => Ok(()),
+        };
+
+        if given("word") {
+            check(&|| "word".to_string(), profile.lemma_error(&self.word))?;
+        }
+        if given("senses") {
+            for (i, sense) in self.senses.iter().enumerate() {
+                let definition = profile.text_error(&sense.definition);
+                check(&|| format!("senses[{i}].definition"), definition)?;
+                let example = sense.example.as_deref().and_then(|e| profile.text_error(e));
+                check(&|| format!("senses[{i}].example"), example)?;
+            }
+        }
+        if given("examples") {
+            for (i, example) in self.examples.iter().enumerate() {
+                let sentence = profile.text_error(&example.sentence);
+                check(&|| format!("examples[{i}].sentence"), sentence)?;
+            }
+        }
+        if given("usageNote") {
+            let usage_note = self
+                .usage_note
+                .as_deref()
+                .and_then(|n| profile.text_error(n));
+            check(&|| "usageNote".to_string(), usage_note)?;
+        }
+        if given("inflections") {
+            for (i, inflection) in self.inflections.iter().enumerate() {
+                let form = profile.lemma_error(&inflection.form);
+                check(&|| format!("inflections[{i}].form"), form)?;
+            }
+        }
+        if given("pronunciations") {
+            for (i, pronunciation) in self.pronunciations.iter().enumerate() {
+                let ipa = profile.pronunciation_error(&pronunciation.pronunciation);
+                check(&|| format!("pronunciations[{i}].pronunciation"), ipa)?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Validates the given fields of the word only, named as in JSON
+    ///
+    /// The rules across fields, inflections and categories applying to the
+    /// word type or the parts of speech of its senses, are checked when one
+    /// of the fields they involve is given.
+    pub fn validate_fields(&self, fields: &[&str]) -> Result<(), ValidationErrors> {
+        let Err(mut errors) = self.validate() else {
+            return Ok(());
+        };
+
+        let across = ["wordType", "senses", "inflections", "categories"];
+        let check_across = fields.iter().any(|field| across.contains(field));
+        let field_names: Vec<String> = fields.iter().map(|field| snake_case(field)).collect();
+        errors.errors_mut().retain(|name, _| {
+            if name == "__all__" {
+                check_across
+            } else {
+                field_names.iter().any(|field| field == name)
+            }
+        });
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the word as it is stored, lowercased but for its examples and usage note
+    fn lowercased(&self) -> Self {
+        Self {
+            word: self.word.to_lowercase(),
+            senses: self
+                .senses
+                .iter()
+                .map(|s| WordSense {
+                    part_of_speech: s.part_of_speech.to_lowercase(),
+                    definition: s.definition.to_lowercase(),
+                    example: s.example.as_ref().map(|e| e.to_lowercase()),
+                })
+                .collect(),
+            examples: self.examples.clone(),
+            usage_note: self.usage_note.clone(),
+            inflections: self
+                .inflections
+                .iter()
+                .map(|i| WordInflection {
+                    kind: i.kind.to_lowercase(),
+                    form: i.form.to_lowercase(),
+                })
+                .collect(),
+            categories: self.categories.iter().map(|c| c.to_lowercase()).collect(),
+            flags: self.flags.iter().map(|f| f.to_lowercase()).collect(),
+            pronunciations: self
+                .pronunciations
+                .iter()
+                .map(|p| PronunciationVariant {
+                    variant: p.variant.to_lowercase(),
+                    pronunciation: p.pronunciation.to_lowercase(),
+                })
+                .collect(),
+            word_type: self.word_type.to_lowercase(),
+        }
+    }
+
     /// Returns the word field after validation
     pub fn word(&self) -> Result<&str, AppError> {
         match self.validate() {
@@ -430,18 +1816,58 @@ impl UpsertWord {
         }
     }
 
-    /// Returns the definition field after validation
-    pub fn definition(&self) -> Result<&str, AppError> {
+    /// Returns the lowercased senses after validation, primary first
+    pub fn senses(&self) -> Result<Vec<WordSense>, AppError> {
         match self.validate() {
-            Ok(_) => Ok(self.definition.as_ref()),
+            Ok(_) => Ok(self.lowercased().senses),
             Err(e) => Err(e.into()),
         }
     }
 
-    /// Returns the pronunciation field after validation
-    pub fn pronunciation(&self) -> Result<&str, AppError> {
+    /// Returns the examples after validation, in display order
+    pub fn examples(&self) -> Result<&[WordExample], AppError> {
         match self.validate() {
-            Ok(_) => Ok(self.pronunciation.as_ref()),
+            Ok(_) => Ok(self.examples.as_ref()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the lowercased inflections after validation, in display order
+    pub fn inflections(&self) -> Result<Vec<WordInflection>, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.lowercased().inflections),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the lowercased categories after validation
+    pub fn categories(&self) -> Result<Vec<String>, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.lowercased().categories),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the lowercased content flags after validation
+    pub fn flags(&self) -> Result<Vec<String>, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.lowercased().flags),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the usage note field after validation
+    pub fn usage_note(&self) -> Result<Option<&str>, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.usage_note.as_deref()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the lowercased pronunciations after validation, primary first
+    pub fn pronunciations(&self) -> Result<Vec<PronunciationVariant>, AppError> {
+        match self.validate() {
+            Ok(_) => Ok(self.lowercased().pronunciations),
             Err(e) => Err(e.into()),
         }
     }
@@ -455,93 +1881,96 @@ impl UpsertWord {
     }
 }
 
-/// Validates a Merriam-Webster lemma using regex pattern matching
+/// Converts a field name from JSON to the name of the struct field (e.g. "wordType" to "word_type")
+fn snake_case(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| {
+            let separator = c.is_ascii_uppercase().then_some('_');
+            separator
+                .into_iter()
+                .chain(std::iter::once(c.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+/// Validates a Merriam-Webster lemma in any of the supported scripts
 ///
-/// Accepts alphanumeric characters, hyphens, apostrophes, periods,
-/// and common accented characters used in English dictionaries.
+/// Accepts letters, combining marks, digits, hyphens, apostrophes and
+/// periods allowed by at least one validation profile. Whether the scripts
+/// fit the language of the word is checked by its own profile on upsert.
 pub fn is_valid_lemma(lemma: &str) -> bool {
-    use regex::Regex;
-    use std::sync::OnceLock;
-
-    static LEMMA_REGEX: OnceLock<Regex> = OnceLock::new();
-    let regex = LEMMA_REGEX.get_or_init(|| {
-        // Pattern explanation:
-        // ^                    - Start of string
-        // [                    - Character class start
-        //   a-zA-Z0-9          - Alphanumeric characters
-        //   \-                 - Hyphen (escaped)
-        //   '                  - Apostrophe
-        //   \.                 - Period (escaped)
-        //   À-ÿ                - Latin-1 supplement accented characters
-        //   Ā-ž                - Latin Extended-A
-        //   Ḁ-ỿ                - Latin Extended Additional (common accented chars)
-        // ]+                   - One or more of the above characters
-        // $                    - End of string
-        Regex::new(r"^[a-zA-Z0-9\-'\.À-ÿĀ-žḀ-ỿ]+$").unwrap()
-    });
-
-    !lemma.is_empty() && regex.is_match(lemma)
+    !lemma.is_empty()
+        && ValidationProfile::ALL
+            .iter()
+            .any(|profile| profile.lemma_error(lemma).is_none())
 }
 
 /// Validates a definition string for dictionary-appropriate content
 ///
 /// Allows letters, numbers, whitespace, and standard punctuation
-/// commonly used in dictionary definitions.
+/// commonly used in dictionary definitions and example sentences,
+/// including straight and curly quotation marks, as allowed by at least
+/// one validation profile.
 pub fn is_valid_definition(definition: &str) -> bool {
-    use regex::Regex;
-    use std::sync::OnceLock;
-
-    static DEFINITION_REGEX: OnceLock<Regex> = OnceLock::new();
-    let regex = DEFINITION_REGEX.get_or_init(|| {
-        // Pattern explanation:
-        // ^                    - Start of string
-        // [                    - Character class start
-        //   a-zA-Z             - Basic Latin letters
-        //   À-ÿĀ-žḀ-ỿ           - Common accented characters
-        //   0-9                - Numbers
-        //   \s                 - Whitespace characters
-        //   .,;:!?()'""\-      - Common punctuation for definitions
-        // ]+                   - One or more of the above characters
-        // $                    - End of string
-        Regex::new(r"^[a-zA-ZÀ-ÿĀ-žḀ-ỿ0-9\s.,;:!?()'\-]+$").unwrap()
-    });
-
-    !definition.is_empty() && regex.is_match(definition)
+    !definition.is_empty()
+        && ValidationProfile::ALL
+            .iter()
+            .any(|profile| profile.text_error(definition).is_none())
 }
 
 /// Validates a pronunciation string using International Phonetic Alphabet (IPA) notation
 ///
-/// Requires forward slash delimiters and accepts IPA vowels, consonants,
-/// stress markers, and diacritics commonly used in English pronunciation guides.
+/// Requires forward slash delimiters around symbols of the phoneme
+/// inventory of at least one validation profile, with their stress and
+/// length markers, tie bars and diacritics.
 pub fn is_valid_pronunciation(pronunciation: &str) -> bool {
-    use regex::Regex;
-    use std::sync::OnceLock;
-
-    static PRONUNCIATION_REGEX: OnceLock<Regex> = OnceLock::new();
-    let regex = PRONUNCIATION_REGEX.get_or_init(|| {
-        // Pattern explanation:
-        // ^                    - Start of string
-        // /                    - Forward slash (literal)
-        // [                    - Character class start
-        //   a-zA-Z             - Basic Latin letters
-        //   əɛɪɔʊʌɑæɒɜɪʊʏ     - Common IPA vowels
-        //   ˈˌːˑ               - IPA stress and length markers
-        //   θðʃʒʧʤŋɹɾɭɻɲɳ     - Common IPA consonants
-        //   ʰʷʲˠˤʰᵊᵛᵚᵏ        - IPA diacritics and modifiers
-        //   \p{M}              - Unicode combining marks (diacritics)
-        // ]+                   - One or more of the above characters
-        // /                    - Forward slash (literal)
-        // $                    - End of string
-        Regex::new(r"^/[a-zA-Zəɛɪɔʊʌɑæɒɜɪʊʏˈˌːˑθðʃʒʧʤŋɹɾɭɻɲɳʰʷʲˠˤᵊᵛᵚᵏ]+/$").unwrap()
-    });
-
-    !pronunciation.is_empty() && regex.is_match(pronunciation)
+    pronunciation.len() > 2
+        && pronunciation.starts_with('/')
+        && pronunciation.ends_with('/')
+        && ValidationProfile::ALL
+            .iter()
+            .any(|profile| profile.pronunciation_error(pronunciation).is_none())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_merge_patch() {
+        let mut word = serde_json::json!({
+            "word": "cat",
+            "usageNote": "informal",
+            "senses": [{"definition": "a small animal", "partOfSpeech": "noun"}],
+            "flags": ["slang"],
+        });
+        merge_patch(
+            &mut word,
+            &serde_json::json!({
+                "usageNote": null,
+                "senses": [{"definition": "a feline", "partOfSpeech": "noun"}],
+                "inflections": [{"kind": "plural", "form": "cats"}],
+            }),
+        );
+        assert_eq!(
+            word,
+            serde_json::json!({
+                "word": "cat",
+                "senses": [{"definition": "a feline", "partOfSpeech": "noun"}],
+                "flags": ["slang"],
+                "inflections": [{"kind": "plural", "form": "cats"}],
+            })
+        );
+
+        // nested objects are merged, anything else is replaced
+        let mut document = serde_json::json!({"a": {"b": 1, "c": 2}, "d": [1, 2]});
+        merge_patch(
+            &mut document,
+            &serde_json::json!({"a": {"c": null}, "d": [3]}),
+        );
+        assert_eq!(document, serde_json::json!({"a": {"b": 1}, "d": [3]}));
+    }
+
     #[test]
     fn test_is_valid_lemma() {
         // Valid lemmas
@@ -551,6 +1980,9 @@ mod tests {
         assert!(is_valid_lemma("Mr."));
         assert!(is_valid_lemma("café"));
         assert!(is_valid_lemma("naïve"));
+        assert!(is_valid_lemma("λόγος"));
+        assert!(is_valid_lemma("слово"));
+        assert!(is_valid_lemma("言葉"));
         assert!(is_valid_lemma("test123"));
 
         // Invalid lemmas
@@ -570,6 +2002,8 @@ mod tests {
         ));
         assert!(is_valid_definition("having the quality of being naïve"));
         assert!(is_valid_definition("test: definition with punctuation!"));
+        assert!(is_valid_definition(r#"she said "bank" twice"#));
+        assert!(is_valid_definition("the “river” bank’s edge"));
 
         // Invalid definitions
         assert!(!is_valid_definition("contact us at test@email.com")); // email
@@ -586,6 +2020,8 @@ mod tests {
         assert!(is_valid_pronunciation("/ˌæbəˈreɪʃən/"));
         assert!(is_valid_pronunciation("/ˈhɛloʊ/"));
         assert!(is_valid_pronunciation("/test/"));
+        assert!(is_valid_pronunciation("/ˈɡʊtɐ/")); // IPA g and near-open central vowel
+        assert!(is_valid_pronunciation("/t͡ʃiːz/")); // tie bar
 
         // Invalid pronunciations
         assert!(!is_valid_pronunciation("invalid")); // no slashes
@@ -602,11 +2038,11 @@ mod tests {
         assert!(validate_word_type("verb").is_ok());
         assert!(validate_word_type("adjective").is_ok());
         assert!(validate_word_type("adverb").is_ok());
-        // assert!(validate_word_type("pronoun").is_ok());
-        // assert!(validate_word_type("preposition").is_ok());
-        // assert!(validate_word_type("conjunction").is_ok());
-        // assert!(validate_word_type("interjection").is_ok());
-        // assert!(validate_word_type("article").is_ok());
+        assert!(validate_word_type("pronoun").is_ok());
+        assert!(validate_word_type("preposition").is_ok());
+        assert!(validate_word_type("conjunction").is_ok());
+        assert!(validate_word_type("interjection").is_ok());
+        assert!(validate_word_type("article").is_ok());
 
         // Invalid word types
         assert!(validate_word_type("").is_err());
@@ -636,26 +2072,26 @@ mod tests {
             GrammaticalType::from_str("adverb").unwrap(),
             GrammaticalType::Adverb
         );
-        // assert_eq!(
-        //     GrammaticalType::from_str("pronoun").unwrap(),
-        //     GrammaticalType::Pronoun
-        // );
-        // assert_eq!(
-        //     GrammaticalType::from_str("preposition").unwrap(),
-        //     GrammaticalType::Preposition
-        // );
-        // assert_eq!(
-        //     GrammaticalType::from_str("conjunction").unwrap(),
-        //     GrammaticalType::Conjunction
-        // );
-        // assert_eq!(
-        //     GrammaticalType::from_str("interjection").unwrap(),
-        //     GrammaticalType::Interjection
-        // );
-        // assert_eq!(
-        //     GrammaticalType::from_str("article").unwrap(),
-        //     GrammaticalType::Article
-        // );
+        assert_eq!(
+            GrammaticalType::from_str("pronoun").unwrap(),
+            GrammaticalType::Pronoun
+        );
+        assert_eq!(
+            GrammaticalType::from_str("preposition").unwrap(),
+            GrammaticalType::Preposition
+        );
+        assert_eq!(
+            GrammaticalType::from_str("conjunction").unwrap(),
+            GrammaticalType::Conjunction
+        );
+        assert_eq!(
+            GrammaticalType::from_str("interjection").unwrap(),
+            GrammaticalType::Interjection
+        );
+        assert_eq!(
+            GrammaticalType::from_str("article").unwrap(),
+            GrammaticalType::Article
+        );
 
         // Invalid grammatical types
         assert!(GrammaticalType::from_str("invalid").is_err());
@@ -669,11 +2105,11 @@ mod tests {
         assert_eq!(GrammaticalType::Verb.type_name(), "verb");
         assert_eq!(GrammaticalType::Adjective.type_name(), "adjective");
         assert_eq!(GrammaticalType::Adverb.type_name(), "adverb");
-        // assert_eq!(GrammaticalType::Pronoun.type_name(), "pronoun");
-        // assert_eq!(GrammaticalType::Preposition.type_name(), "preposition");
-        // assert_eq!(GrammaticalType::Conjunction.type_name(), "conjunction");
-        // assert_eq!(GrammaticalType::Interjection.type_name(), "interjection");
-        // assert_eq!(GrammaticalType::Article.type_name(), "article");
+        assert_eq!(GrammaticalType::Pronoun.type_name(), "pronoun");
+        assert_eq!(GrammaticalType::Preposition.type_name(), "preposition");
+        assert_eq!(GrammaticalType::Conjunction.type_name(), "conjunction");
+        assert_eq!(GrammaticalType::Interjection.type_name(), "interjection");
+        assert_eq!(GrammaticalType::Article.type_name(), "article");
     }
 
     #[test]
@@ -682,11 +2118,11 @@ mod tests {
         assert_eq!(format!("{}", GrammaticalType::Verb), "verb");
         assert_eq!(format!("{}", GrammaticalType::Adjective), "adjective");
         assert_eq!(format!("{}", GrammaticalType::Adverb), "adverb");
-        // assert_eq!(format!("{}", GrammaticalType::Pronoun), "pronoun");
-        // assert_eq!(format!("{}", GrammaticalType::Preposition), "preposition");
-        // assert_eq!(format!("{}", GrammaticalType::Conjunction), "conjunction");
-        // assert_eq!(format!("{}", GrammaticalType::Interjection), "interjection");
-        // assert_eq!(format!("{}", GrammaticalType::Article), "article");
+        assert_eq!(format!("{}", GrammaticalType::Pronoun), "pronoun");
+        assert_eq!(format!("{}", GrammaticalType::Preposition), "preposition");
+        assert_eq!(format!("{}", GrammaticalType::Conjunction), "conjunction");
+        assert_eq!(format!("{}", GrammaticalType::Interjection), "interjection");
+        assert_eq!(format!("{}", GrammaticalType::Article), "article");
     }
 
     #[test]
@@ -728,25 +2164,341 @@ mod tests {
     fn test_upsert_word_validation() {
         let valid_word = UpsertWord {
             word: "hello".to_string(),
-            definition: "a greeting".to_string(),
-            pronunciation: "/həˈloʊ/".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a greeting".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: DEFAULT_VARIANT.to_string(),
+                pronunciation: "/həˈloʊ/".to_string(),
+            }],
             word_type: "noun".to_string(),
         };
 
         assert!(valid_word.validate().is_ok());
         assert!(valid_word.word().is_ok());
-        assert!(valid_word.definition().is_ok());
-        assert!(valid_word.pronunciation().is_ok());
+        assert!(valid_word.senses().is_ok());
+        assert!(valid_word.pronunciations().is_ok());
         assert!(valid_word.word_type().is_ok());
 
         let invalid_word = UpsertWord {
             word: "hello world".to_string(), // invalid: contains space
-            definition: "a greeting".to_string(),
-            pronunciation: "/həˈloʊ/".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a greeting".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: DEFAULT_VARIANT.to_string(),
+                pronunciation: "/həˈloʊ/".to_string(),
+            }],
             word_type: "noun".to_string(),
         };
 
         assert!(invalid_word.validate().is_err());
         assert!(invalid_word.word().is_err());
     }
+
+    #[test]
+    fn test_upsert_word_pronunciations_validation() {
+        let variant = |variant: &str, pronunciation: &str| PronunciationVariant {
+            variant: variant.to_string(),
+            pronunciation: pronunciation.to_string(),
+        };
+        let upsert = |pronunciations| UpsertWord {
+            word: "tomato".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: "a red fruit".to_string(),
+                example: None,
+            }],
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations,
+            word_type: "noun".to_string(),
+        };
+
+        let word = upsert(vec![
+            variant("us", "/təˈmeɪtoʊ/"),
+            variant("uk", "/təˈmɑːtəʊ/"),
+        ]);
+        assert!(word.validate().is_ok());
+        assert_eq!(word.pronunciations().unwrap()[1].variant, "uk");
+
+        // at least one pronunciation is required
+        assert!(upsert(vec![]).validate().is_err());
+        // every pronunciation must be valid IPA
+        assert!(upsert(vec![variant("us", "tomato")]).validate().is_err());
+        // the same pronunciation can't be listed twice
+        assert!(upsert(vec![
+            variant("us", "/təˈmeɪtoʊ/"),
+            variant("ca", "/təˈmeɪtoʊ/"),
+        ])
+        .validate()
+        .is_err());
+        // variant labels are short lowercase tags
+        assert!(upsert(vec![variant("U S", "/təˈmeɪtoʊ/")])
+            .validate()
+            .is_err());
+        assert!(upsert(vec![variant("-us", "/təˈmeɪtoʊ/")])
+            .validate()
+            .is_err());
+        assert!(upsert(vec![variant("en-au", "/təˈmɑːtəʊ/")])
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_pronunciation_variant_default_label() {
+        let parsed: PronunciationVariant =
+            serde_json::from_str(r#"{"pronunciation": "/rʌn/"}"#).unwrap();
+        assert_eq!(parsed.variant, DEFAULT_VARIANT);
+    }
+
+    #[test]
+    fn test_upsert_word_senses_validation() {
+        let sense = |part_of_speech: &str, definition: &str| WordSense {
+            part_of_speech: part_of_speech.to_string(),
+            definition: definition.to_string(),
+            example: None,
+        };
+        let upsert = |senses| UpsertWord {
+            word: "bank".to_string(),
+            senses,
+            examples: vec![],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: DEFAULT_VARIANT.to_string(),
+                pronunciation: "/bæŋk/".to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+
+        let word = upsert(vec![
+            sense("noun", "the land alongside a river"),
+            sense("verb", "to deposit money"),
+        ]);
+        assert!(word.validate().is_ok());
+        assert_eq!(word.senses().unwrap()[1].part_of_speech, "verb");
+
+        // at least one sense is required
+        assert!(upsert(vec![]).validate().is_err());
+        // every part of speech must be an allowed grammatical type
+        assert!(upsert(vec![sense("determiner", "a word")])
+            .validate()
+            .is_err());
+        // the same definition can't be listed twice
+        assert!(upsert(vec![
+            sense("noun", "a place for money"),
+            sense("verb", "A place for money"),
+        ])
+        .validate()
+        .is_err());
+
+        let mut with_example = sense("noun", "a place for money");
+        with_example.example = Some("i put my savings in the bank".to_string());
+        assert!(upsert(vec![with_example.clone()]).validate().is_ok());
+        with_example.example = Some("".to_string());
+        assert!(upsert(vec![with_example]).validate().is_err());
+    }
+
+    #[test]
+    fn test_upsert_word_examples_validation() {
+        let example = |sentence: &str, target_start, target_end| WordExample {
+            sentence: sentence.to_string(),
+            target_start,
+            target_end,
+        };
+        let upsert = |examples| UpsertWord {
+            word: "run".to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "verb".to_string(),
+                definition: "to move swiftly on foot".to_string(),
+                example: None,
+            }],
+            examples,
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: DEFAULT_VARIANT.to_string(),
+                pronunciation: "/rʌn/".to_string(),
+            }],
+            word_type: "verb".to_string(),
+        };
+
+        // the marked token may be an inflected form, offsets count characters
+        let ran = example("“Zoë ran home,” she said.", 5, 8);
+        assert_eq!(ran.target().unwrap(), "ran");
+        let word = upsert(vec![ran.clone(), example("Run!", 0, 3)]);
+        assert!(word.validate().is_ok());
+        assert_eq!(word.examples().unwrap()[0].sentence, ran.sentence);
+
+        // offsets outside of the sentence, empty or across words
+        assert!(upsert(vec![example("Run!", 0, 5)]).validate().is_err());
+        assert!(upsert(vec![example("Run!", 2, 2)]).validate().is_err());
+        assert!(upsert(vec![example("she ran home", 4, 12)])
+            .validate()
+            .is_err());
+        // the same sentence can't be listed twice
+        assert!(upsert(vec![example("Run!", 0, 3), example("run!", 0, 3)])
+            .validate()
+            .is_err());
+
+        let mut with_note = upsert(vec![]);
+        with_note.usage_note = Some("Informal when used as a noun.".to_string());
+        assert!(with_note.validate().is_ok());
+        assert_eq!(
+            with_note.usage_note().unwrap(),
+            Some("Informal when used as a noun.")
+        );
+        with_note.usage_note = Some("".to_string());
+        assert!(with_note.validate().is_err());
+    }
+
+    #[test]
+    fn test_upsert_word_inflections_validation() {
+        let inflection = |kind: &str, form: &str| WordInflection {
+            kind: kind.to_string(),
+            form: form.to_string(),
+        };
+        let upsert = |word_type: &str, inflections| UpsertWord {
+            word: "bank".to_string(),
+            senses: vec![
+                WordSense {
+                    part_of_speech: "noun".to_string(),
+                    definition: "a place for money".to_string(),
+                    example: None,
+                },
+                WordSense {
+                    part_of_speech: "verb".to_string(),
+                    definition: "to deposit money".to_string(),
+                    example: None,
+                },
+            ],
+            examples: vec![],
+            usage_note: None,
+            inflections,
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: DEFAULT_VARIANT.to_string(),
+                pronunciation: "/bæŋk/".to_string(),
+            }],
+            word_type: word_type.to_string(),
+        };
+
+        // kinds apply to the word type or to the part of speech of a sense
+        let word = upsert(
+            "noun",
+            vec![
+                inflection("Plural", "Banks"),
+                inflection("past_tense", "banked"),
+            ],
+        );
+        assert!(word.validate().is_ok());
+        assert_eq!(
+            word.inflections().unwrap()[0],
+            inflection("plural", "banks")
+        );
+        assert!(upsert("noun", vec![inflection("comparative", "banker")])
+            .validate()
+            .is_err());
+
+        // unknown kinds, invalid forms and duplicates are rejected
+        assert!(upsert("noun", vec![inflection("gerund", "banking")])
+            .validate()
+            .is_err());
+        assert!(upsert("noun", vec![inflection("plural", "bank s")])
+            .validate()
+            .is_err());
+        assert!(upsert(
+            "noun",
+            vec![inflection("plural", "banks"), inflection("plural", "BANKS")]
+        )
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_upsert_word_conform_to_profile() {
+        let upsert = |word: &str, definition: &str, pronunciation: &str| UpsertWord {
+            word: word.to_string(),
+            senses: vec![WordSense {
+                part_of_speech: "noun".to_string(),
+                definition: definition.to_string(),
+                example: None,
+            }],
+            examples: vec![WordExample {
+                sentence: format!("{word}!"),
+                target_start: 0,
+                target_end: word.chars().count() as u32,
+            }],
+            usage_note: None,
+            inflections: vec![],
+            categories: vec![],
+            flags: vec![],
+            pronunciations: vec![PronunciationVariant {
+                variant: DEFAULT_VARIANT.to_string(),
+                pronunciation: pronunciation.to_string(),
+            }],
+            word_type: "noun".to_string(),
+        };
+
+        let greek = upsert("λόγος", "λέξη, ομιλία", "/ˈloɣos/");
+        assert!(greek.conform(ValidationProfile::Greek).is_ok());
+
+        // the error names the field and the character the profile rejects
+        let greek = upsert("λόγος", "λέξη, ομιλία", "/ˈloɣos/");
+        let error = greek.conform(ValidationProfile::Latin).err().unwrap();
+        let message = error.to_string();
+        assert!(message.contains("word: character 'λ' (U+03BB)"));
+        assert!(message.contains("latin validation profile"));
+
+        let english = upsert("house", "a building", "/haʊs/");
+        let error = english.conform(ValidationProfile::Greek).err().unwrap();
+        assert!(error.to_string().contains("word: character 'h'"));
+
+        // decomposed accents are stored precomposed, example offsets follow
+        let decomposed = upsert("cafe\u{301}", "a coffee house", "/kæˈfeɪ/");
+        assert_eq!(decomposed.examples[0].target_end, 5);
+        let conformed = decomposed.conform(ValidationProfile::Latin).unwrap();
+        assert_eq!(conformed.word().unwrap(), "café");
+        assert_eq!(conformed.examples[0].sentence, "café!");
+        assert_eq!(conformed.examples[0].target().unwrap(), "café");
+    }
+
+    #[test]
+    fn test_sense_params_selection() {
+        let params = SenseParams { sense: None };
+        assert_eq!(params.selection().unwrap(), SenseSelection::All);
+
+        let params = SenseParams {
+            sense: Some("random".to_string()),
+        };
+        assert_eq!(params.selection().unwrap(), SenseSelection::Random);
+
+        let params = SenseParams {
+            sense: Some("first".to_string()),
+        };
+        assert!(params.selection().is_err());
+    }
 }
@@ -5,18 +5,57 @@
 //! All routes require admin authentication via JWT token.
 //!
 //! # Routes
-//! - `GET /admin/{lang}/words` - List all words (admin only)
+//! - `GET /admin/{lang}/words` - List words a page at a time, sorted and filtered (admin only)
 //! - `POST /admin/{lang}/words` - Create new word (admin only)
 //! - `GET /admin/{lang}/words/{id}` - Get word by ID (admin only)
 //! - `PUT /admin/{lang}/words/{id}` - Update word by ID (admin only)
-//! - `DELETE /admin/{lang}/words/{id}` - Delete word by ID (admin only)
+//! - `PATCH /admin/{lang}/words/{id}` - Partially update word by ID with a JSON Merge Patch (admin only)
+//! - `DELETE /admin/{lang}/words/{id}` - Move word by ID to the trash (admin only)
+//! - `POST /admin/{lang}/words/import` - Import words from CSV, JSON or NDJSON (admin only)
+//! - `GET /admin/{lang}/words/export` - Export words as CSV, NDJSON or JSON (admin only)
+//! - `GET /admin/{lang}/words/trash` - List deleted words (admin only)
+//! - `DELETE /admin/{lang}/words/trash` - Purge words past the trash retention (admin only)
+//! - `POST /admin/{lang}/words/trash/{id}/restore` - Restore a deleted word (admin only)
+//! - `DELETE /admin/{lang}/words/trash/{id}` - Purge a deleted word for good (admin only)
+//! - `GET /admin/{lang}/words/{id}/history` - List the revisions of a word (admin only)
+//! - `POST /admin/{lang}/words/{id}/history/{revision_id}/revert` - Revert a word to a revision (admin only)
+//! - `GET /admin/{lang}/words/{id}/relations` - List relations of a word (admin only)
+//! - `POST /admin/{lang}/words/{id}/relations` - Relate a word to another one (admin only)
+//! - `DELETE /admin/{lang}/words/{id}/relations/{relation_id}` - Delete a relation (admin only)
+//! - `GET /admin/{lang}/words/{id}/translations` - List translations of a word (admin only)
+//! - `POST /admin/{lang}/words/{id}/translations` - Link a word to its translation (admin only)
+//! - `DELETE /admin/{lang}/words/{id}/translations/{link_id}` - Delete a translation link (admin only)
+//! - `PUT /admin/{lang}/words/{id}/tags/{name}` - Tag a word (admin only)
+//! - `DELETE /admin/{lang}/words/{id}/tags/{name}` - Remove a tag from a word (admin only)
+//! - `GET /admin/{lang}/proposals` - List the proposals of editors in a review state (admin only)
+//! - `POST /admin/{lang}/proposals/{id}/approve` - Approve and apply a proposal (admin only)
+//! - `POST /admin/{lang}/proposals/{id}/reject` - Reject a proposal with a comment (admin only)
+//! - `POST /admin/{lang}/proposals/{id}/request-changes` - Send a proposal back for changes (admin only)
+//! - `GET /admin/{lang}/reports` - List problem reports in a triage state (admin only)
+//! - `GET /admin/{lang}/reports/words` - List reported words with their reports grouped (admin only)
+//! - `POST /admin/{lang}/reports/{id}/resolve` - Resolve a problem report (admin only)
+//! - `POST /admin/{lang}/reports/{id}/dismiss` - Dismiss a problem report (admin only)
+//! - `GET /admin/tags` - List all tags (admin only)
+//! - `POST /admin/tags` - Create new tag (admin only)
+//! - `DELETE /admin/tags/{id}` - Delete tag by ID (admin only)
+//! - `GET /admin/languages` - List all languages (admin only)
+//! - `POST /admin/languages` - Register new language (admin only)
+//! - `PUT /admin/languages/{code}` - Update or enable/disable a language (admin only)
+//! - `PUT /admin/users/{id}/editor` - Grant the editor role to a user (admin only)
+//! - `DELETE /admin/users/{id}/editor` - Revoke the editor role of a user (admin only)
 
-use axum::{middleware, routing::get, Router};
-use http::{HeaderValue, Method};
+use axum::{
+    extract::DefaultBodyLimit,
+    middleware,
+    routing::{delete, get, post, put},
+    Router,
+};
+use http::{header, HeaderName, HeaderValue, Method};
 use tower_http::cors::CorsLayer;
 
 use crate::auth::admin_auth_middleware;
 use crate::handlers::admin::*;
+use crate::middleware::create_body_limit_layer_with_size;
 use crate::state::AppState;
 
 /// Creates administrative routes with CORS and state injection
@@ -28,7 +67,61 @@ pub fn create_admin_routes(shared_state: AppState, origins: &[HeaderValue]) -> R
                 .route("/{lang}/words", get(word_list).post(word_create))
                 .route(
                     "/{lang}/words/{id}",
-                    get(word_read).put(word_update).delete(word_delete),
+                    get(word_read)
+                        .put(word_update)
+                        .patch(word_patch)
+                        .delete(word_delete),
+                )
+                .route("/{lang}/words/export", get(word_export))
+                .route(
+                    "/{lang}/words/trash",
+                    get(trash_list).delete(trash_purge_expired),
+                )
+                .route("/{lang}/words/trash/{id}", delete(trash_purge))
+                .route("/{lang}/words/trash/{id}/restore", post(trash_restore))
+                .route("/{lang}/words/{id}/history", get(word_history))
+                .route(
+                    "/{lang}/words/{id}/history/{revision_id}/revert",
+                    post(word_revert),
+                )
+                .route(
+                    "/{lang}/words/{id}/relations",
+                    get(relation_list).post(relation_create),
+                )
+                .route(
+                    "/{lang}/words/{id}/relations/{relation_id}",
+                    delete(relation_delete),
+                )
+                .route(
+                    "/{lang}/words/{id}/translations",
+                    get(translation_list).post(translation_create),
+                )
+                .route(
+                    "/{lang}/words/{id}/translations/{link_id}",
+                    delete(translation_delete),
+                )
+                .route(
+                    "/{lang}/words/{id}/tags/{name}",
+                    put(word_tag_add).delete(word_tag_remove),
+                )
+                .route("/{lang}/proposals", get(proposal_queue))
+                .route("/{lang}/proposals/{id}/approve", post(proposal_approve))
+                .route("/{lang}/proposals/{id}/reject", post(proposal_reject))
+                .route(
+                    "/{lang}/proposals/{id}/request-changes",
+                    post(proposal_request_changes),
+                )
+                .route("/{lang}/reports", get(report_queue))
+                .route("/{lang}/reports/words", get(reported_words))
+                .route("/{lang}/reports/{id}/resolve", post(report_resolve))
+                .route("/{lang}/reports/{id}/dismiss", post(report_dismiss))
+                .route("/tags", get(tag_list).post(tag_create))
+                .route("/tags/{id}", delete(tag_delete))
+                .route("/languages", get(language_list).post(language_create))
+                .route("/languages/{code}", put(language_update))
+                .route(
+                    "/users/{id}/editor",
+                    put(user_editor_grant).delete(user_editor_revoke),
                 )
                 .layer(middleware::from_fn_with_state(
                     shared_state.clone(),
@@ -38,7 +131,47 @@ pub fn create_admin_routes(shared_state: AppState, origins: &[HeaderValue]) -> R
         .with_state(shared_state)
         .layer(
             CorsLayer::new()
-                .allow_methods([Method::POST, Method::GET, Method::PUT, Method::DELETE])
+                .allow_methods([
+                    Method::POST,
+                    Method::GET,
+                    Method::PUT,
+                    Method::PATCH,
+                    Method::DELETE,
+                ])
+                .allow_origin(origins.to_owned())
+                // paging and version headers of words, for browser clients
+                .expose_headers([
+                    header::LINK,
+                    HeaderName::from_static("x-total-count"),
+                    header::ETAG,
+                    header::LAST_MODIFIED,
+                ]),
+        )
+}
+
+/// Creates the administrative word import route with its own body limit
+///
+/// Imports are bounded by `import_body_limit_kilobytes` instead of the request
+/// body limit, so the route is kept out of the global body limit layer.
+pub fn create_import_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    let limit = {
+        let config_lock = shared_state.apiconfig.lock().unwrap();
+        config_lock.api_limits.import_body_limit_kilobytes
+    };
+
+    Router::new()
+        .route("/admin/{lang}/words/import", post(word_import))
+        // only the limit below applies, not the one of the body extractor
+        .layer(DefaultBodyLimit::disable())
+        .layer(create_body_limit_layer_with_size(limit))
+        .layer(middleware::from_fn_with_state(
+            shared_state.clone(),
+            admin_auth_middleware,
+        ))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::POST])
                 .allow_origin(origins.to_owned()),
         )
 }
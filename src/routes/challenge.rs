@@ -0,0 +1,40 @@
+//! Daily challenge routes
+//!
+//! Provides the daily challenge, result submission and leaderboard endpoints
+//! under `/{lang}/challenge/{date}`. Reading is public; submitting results
+//! requires a JWT token.
+//!
+//! # Routes
+//! - `GET /{lang}/challenge/{date}` - Daily challenge words
+//! - `POST /{lang}/challenge/{date}/submissions` - Submit results (auth required)
+//! - `GET /{lang}/challenge/{date}/leaderboard` - Daily rankings
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::challenge::*;
+use crate::state::AppState;
+
+/// Creates daily challenge routes with CORS and state injection
+pub fn create_challenge_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .route("/{lang}/challenge/{date}", get(challenge_read))
+        .route(
+            "/{lang}/challenge/{date}/submissions",
+            post(challenge_submit),
+        )
+        .route(
+            "/{lang}/challenge/{date}/leaderboard",
+            get(challenge_leaderboard),
+        )
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::GET, Method::POST])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -0,0 +1,52 @@
+//! Editorial route configuration with authentication middleware
+//!
+//! Configures the endpoints editors use to propose words and changes under
+//! `/editor/{lang}`. Includes CORS configuration for development and
+//! production use. All routes require an editor or admin JWT token.
+//!
+//! # Routes
+//! - `POST /editor/{lang}/proposals` - Propose a new word (editor only)
+//! - `GET /editor/{lang}/proposals` - List the proposals of the editor (editor only)
+//! - `GET /editor/{lang}/proposals/{id}` - Get a proposal of the editor (editor only)
+//! - `PUT /editor/{lang}/proposals/{id}` - Revise a proposal of the editor (editor only)
+//! - `POST /editor/{lang}/words/{id}/proposals` - Propose a change to a word (editor only)
+
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::auth::editor_auth_middleware;
+use crate::handlers::editor::*;
+use crate::state::AppState;
+
+/// Creates editorial routes with CORS and state injection
+pub fn create_editor_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .nest(
+            "/editor",
+            Router::new()
+                .route(
+                    "/{lang}/proposals",
+                    get(proposal_list).post(proposal_create),
+                )
+                .route(
+                    "/{lang}/proposals/{id}",
+                    get(proposal_read).put(proposal_revise),
+                )
+                .route("/{lang}/words/{id}/proposals", post(proposal_create_change))
+                .layer(middleware::from_fn_with_state(
+                    shared_state.clone(),
+                    editor_auth_middleware,
+                )),
+        )
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::POST, Method::GET, Method::PUT])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -0,0 +1,27 @@
+//! Public homophone routes
+//!
+//! Provides the `/{lang}/words/{lemma}/homophones` endpoint listing words
+//! that share a pronunciation with a lemma. Random homophone groups are
+//! served by the word routes with `?mode=homophones`.
+//!
+//! # Routes
+//! - `GET /{lang}/words/{lemma}/homophones` - Words sounding like a lemma
+
+use axum::{routing::get, Router};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::homophone::*;
+use crate::state::AppState;
+
+/// Creates public homophone routes with CORS configuration
+pub fn create_homophone_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .route("/{lang}/words/{lemma}/homophones", get(word_homophones))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::GET])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -0,0 +1,27 @@
+//! Public inflection routes
+//!
+//! Provides the `/{lang}/words/{form}/lemma` endpoint resolving inflected
+//! forms back to their lemmas. Inflections are edited with the words they
+//! belong to through the admin routes.
+//!
+//! # Routes
+//! - `GET /{lang}/words/{form}/lemma` - Lemmas of an inflected form
+
+use axum::{routing::get, Router};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::inflection::*;
+use crate::state::AppState;
+
+/// Creates public inflection routes with CORS configuration
+pub fn create_inflection_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .route("/{lang}/words/{form}/lemma", get(word_lemma))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::GET])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -0,0 +1,29 @@
+//! Public word ladder routes
+//!
+//! Provides `/{lang}/ladder/solve` and `/{lang}/ladder/random` endpoints for
+//! the word ladder game mode. All endpoints are publicly accessible and
+//! return JSON responses.
+//!
+//! # Routes
+//! - `GET /{lang}/ladder/solve?from=&to=` - Shortest ladder between two lemmas
+//! - `GET /{lang}/ladder/random?length=&letters=` - Random ladder puzzle
+
+use axum::{routing::get, Router};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::ladder::*;
+use crate::state::AppState;
+
+/// Creates public word ladder routes with CORS configuration
+pub fn create_ladder_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .route("/{lang}/ladder/solve", get(ladder_solve))
+        .route("/{lang}/ladder/random", get(ladder_random))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::GET])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -6,13 +6,22 @@
 //! # Route Groups
 //! - `/auth` - Authentication and authorization (requires admin user)
 //! - `/admin/{lang}/words` - Administrative CRUD endpoints (requires auth)
+//! - `/editor/{lang}/proposals` - Editorial proposals awaiting review (requires auth)
 //! - `/health/alive` and `/health/ready` - Health check endpoints
 //! - `/{lang}/random` and `/{lang}/{type}` - Public word retrieval endpoints
+//! - `/{lang}/challenge/{date}` - Daily challenge and leaderboard endpoints
+//! - `/{lang}/ladder/solve` and `/{lang}/ladder/random` - Public word ladder endpoints
+//! - `/{lang}/words/{lemma}/homophones` - Public homophone lookup endpoint
+//! - `/{lang}/words/{lemma}/related` - Public related word lookup endpoint
+//! - `/{lang}/words/{lemma}/translations` - Public translation lookup endpoint
+//! - `/{lang}/words/{form}/lemma` - Public inflected form lookup endpoint
+//! - `/{lang}/words/{lemma}/reports` - Public problem report endpoint, rate limited per IP
 //! - `/swagger-ui`, `/redoc`, `/scalar`, `/rapidoc` - OpenAPI documentation interfaces
 //!
 //! # Security Model
 //! - **Public routes**: Health checks, word retrieval, API documentation
 //! - **Protected routes**: Admin word management (JWT required)
+//! - **Editorial routes**: Word proposals of editors or admins (JWT required)
 //!
 //! # CORS Configuration
 //! Configured for development (localhost) by default with appropriate method
@@ -21,7 +30,7 @@
 //! # Middleware Stack (applied globally)
 //! - Security headers
 //! - Rate limiting per IP
-//! - Request body size limits
+//! - Request body size limits, word imports having their own
 //! - Compression (gzip/brotli)
 //! - HTTP request tracing for observability
 //! - CORS headers for cross-origin requests
@@ -37,17 +46,33 @@ use tower_http::trace::TraceLayer;
 
 pub mod admin;
 pub mod auth;
+pub mod challenge;
+pub mod editor;
 pub mod healthcheck;
+pub mod homophone;
+pub mod inflection;
+pub mod ladder;
 pub mod openapi;
+pub mod relation;
+pub mod report;
+pub mod translation;
 pub mod word;
 
 use crate::error::AppError;
 use crate::middleware::{create_body_limit_layer_with_size, init_tracing, security_headers};
 use crate::state::AppState;
-use admin::create_admin_routes;
+use admin::{create_admin_routes, create_import_routes};
 use auth::create_auth_routes;
+use challenge::create_challenge_routes;
+use editor::create_editor_routes;
 use healthcheck::create_health_routes;
+use homophone::create_homophone_routes;
+use inflection::create_inflection_routes;
+use ladder::create_ladder_routes;
 use openapi::create_apidocs_routes;
+use relation::create_relation_routes;
+use report::create_report_routes;
+use translation::create_translation_routes;
 use word::create_word_routes;
 
 fn process_origins(allowed_origins: Vec<String>) -> anyhow::Result<Vec<HeaderValue>, AppError> {
@@ -74,6 +99,9 @@ pub async fn create_router(shared_state: AppState) -> Result<Router, AppError> {
     // Add admin routes under /admin
     let admin_routes = create_admin_routes(shared_state.clone(), &origins);
 
+    // Add editorial routes under /editor
+    let editor_routes = create_editor_routes(shared_state.clone(), &origins);
+
     // Add auth routes under /auth
     let auth_routes = create_auth_routes(shared_state.clone(), &origins);
 
@@ -86,13 +114,52 @@ pub async fn create_router(shared_state: AppState) -> Result<Router, AppError> {
     // Add public word routes under /{lang}
     let word_routes = create_word_routes(shared_state.clone(), &origins);
 
+    // Add public word ladder routes under /{lang}/ladder
+    let ladder_routes = create_ladder_routes(shared_state.clone(), &origins);
+
+    // Add daily challenge routes under /{lang}/challenge
+    let challenge_routes = create_challenge_routes(shared_state.clone(), &origins);
+
+    // Add homophone lookup routes under /{lang}/words/{lemma}
+    let homophone_routes = create_homophone_routes(shared_state.clone(), &origins);
+
+    // Add related word lookup routes under /{lang}/words/{lemma}
+    let relation_routes = create_relation_routes(shared_state.clone(), &origins);
+
+    // Add translation lookup routes under /{lang}/words/{lemma}
+    let translation_routes = create_translation_routes(shared_state.clone(), &origins);
+
+    // Add inflected form lookup routes under /{lang}/words/{form}
+    let inflection_routes = create_inflection_routes(shared_state.clone(), &origins);
+
+    // Add problem report routes under /{lang}/words/{lemma}
+    let report_routes = create_report_routes(shared_state.clone(), &origins);
+
+    // Add the word import route under /admin, with its own body limit
+    let import_routes = create_import_routes(shared_state.clone(), &origins);
+
     // Create the base router with all routes
     let mut router = Router::new()
         .merge(admin_routes)
+        .merge(editor_routes)
         .merge(auth_routes)
         .merge(health_routes)
         .merge(apidocs_routes)
-        .merge(word_routes);
+        .merge(word_routes)
+        .merge(ladder_routes)
+        .merge(challenge_routes)
+        .merge(homophone_routes)
+        .merge(relation_routes)
+        .merge(translation_routes)
+        .merge(inflection_routes)
+        .merge(report_routes);
+
+    // Creates a RequestBodyLimitLayer with custom size limit, before merging
+    // the import route which has a limit of its own
+    router = router.layer(create_body_limit_layer_with_size(
+        config.api_limits.request_body_limit_kilobytes,
+    ));
+    router = router.merge(import_routes);
 
     // Apply middleware stack in the correct order (inside-out):
     // Brotli and gzip compression
@@ -107,11 +174,6 @@ pub async fn create_router(shared_state: AppState) -> Result<Router, AppError> {
         config.api_limits.request_timeout,
     )));
 
-    // Creates a RequestBodyLimitLayer with custom size limit
-    router = router.layer(create_body_limit_layer_with_size(
-        config.api_limits.request_body_limit_kilobytes,
-    ));
-
     // Rate limiting per IP
     let governor_conf = GovernorConfigBuilder::default()
         .per_second(config.api_limits.rate_limit_per_second)
@@ -131,3 +193,89 @@ pub async fn create_router(shared_state: AppState) -> Result<Router, AppError> {
 
     Ok(router)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::JwtManager;
+    use crate::models::user::User;
+    use axum::http::StatusCode;
+    use std::net::SocketAddr;
+
+    #[tokio::test]
+    async fn test_import_body_limit() {
+        let (state, _temp_db) = crate::state::test_state().await;
+        let secret = {
+            let mut config = state.apiconfig.lock().unwrap();
+            config.api_limits.request_body_limit_kilobytes = 4;
+            config.api_limits.import_body_limit_kilobytes = 64;
+            config.jwt_settings.secret.clone()
+        };
+
+        // the governor keys requests by peer address
+        let router = create_router(state).await.unwrap();
+        let mut server = axum_test::TestServer::builder()
+            .http_transport()
+            .build(router.into_make_service_with_connect_info::<SocketAddr>())
+            .unwrap();
+        let admin = User {
+            id: 1,
+            username: "admin".to_string(),
+            password_hash: String::new(),
+            is_admin: true,
+            is_editor: false,
+            created_at: None,
+            updated_at: None,
+        };
+        let token = JwtManager::generate_token(&admin, &secret, 5).unwrap();
+        server.add_header("Authorization", format!("Bearer {token}"));
+
+        let rows = |count: usize| {
+            (0..count)
+                .map(|i| {
+                    let word: String = format!("{i:04}")
+                        .bytes()
+                        .map(|digit| (b'a' + digit - b'0') as char)
+                        .collect();
+                    serde_json::json!({
+                        "word": word,
+                        "wordType": "noun",
+                        "senses": [{"definition": "a word of the import", "partOfSpeech": "noun"}],
+                        "pronunciations": [{"pronunciation": "/wɜːd/"}],
+                    })
+                    .to_string()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        // imports over the request body limit are only bounded by their own limit
+        let body = rows(200);
+        assert!(body.len() > 4 * 1024 && body.len() < 64 * 1024);
+        let response = server
+            .post("/admin/en/words/import?dry_run=true")
+            .text(body.clone())
+            .content_type("application/x-ndjson")
+            .await;
+        response.assert_status_ok();
+        let json: serde_json::Value = response.json();
+        assert_eq!(json["accepted"], 200);
+
+        // other routes keep the request body limit
+        let response = server
+            .post("/admin/en/words")
+            .text(body)
+            .content_type("application/json")
+            .await;
+        response.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+
+        let body = rows(1000);
+        assert!(body.len() > 64 * 1024);
+        let response = server
+            .post("/admin/en/words/import?dry_run=true")
+            .text(body)
+            .content_type("application/x-ndjson")
+            .await;
+        response.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -10,9 +10,30 @@ use axum::Router;
 use http::HeaderValue;
 use utoipa::OpenApi;
 
-use crate::handlers::{admin::*, auth::*, healthcheck::*, word::*};
-use crate::models::user::{AuthResponse, LoginRequest};
-use crate::models::word::{GetWord, UpsertWord, Word};
+use crate::handlers::{
+    admin::*, auth::*, challenge::*, editor::*, healthcheck::*, homophone::*, inflection::*,
+    ladder::*, relation::*, report::*, translation::*, word::*,
+};
+use crate::models::challenge::{
+    ChallengeResult, ChallengeSubmission, ChallengeWord, DailyChallenge, Leaderboard,
+    LeaderboardEntry,
+};
+use crate::models::export::ExportMetadata;
+use crate::models::import::{FieldError, ImportReport, ImportRow};
+use crate::models::inflection::{LemmaMatch, WordInflection};
+use crate::models::ladder::WordLadder;
+use crate::models::language::{Language, UpsertLanguage};
+use crate::models::proposal::{ProposalReview, WordProposal};
+use crate::models::relation::{RelatedWord, UpsertRelation, WordRelation};
+use crate::models::report::{NewReport, ReportResolution, ReportedWord, WordReport};
+use crate::models::revision::WordRevision;
+use crate::models::tag::{Tag, UpsertTag};
+use crate::models::translation::{TranslatedWord, UpsertTranslation, WordTranslation};
+use crate::models::trash::{PurgeReport, TrashedWord};
+use crate::models::user::{AuthResponse, LoginRequest, User};
+use crate::models::word::{
+    GetWord, PronunciationVariant, UpsertWord, Word, WordExample, WordSense,
+};
 use crate::state::AppState;
 
 /// OpenAPI specification structure with comprehensive endpoint documentation
@@ -25,6 +46,16 @@ use crate::state::AppState;
         // Public word endpoints
         word_random,
         word_type,
+        word_homophones,
+        word_related,
+        word_translations,
+        word_lemma,
+        word_report,
+        ladder_solve,
+        ladder_random,
+        challenge_read,
+        challenge_submit,
+        challenge_leaderboard,
         // Authentication endpoints
         login,
         // Administrative endpoints
@@ -32,16 +63,98 @@ use crate::state::AppState;
         word_create,
         word_read,
         word_update,
+        word_patch,
         word_delete,
+        word_import,
+        word_export,
+        trash_list,
+        trash_restore,
+        trash_purge,
+        trash_purge_expired,
+        word_history,
+        word_revert,
+        proposal_queue,
+        proposal_approve,
+        proposal_reject,
+        proposal_request_changes,
+        report_queue,
+        reported_words,
+        report_resolve,
+        report_dismiss,
+        relation_list,
+        relation_create,
+        relation_delete,
+        translation_list,
+        translation_create,
+        translation_delete,
+        word_tag_add,
+        word_tag_remove,
+        tag_list,
+        tag_create,
+        tag_delete,
+        language_list,
+        language_create,
+        language_update,
+        user_editor_grant,
+        user_editor_revoke,
+        // Editorial endpoints
+        proposal_create,
+        proposal_create_change,
+        proposal_list,
+        proposal_read,
+        proposal_revise,
     ),
     components(
-        schemas(Word, GetWord, UpsertWord, LoginRequest, AuthResponse)
+        schemas(
+            Word,
+            GetWord,
+            UpsertWord,
+            ImportReport,
+            ExportMetadata,
+            TrashedWord,
+            WordRevision,
+            WordProposal,
+            ProposalReview,
+            WordReport,
+            NewReport,
+            ReportedWord,
+            ReportResolution,
+            PurgeReport,
+            ImportRow,
+            FieldError,
+            PronunciationVariant,
+            WordSense,
+            WordExample,
+            WordInflection,
+            LemmaMatch,
+            RelatedWord,
+            WordRelation,
+            UpsertRelation,
+            TranslatedWord,
+            WordTranslation,
+            UpsertTranslation,
+            Tag,
+            UpsertTag,
+            Language,
+            UpsertLanguage,
+            WordLadder,
+            DailyChallenge,
+            ChallengeWord,
+            ChallengeSubmission,
+            ChallengeResult,
+            Leaderboard,
+            LeaderboardEntry,
+            LoginRequest,
+            AuthResponse,
+            User,
+        )
     ),
     tags(
         (name = "healthcheck_endpoints", description = "Health check and system status endpoints"),
         (name = "public_endpoints", description = "Public word retrieval endpoints"),
         (name = "auth_endpoints", description = "User authentication endpoints"),
         (name = "administration_endpoints", description = "Administrative word management endpoints. Require authentication and administrative privileges."),
+        (name = "editorial_endpoints", description = "Editorial endpoints proposing words and changes for review. Require authentication and editorial or administrative privileges."),
     ),
 )]
 pub struct ApiDoc;
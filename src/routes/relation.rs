@@ -0,0 +1,27 @@
+//! Public related word routes
+//!
+//! Provides the `/{lang}/words/{lemma}/related` endpoint listing synonyms,
+//! antonyms, hypernyms and derived forms of a lemma. Relations are managed
+//! by the admin routes under `/admin/{lang}/words/{id}/relations`.
+//!
+//! # Routes
+//! - `GET /{lang}/words/{lemma}/related` - Words related to a lemma
+
+use axum::{routing::get, Router};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::relation::*;
+use crate::state::AppState;
+
+/// Creates public related word routes with CORS configuration
+pub fn create_relation_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .route("/{lang}/words/{lemma}/related", get(word_related))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::GET])
+                .allow_origin(origins.to_owned()),
+        )
+}
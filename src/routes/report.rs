@@ -0,0 +1,43 @@
+//! Public problem report routes
+//!
+//! Provides the `/{lang}/words/{lemma}/reports` endpoint letting any client
+//! report a problem with a word. On top of the global rate limit, reports
+//! are limited per IP address to `report_limit_per_hour`. Reports are
+//! triaged by the admin routes under `/admin/{lang}/reports`.
+//!
+//! # Routes
+//! - `POST /{lang}/words/{lemma}/reports` - Report a problem with a word
+
+use axum::{routing::post, Router};
+use http::{HeaderValue, Method};
+use std::time::Duration;
+use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::report::*;
+use crate::state::AppState;
+
+/// Creates public problem report routes with their rate limit and CORS configuration
+pub fn create_report_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    let limit = {
+        let config_lock = shared_state.apiconfig.lock().unwrap();
+        config_lock.api_limits.report_limit_per_hour
+    };
+
+    // Reports per IP, replenished evenly over the hour
+    let governor_conf = GovernorConfigBuilder::default()
+        .period(Duration::from_secs(3600) / limit)
+        .burst_size(limit)
+        .finish()
+        .unwrap();
+
+    Router::new()
+        .route("/{lang}/words/{lemma}/reports", post(word_report))
+        .layer(GovernorLayer::new(governor_conf))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::POST])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -0,0 +1,29 @@
+//! Public translation routes
+//!
+//! Provides the `/{lang}/words/{lemma}/translations` endpoint listing the
+//! translations of a lemma into other languages. Translation links are
+//! managed by the admin routes under `/admin/{lang}/words/{id}/translations`.
+//! The bilingual random mode is served by the word routes with
+//! `?mode=bilingual`.
+//!
+//! # Routes
+//! - `GET /{lang}/words/{lemma}/translations` - Translations of a lemma
+
+use axum::{routing::get, Router};
+use http::{HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+use crate::handlers::translation::*;
+use crate::state::AppState;
+
+/// Creates public translation routes with CORS configuration
+pub fn create_translation_routes(shared_state: AppState, origins: &[HeaderValue]) -> Router {
+    Router::new()
+        .route("/{lang}/words/{lemma}/translations", get(word_translations))
+        .with_state(shared_state)
+        .layer(
+            CorsLayer::new()
+                .allow_methods([Method::GET])
+                .allow_origin(origins.to_owned()),
+        )
+}
@@ -9,11 +9,14 @@
 //! - `GET /{lang}/{type}` - Get random word of specific grammatical type
 //!
 //! # Supported Languages
-//! - `en` - English (currently the only supported language)
+//! - Every language registered and enabled by administrators (e.g. `en`)
 //!
 //! # Supported Word Types
 //! - `noun`, `verb`, `adjective`, `adverb`, `pronoun`, `preposition`,
-//!   `conjunction`, `interjection`, `article`
+//!   `conjunction`, `interjection`, `article`, or the names a language gives
+//!   them (e.g. `/de/substantiv`)
+//! - Nouns and verbs can be narrowed down with `?category=` to proper,
+//!   countable or uncountable nouns and transitive or intransitive verbs
 
 use axum::{routing::get, Router};
 use http::{HeaderValue, Method};
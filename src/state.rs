@@ -7,6 +7,8 @@ use std::sync::{Arc, Mutex};
 
 use crate::config::ApiConfig;
 use crate::error::SqlxError;
+use crate::models::ladder::LadderIndex;
+use crate::models::pronunciation::PronunciationRegistry;
 
 /// Central application state shared across all HTTP handlers
 #[derive(Clone)]
@@ -16,6 +18,12 @@ pub struct AppState {
 
     /// SQLite database connection pool for efficient query execution
     pub dbpool: sqlx::Pool<sqlx::Sqlite>,
+
+    /// Word ladder adjacency graphs, dropped on administrative writes and rebuilt lazily
+    pub ladders: Arc<LadderIndex>,
+
+    /// Pronunciation conversion tables, loaded once at startup
+    pub pronunciations: Arc<PronunciationRegistry>,
 }
 
 /// Configure the database pool with optimized settings
@@ -50,3 +58,26 @@ pub async fn init_dbpool(db_url: &str) -> Result<sqlx::Pool<sqlx::Sqlite>, SqlxE
 
     Ok(dbpool)
 }
+
+/// Handler state over a migrated database in a temporary file
+///
+/// Shared fixture of the handler tests, with the default configuration. The
+/// database is deleted with the returned file, keep it alive for the test.
+#[cfg(test)]
+pub(crate) async fn test_state() -> (AppState, tempfile::NamedTempFile) {
+    let temp_db = tempfile::NamedTempFile::new().unwrap();
+    let db_url = format!("sqlite:{}", temp_db.path().display());
+    let dbpool = init_dbpool(&db_url).await.unwrap();
+    let config = ApiConfig::default();
+    let pronunciations =
+        PronunciationRegistry::load(&config.server_settings.pronunciation_dir).unwrap();
+
+    let state = AppState {
+        apiconfig: Arc::new(Mutex::new(config)),
+        dbpool,
+        ladders: Arc::new(LadderIndex::new()),
+        pronunciations: Arc::new(pronunciations),
+    };
+
+    (state, temp_db)
+}